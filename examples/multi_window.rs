@@ -169,7 +169,7 @@ impl eframe::App for MultiWindowApp {
                 apply_rounded_corners_to_viewport(ctx);
 
                 // Settings window title bar
-                settings_title_bar.lock().unwrap().show(ctx);
+                settings_title_bar.lock().unwrap().show_in_viewport(ctx);
 
                 // Render resize handles for settings window
                 render_resize_handles(ctx);
@@ -245,7 +245,7 @@ impl eframe::App for MultiWindowApp {
                 apply_rounded_corners_to_viewport(ctx);
 
                 // About window title bar
-                about_title_bar.lock().unwrap().show(ctx);
+                about_title_bar.lock().unwrap().show_in_viewport(ctx);
 
                 // Render resize handles for about window
                 render_resize_handles(ctx);
@@ -26,11 +26,36 @@ pub mod menu;
 pub mod theme;
 /// Title bar widgets, options and rendering helpers.
 pub mod titlebar;
-/// Utility helpers (OS interop, resize handles, rounded corners).
+/// Utility helpers (OS interop, resize handles, rounded corners, window shadows).
 pub mod utils;
 
-pub use menu::shortcuts::KeyboardShortcut;
-pub use menu::{MenuItem, SubMenuItem};
-pub use theme::{ThemeError, ThemeMode, ThemeProvider, TitleBarTheme, detect_system_dark_mode};
-pub use titlebar::{main::CustomIcon, main::TitleBar, options::TitleBarOptions};
+#[cfg(feature = "global-shortcuts")]
+pub use menu::global_shortcuts::{GlobalShortcutError, GlobalShortcutId};
+pub use menu::shortcuts::{KeyboardShortcut, ShortcutParseError};
+pub use menu::{
+    MenuAction, MenuItem, MenuMessages, PopupMenu, PopupMenuResponse, RecentMenu, StateCallbacks,
+    SubMenuItem,
+};
+pub use theme::{
+    ColorScheme, ThemeError, ThemeMode, ThemeProvider, TitleBarTheme, detect_system_dark_mode,
+};
+#[cfg(feature = "serde")]
+pub use titlebar::config::TitleBarConfig;
+pub use titlebar::{
+    appearance::TitleBarAppearance,
+    center_content::CenterContentFn,
+    control_buttons::{CloseRequestFn, ControlButtonLayout, MinimizeFn},
+    main::ChevronStyle,
+    main::CustomIcon,
+    main::MenuActivation,
+    main::TitleBar,
+    main::TitleBarEdge,
+    main::TitleBarStyle,
+    options::TitleBarOptions,
+    search::{SearchEvent, TitleBarSearch},
+    snap::SnapZone,
+    system_menu::SystemMenu,
+    tabs::{Tab, TabEvent, TitleBarTabs},
+    toolbar::ToolbarFn,
+};
 pub use utils::*;
@@ -0,0 +1,46 @@
+use egui::{Context, Id, accesskit};
+
+/// Accessibility description of a painter-drawn menu entry.
+///
+/// The menus are drawn with `ui.painter()`, so the only widgets egui knows
+/// about are the `ui.interact` rects behind each entry. This fills in the
+/// AccessKit node egui created for such a rect.
+pub(crate) struct MenuNode<'a> {
+    /// Text announced for the entry.
+    pub label: &'a str,
+    /// Whether the entry can be activated.
+    pub enabled: bool,
+    /// Check state for checkable entries.
+    pub checked: Option<bool>,
+    /// Open state for entries that own a submenu.
+    pub expanded: Option<bool>,
+    /// Human-readable shortcut, e.g. "Ctrl+S".
+    pub shortcut: Option<String>,
+}
+
+/// Describe the `ui.interact` widget with the given id as a menu item.
+///
+/// Does nothing when AccessKit is not active for this pass.
+pub(crate) fn describe_menu_item(ctx: &Context, id: Id, node: MenuNode<'_>) {
+    ctx.accesskit_node_builder(id, |builder| {
+        builder.set_role(if node.checked.is_some() {
+            accesskit::Role::MenuItemCheckBox
+        } else {
+            accesskit::Role::MenuItem
+        });
+        builder.set_label(node.label);
+        if !node.enabled {
+            builder.set_disabled();
+        }
+        if let Some(checked) = node.checked {
+            builder.set_toggled(checked.into());
+        }
+        if let Some(expanded) = node.expanded {
+            builder.set_has_popup(accesskit::HasPopup::Menu);
+            builder.set_expanded(expanded);
+        }
+        if let Some(shortcut) = node.shortcut {
+            builder.set_keyboard_shortcut(shortcut);
+        }
+    });
+}
@@ -1,15 +1,28 @@
 use egui::{
-    Align2, Area, Color32, Context, CornerRadius, CursorIcon, FontId, Id, Order, Pos2, Rect, Sense,
-    Stroke, StrokeKind, Ui, Vec2,
+    Align2, Area, Color32, Context, CornerRadius, CursorIcon, Event, FontId, Id, Order,
+    PointerButton, Pos2, Rect, Response, Sense, Shadow, Shape, Stroke, StrokeKind, Ui, UiBuilder,
+    Vec2,
 };
-use std::cell::RefCell;
-use std::sync::atomic::{AtomicUsize, Ordering};
-
-use crate::TitleBar;
-use crate::menu::items::MenuItem;
-
-// Global state for submenu management
-static SUBMENU_CLICK_COUNTER: AtomicUsize = AtomicUsize::new(0);
+use std::hash::Hash;
+use std::sync::Arc;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+#[cfg(feature = "accesskit")]
+use crate::menu::accessibility::{MenuNode, describe_menu_item};
+use crate::menu::items::{LabelResolverFn, MenuAction, MenuItem, SubMenuItem, resolve_label};
+use crate::menu::layout;
+use crate::menu::navigation::{MenuNavModel, MenuNavigator, NavAction, NavEntry, NavKeys};
+use crate::menu::overflow::OVERFLOW_LABEL;
+use crate::menu::popup::PopupMenuResponse;
+use crate::menu::text_cache::TextWidthCache;
+use crate::{ChevronStyle, KeyboardShortcut, MenuActivation, TitleBar, TitleBarEdge};
+
+/// Seconds between keystrokes that still extend a typeahead prefix.
+const TYPEAHEAD_TIMEOUT: f64 = 1.0;
+
+/// Seconds a menu opened by hovering stays open after the pointer leaves it.
+const HOVER_CLOSE_DELAY: f64 = 0.3;
 
 impl TitleBar {
     /// Set the color of menu item text
@@ -56,9 +69,724 @@ impl TitleBar {
     /// ```
     pub fn with_menu_text_size(mut self, size: f32) -> Self {
         self.menu_text_size = size;
+        self.text_widths.clear();
+        self
+    }
+
+    /// Set the background color of menu badges
+    ///
+    /// # Arguments
+    /// * `color` - The badge pill color as a Color32
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::Color32;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_menu_badge_color(Color32::from_rgb(0, 120, 215))
+    /// # ;
+    /// ```
+    pub fn with_menu_badge_color(mut self, color: Color32) -> Self {
+        self.menu_badge_color = color;
+        self
+    }
+
+    /// Set the text color of menu badges
+    ///
+    /// # Arguments
+    /// * `color` - The badge count color as a Color32
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::Color32;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_menu_badge_text_color(Color32::BLACK)
+    /// # ;
+    /// ```
+    pub fn with_menu_badge_text_color(mut self, color: Color32) -> Self {
+        self.menu_badge_text_color = color;
+        self
+    }
+
+    /// Set how long the pointer must rest on an entry before its side menu opens
+    ///
+    /// Defaults to zero, opening side menus as soon as the entry is hovered.
+    ///
+    /// # Arguments
+    /// * `delay` - Hover time before a side menu opens
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use std::time::Duration;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_open_delay(Duration::from_millis(200))
+    /// # ;
+    /// ```
+    pub fn with_submenu_open_delay(mut self, delay: Duration) -> Self {
+        self.submenu_open_delay = delay;
+        self
+    }
+
+    /// Set how long a side menu stays open after the pointer leaves it
+    ///
+    /// Defaults to zero. A short delay keeps the side menu from flickering
+    /// closed when the pointer cuts across a neighbouring entry on its way
+    /// to it.
+    ///
+    /// # Arguments
+    /// * `delay` - Time a side menu lingers after the pointer leaves
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use std::time::Duration;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_close_delay(Duration::from_millis(300))
+    /// # ;
+    /// ```
+    pub fn with_submenu_close_delay(mut self, delay: Duration) -> Self {
+        self.submenu_close_delay = delay;
+        self
+    }
+
+    /// Fade menus in when they open
+    ///
+    /// Off by default, so menus appear at once.
+    ///
+    /// # Arguments
+    /// * `duration` - How long the fade takes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use std::time::Duration;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_animation(Duration::from_millis(120))
+    /// # ;
+    /// ```
+    pub fn with_submenu_animation(mut self, duration: Duration) -> Self {
+        self.submenu_animation = Some(duration);
+        self
+    }
+
+    /// Set whether opening menus also slide a few points into place (default `false`).
+    ///
+    /// Only has an effect together with `with_submenu_animation()`.
+    pub fn with_submenu_slide(mut self, slide: bool) -> Self {
+        self.submenu_animation_slide = slide;
+        self
+    }
+
+    /// Fade hover highlights in and out
+    ///
+    /// Menu labels, submenu entries and the window control buttons fade
+    /// their hover background over `duration` instead of switching it on and
+    /// off; egui keeps repainting while a fade runs. Defaults to 100 ms, and
+    /// `Duration::ZERO` gives instant highlights.
+    ///
+    /// # Arguments
+    /// * `duration` - How long a fade takes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use std::time::Duration;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_hover_animation(Duration::from_millis(150))
+    /// # ;
+    /// ```
+    pub fn with_hover_animation(mut self, duration: Duration) -> Self {
+        self.hover_animation = duration;
+        self
+    }
+
+    /// Turn every animation of the bar on or off (default `true`)
+    ///
+    /// Without animations hover highlights switch instantly and menus open
+    /// at once, whatever `with_hover_animation()` and
+    /// `with_submenu_animation()` say. Useful for users who prefer reduced
+    /// motion and on low-power devices, where animations cost repaints.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether the bar animates
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_animations(false)
+    /// # ;
+    /// ```
+    pub fn with_animations(mut self, enabled: bool) -> Self {
+        self.animations = enabled;
+        self
+    }
+
+    /// Mirror the menus for right-to-left languages
+    ///
+    /// Menus are laid out from right to left, submenus and side menus open
+    /// leftward with labels right-aligned and shortcuts and chevrons on the
+    /// left, and ArrowLeft/ArrowRight swap meaning during keyboard navigation.
+    ///
+    /// # Arguments
+    /// * `rtl` - Whether to use the right-to-left layout
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_rtl(true)
+    /// # ;
+    /// ```
+    pub fn with_rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Cap how many rows a menu shows before it scrolls
+    ///
+    /// Longer menus and side menus are cut to `rows` rows; the rest is
+    /// reached with the mouse wheel, the arrows shown at the top and bottom
+    /// edges, or the keyboard, which scrolls the selection into view.
+    ///
+    /// # Arguments
+    /// * `rows` - Maximum number of rows shown at once
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_max_visible_items(12)
+    /// # ;
+    /// ```
+    pub fn with_submenu_max_visible_items(mut self, rows: usize) -> Self {
+        self.submenu_max_visible_items = Some(rows.max(1));
+        self
+    }
+
+    /// Cap how wide a menu grows
+    ///
+    /// Labels that don't fit next to their shortcut are cut with "…" and show
+    /// their full text on hover; shortcuts and side-menu arrows stay visible.
+    ///
+    /// # Arguments
+    /// * `width` - Maximum menu width in points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_max_width(320.0)
+    /// # ;
+    /// ```
+    pub fn with_submenu_max_width(mut self, width: f32) -> Self {
+        self.submenu_max_width = Some(width);
+        self
+    }
+
+    /// Show the menus in the macOS menu bar instead of the title bar
+    ///
+    /// The menus are mirrored into the menu bar at the top of the screen,
+    /// after the application menu, and rebuilt whenever their labels,
+    /// states or entries change. Callbacks, messages and state callbacks run
+    /// in the next frame after an entry is chosen, and shortcuts become the
+    /// entries' key equivalents. Custom widget rows are left out, and the
+    /// action of split entries comes first in their side menu. On other
+    /// platforms the menus stay in the title bar.
+    ///
+    /// # Arguments
+    /// * `native` - Whether to use the macOS menu bar (default `false`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_native_macos_menubar(true)
+    /// # ;
+    /// ```
+    pub fn with_native_macos_menubar(mut self, native: bool) -> Self {
+        self.native_macos_menubar = native;
+        self
+    }
+
+    /// Open the menus in the bar on click or on hover
+    ///
+    /// With `MenuActivation::Hover`, moving the pointer over a menu label
+    /// opens its menu, and the menu closes a moment after the pointer has
+    /// left both the menu and the labels. Clicking an open menu's label
+    /// keeps it open. Either way, hovering another label while a menu is
+    /// open switches to it.
+    ///
+    /// # Arguments
+    /// * `activation` - What opens a menu (default `MenuActivation::Click`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::MenuActivation;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_menu_activation(MenuActivation::Hover)
+    /// # ;
+    /// ```
+    pub fn with_menu_activation(mut self, activation: MenuActivation) -> Self {
+        self.menu_activation = activation;
+        self
+    }
+
+    /// Whether the menus are shown in the macOS menu bar instead of the title bar.
+    pub(crate) fn uses_native_menubar(&self) -> bool {
+        cfg!(target_os = "macos") && self.native_macos_menubar
+    }
+
+    /// Collapse the menus that don't fit in the bar into a "»" menu
+    ///
+    /// When the window gets too narrow for every menu between the title and
+    /// the controls, the trailing menus move into a "»" menu at the end of
+    /// the bar, where they open as side menus. Keyboard navigation still
+    /// walks them, highlighting the "»" button.
+    ///
+    /// # Arguments
+    /// * `overflow` - Whether to collapse menus that don't fit (default `false`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_menu_overflow(true)
+    /// # ;
+    /// ```
+    pub fn with_menu_overflow(mut self, overflow: bool) -> Self {
+        self.menu_overflow = overflow;
+        self
+    }
+
+    /// Set the color of the lines between submenu groups
+    ///
+    /// By default separators use the submenu border color, so they follow
+    /// the light or dark theme.
+    ///
+    /// # Arguments
+    /// * `color` - The separator color as a Color32
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::Color32;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_separator_color(Color32::from_rgb(70, 70, 70))
+    /// # ;
+    /// ```
+    pub fn with_submenu_separator_color(mut self, color: Color32) -> Self {
+        self.submenu_separator_color = Some(color);
+        self
+    }
+
+    /// Set the thickness of the lines between submenu groups, in points (default `1.0`)
+    ///
+    /// Menus grow by the extra thickness, so rows never overlap a separator.
+    /// The thickness follows the UI scale and compact mode like the menu rows.
+    pub fn with_submenu_separator_thickness(mut self, thickness: f32) -> Self {
+        self.submenu_separator_thickness = thickness.max(0.0);
+        self
+    }
+
+    /// Set the color of the arrows marking entries with a side menu
+    ///
+    /// By default the arrows use the submenu text color. They turn white on
+    /// the keyboard selection either way.
+    ///
+    /// # Arguments
+    /// * `color` - The arrow color as a Color32
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::Color32;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_chevron_color(Color32::from_rgb(0, 120, 215))
+    /// # ;
+    /// ```
+    pub fn with_submenu_chevron_color(mut self, color: Color32) -> Self {
+        self.submenu_chevron_color = Some(color);
+        self
+    }
+
+    /// Set the height of side-menu arrows in points
+    ///
+    /// Defaults to 60% of the menu text size. Menus widen to fit larger
+    /// arrows.
+    ///
+    /// # Arguments
+    /// * `size` - Arrow height in points, scaled by `ui_scale`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_chevron_size(10.0)
+    /// # ;
+    /// ```
+    pub fn with_submenu_chevron_size(mut self, size: f32) -> Self {
+        self.submenu_chevron_size = Some(size.max(0.0));
+        self
+    }
+
+    /// Draw side-menu arrows as a stroked chevron or a filled triangle (default `Stroke`).
+    pub fn with_submenu_chevron_style(mut self, style: ChevronStyle) -> Self {
+        self.submenu_chevron_style = style;
+        self
+    }
+
+    /// Set the drop shadow painted under submenus and their side menus
+    ///
+    /// Menus cast a soft shadow downward by default. Offset, blur and spread
+    /// are in points; pass `Shadow::NONE` to draw flat menus.
+    ///
+    /// # Arguments
+    /// * `shadow` - Color, offset, blur and spread of the shadow
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::{Color32, Shadow};
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_submenu_shadow(Shadow {
+    ///     offset: [0, 6],
+    ///     blur: 16,
+    ///     spread: 0,
+    ///     color: Color32::from_black_alpha(80),
+    /// })
+    /// # ;
+    /// ```
+    pub fn with_submenu_shadow(mut self, shadow: Shadow) -> Self {
+        self.submenu_shadow = shadow;
+        self
+    }
+
+    /// Set the corner radius of submenus and their side menus, in points (default `4`).
+    pub fn with_submenu_corner_radius(mut self, radius: u8) -> Self {
+        self.submenu_corner_radius = radius;
+        self
+    }
+
+    /// Set the minimum width of submenus and their side menus, in points (default `120.0`)
+    ///
+    /// Menus still widen to fit their longest entry, up to the width set by
+    /// `with_submenu_max_width`.
+    pub fn with_submenu_min_width(mut self, width: f32) -> Self {
+        self.submenu_min_width = width.max(0.0);
+        self
+    }
+
+    /// Let shortcuts fire while a text field has keyboard focus
+    ///
+    /// Shortcuts without Ctrl, Alt or Cmd never fire while the user is typing.
+    /// Modified ones do by default; pass `false` so that keys like Ctrl+A go
+    /// to the focused `TextEdit` instead of the menu.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether Ctrl/Alt/Cmd shortcuts fire while editing text
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_shortcuts_while_editing(false)
+    /// # ;
+    /// ```
+    pub fn with_shortcuts_while_editing(mut self, enabled: bool) -> Self {
+        self.shortcuts_while_editing = enabled;
+        self
+    }
+
+    /// Namespace the ids of the bar with `id_salt`
+    ///
+    /// Only needed when several title bars are shown in the same window;
+    /// bars in different viewports are told apart on their own.
+    ///
+    /// # Arguments
+    /// * `id_salt` - Any hashable value unique to this bar
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::{TitleBar, TitleBarEdge, TitleBarOptions};
+    /// let status_bar = TitleBar::new(TitleBarOptions::new())
+    ///     .with_title_bar_position(TitleBarEdge::Bottom)
+    ///     .with_id("status_bar");
+    /// ```
+    pub fn with_id(mut self, id_salt: impl Hash) -> Self {
+        self.id_salt = Id::new(id_salt);
+        self.id = self.id_salt;
         self
     }
 
+    /// Hide key presses that fire a shortcut from the rest of the UI
+    ///
+    /// When a menu or icon shortcut fires, its key press is removed from the
+    /// frame's input, so widgets drawn afterwards don't react to it as well,
+    /// e.g. a focused `TextEdit` selecting everything on Ctrl+A. Pass `false`
+    /// to let the press reach them too.
+    ///
+    /// # Arguments
+    /// * `consume` - Whether fired shortcuts are consumed (default `true`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_consume_shortcuts(false)
+    /// # ;
+    /// ```
+    pub fn with_consume_shortcuts(mut self, consume: bool) -> Self {
+        self.consume_shortcuts = consume;
+        self
+    }
+
+    /// Get the menu item text color.
+    pub fn menu_text_color(&self) -> Color32 {
+        self.menu_text_color
+    }
+
+    /// Get the menu item hover color.
+    pub fn menu_hover_color(&self) -> Color32 {
+        self.menu_hover_color
+    }
+
+    /// Get the menu item font size in points.
+    pub fn menu_text_size(&self) -> f32 {
+        self.menu_text_size
+    }
+
+    /// Whether a submenu is currently open.
+    pub fn is_submenu_open(&self) -> bool {
+        self.open_submenu.is_some()
+    }
+
+    /// Get the index of the open submenu among the menus with submenus.
+    pub fn open_submenu_index(&self) -> Option<usize> {
+        self.open_submenu
+    }
+
+    /// Whether keyboard menu navigation is active.
+    pub fn is_keyboard_navigation_active(&self) -> bool {
+        self.keyboard_navigation_active
+    }
+
+    /// Whether any menu overlay is open
+    ///
+    /// Covers the menus of the bar, the "»" overflow menu, the icon menu and
+    /// the system menu, including the keyboard move or resize it starts.
+    /// While one is open, the menus consume Escape, Enter and the arrow keys,
+    /// so apps with their own key handling should skip those keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui::{Context, Key};
+    /// # use egui_desktop::TitleBar;
+    /// # struct App { title_bar: TitleBar }
+    /// # impl App {
+    /// # fn exit_fullscreen(&mut self) {}
+    /// # fn update(&mut self, ctx: &Context) {
+    /// # let title_bar = &self.title_bar;
+    /// if !title_bar.is_any_menu_open() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+    ///     self.exit_fullscreen();
+    /// }
+    /// # }
+    /// # }
+    /// ```
+    pub fn is_any_menu_open(&self) -> bool {
+        self.is_submenu_open()
+            || self.is_system_menu_open()
+            || self.icon_menu_anchor.is_some()
+            || self.overflow_menu_open
+    }
+
+    /// Shorthand for [`TitleBar::is_keyboard_navigation_active`].
+    pub fn is_keyboard_nav_active(&self) -> bool {
+        self.is_keyboard_navigation_active()
+    }
+
+    /// Get the top-level menu highlighted by keyboard navigation
+    ///
+    /// Simple menu items come first, followed by menus with submenus.
+    pub fn selected_menu_index(&self) -> Option<usize> {
+        self.selected_menu_index
+    }
+
+    /// Open a menu from code, as if its label had been clicked
+    ///
+    /// Returns `false` if `index` is out of range or the menu is hidden or
+    /// disabled, the same menus a click or keyboard navigation won't open.
+    ///
+    /// # Arguments
+    /// * `index` - Index among the menus with submenus
+    pub fn open_menu(&mut self, index: usize) -> bool {
+        if !self
+            .menu_items_with_submenus
+            .get(index)
+            .is_some_and(|menu| menu.visible && menu.enabled)
+        {
+            return false;
+        }
+        self.close_all_menus();
+        self.open_submenu = Some(index);
+        self.submenu_just_opened_frame = true;
+        self.last_click_id = self.submenu_click_counter;
+        self.submenu_click_counter += 1;
+        true
+    }
+
+    /// Find a submenu item by its path of ids or labels
+    ///
+    /// The path starts with a top-level menu and walks down through submenu
+    /// items and their children. Each segment matches an item's id first and
+    /// its label otherwise. Returns `None` if a segment is not found or the
+    /// path names only a menu.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// if let Some(item) = title_bar.item_by_path(&["View", "Panels", "Outline"]) {
+    ///     println!("outline shown: {:?}", item.checked);
+    /// }
+    /// ```
+    pub fn item_by_path(&self, path: &[&str]) -> Option<&SubMenuItem> {
+        let (menu, rest) = path.split_first()?;
+        let (first, rest) = rest.split_first()?;
+        let menu = MenuItem::position_by_path_segment(&self.menu_items_with_submenus, menu)?;
+        let subitems = &self.menu_items_with_submenus[menu].subitems;
+        let mut item = &subitems[SubMenuItem::position_by_path_segment(subitems, first)?];
+        for segment in rest {
+            item = &item.children[SubMenuItem::position_by_path_segment(&item.children, segment)?];
+        }
+        Some(item)
+    }
+
+    /// Mutable variant of [`TitleBar::item_by_path`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let mut title_bar = TitleBar::default();
+    /// # struct History;
+    /// # impl History { fn can_undo(&self) -> bool { false } }
+    /// # let history = History;
+    /// if let Some(item) = title_bar.item_by_path_mut(&["Edit", "Undo"]) {
+    ///     item.enabled = history.can_undo();
+    /// }
+    /// ```
+    pub fn item_by_path_mut(&mut self, path: &[&str]) -> Option<&mut SubMenuItem> {
+        let (menu, rest) = path.split_first()?;
+        let (first, rest) = rest.split_first()?;
+        let menu = MenuItem::position_by_path_segment(&self.menu_items_with_submenus, menu)?;
+        let subitems = &mut self.menu_items_with_submenus[menu].subitems;
+        let index = SubMenuItem::position_by_path_segment(subitems, first)?;
+        let mut item = &mut subitems[index];
+        for segment in rest {
+            let index = SubMenuItem::position_by_path_segment(&item.children, segment)?;
+            item = &mut item.children[index];
+        }
+        Some(item)
+    }
+
+    /// Close every open menu and leave keyboard navigation.
+    pub fn close_all_menus(&mut self) {
+        self.open_submenu = None;
+        self.submenu_just_opened_frame = false;
+        self.submenu_selection.clear();
+        self.keyboard_navigation_active = false;
+        self.selected_menu_index = None;
+        self.icon_menu_anchor = None;
+    }
+
+    /// Open menus along a path of labels and highlight the last entry
+    ///
+    /// The path starts with a top-level menu, followed by the entries on the
+    /// way down to the one to highlight, each given by id or label. Side menus
+    /// nest to any depth. The state is the one keyboard navigation produces, so the next `render_open_submenu()` draws it and
+    /// the arrow keys continue from there. Useful for guided tours. Returns
+    /// `false`, leaving the menus untouched, if a label is not found or the
+    /// menu can't be opened.
+    ///
+    /// # Arguments
+    /// * `path` - Labels from the menu bar down to the item to highlight
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let mut title_bar = TitleBar::default();
+    /// // Open "Edit", then the "Find" submenu, and highlight "Replace…"
+    /// title_bar.open_submenu_path(&["Edit", "Find", "Replace…"]);
+    /// ```
+    pub fn open_submenu_path(&mut self, path: &[&str]) -> bool {
+        let [menu_label, rest @ ..] = path else {
+            return false;
+        };
+        let Some(index) =
+            MenuItem::position_by_path_segment(&self.menu_items_with_submenus, menu_label)
+        else {
+            return false;
+        };
+
+        // Generate dynamic entries now so the labels can be resolved; they are
+        // only stored once the whole path is found
+        let menu_item = &self.menu_items_with_submenus[index];
+        let generated = menu_item
+            .dynamic_subitems
+            .as_ref()
+            .map(|generator| generator());
+        let mut selection = Vec::with_capacity(rest.len());
+        let mut entries = generated.as_ref().unwrap_or(&menu_item.subitems);
+        for label in rest {
+            let Some(item) = SubMenuItem::position_by_path_segment(entries, label) else {
+                return false;
+            };
+            selection.push(item);
+            entries = &entries[item].children;
+        }
+
+        if !self.open_menu(index) {
+            return false;
+        }
+        // Marking the entries as generated keeps the next render from
+        // resetting the selection
+        if let Some(subitems) = generated {
+            self.menu_items_with_submenus[index].subitems = subitems;
+        }
+        self.generated_submenu = Some(index);
+        self.keyboard_navigation_active = true;
+        self.selected_menu_index = Some(self.menu_items.len() + index);
+        self.submenu_selection = selection;
+        true
+    }
+
     /// Check for keyboard shortcuts and trigger callbacks
     ///
     /// This method should be called before rendering menus to handle keyboard shortcuts.
@@ -67,19 +795,47 @@ impl TitleBar {
     /// * `ctx` - The egui context
     pub fn check_keyboard_shortcuts(&mut self, ctx: &Context) {
         // Check menu items with submenus
-        for menu_item in &self.menu_items_with_submenus {
-            for subitem in &menu_item.subitems {
-                if let Some(ref shortcut) = subitem.shortcut {
-                    if shortcut.just_pressed(ctx) && subitem.enabled {
-                        if let Some(ref callback) = subitem.callback {
-                            callback();
-                        }
+        for menu_item in self.menu_items_with_submenus.iter().filter(|m| m.visible) {
+            for (index, subitem) in menu_item.subitems.iter().enumerate() {
+                if !subitem.visible {
+                    continue;
+                }
+                if let Some(ref shortcut) = subitem.shortcut
+                    && self.shortcut_allowed(ctx, shortcut)
+                    && shortcut.just_pressed(ctx)
+                    && subitem.enabled
+                {
+                    if let Some(ref callback) = subitem.callback {
+                        callback();
                     }
+                    self.send_menu_message(subitem);
+                    self.send_menu_action(menu_item, &[index]);
+                    self.queue_state_callback(subitem);
+                    self.consume_shortcut(ctx, shortcut);
                 }
             }
         }
     }
 
+    /// Remove the key press of a shortcut that just fired, unless presses propagate.
+    pub(crate) fn consume_shortcut(&self, ctx: &Context, shortcut: &KeyboardShortcut) {
+        if self.consume_shortcuts {
+            shortcut.consume(ctx);
+        }
+    }
+
+    /// Whether a shortcut may fire, given which widget has keyboard focus.
+    ///
+    /// While a text field is focused, shortcuts without Ctrl, Alt or Cmd type
+    /// text instead, and the rest only fire if `shortcuts_while_editing` is set.
+    pub(crate) fn shortcut_allowed(&self, ctx: &Context, shortcut: &KeyboardShortcut) -> bool {
+        if ctx.memory(|m| m.focused()).is_none() {
+            return true;
+        }
+        let modifiers = shortcut.modifiers;
+        self.shortcuts_while_editing && (modifiers.ctrl || modifiers.alt || modifiers.command)
+    }
+
     /// Handle keyboard navigation for menus
     ///
     /// This method handles arrow keys, Enter, and Escape for menu navigation.
@@ -87,334 +843,241 @@ impl TitleBar {
     /// # Arguments
     /// * `ctx` - The egui context
     pub fn handle_keyboard_navigation(&mut self, ctx: &Context) {
-        let current_time = ctx.input(|i| i.time);
-
-        // Check if Alt key or Ctrl+F2 is pressed to activate menu navigation
-        let should_activate = ctx.input(|i| i.modifiers.alt)
-            || (ctx.input(|i| i.modifiers.ctrl) && ctx.input(|i| i.key_pressed(egui::Key::F2)));
-
-        if should_activate {
-            if !self.keyboard_navigation_active {
-                self.keyboard_navigation_active = true;
-                self.selected_menu_index = Some(0);
-                self.selected_submenu_index = None;
-                self.last_keyboard_nav_time = current_time;
-            }
-        }
-
-        // Handle navigation when active
-        if self.keyboard_navigation_active {
-            // Handle Escape to close menus
-            if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
-                self.keyboard_navigation_active = false;
-                self.selected_menu_index = None;
-                self.selected_submenu_index = None;
-                self.open_submenu = None;
-                return;
-            }
-
-            // Handle clicks outside menu areas to close menus (but keep keyboard nav active)
-            if ctx.input(|i| i.pointer.primary_clicked()) {
-                let click_pos = ctx.input(|i| i.pointer.interact_pos()).unwrap_or_default();
-                let menu_bar_rect = Rect::from_min_size(
-                    Pos2::new(0.0, 0.0),
-                    Vec2::new(ctx.content_rect().width(), 32.0),
-                );
-
-                // If click is outside menu bar and any submenu is open, close all menus
-                if !menu_bar_rect.contains(click_pos) && self.open_submenu.is_some() {
-                    self.open_submenu = None;
-                    self.force_open_child_subitem = None;
-                    self.child_submenu_selections.clear();
-                    // Keep keyboard_navigation_active = true (don't disable it)
-                }
-            }
-
-            // Handle left/right arrow keys for top-level menu navigation
-            // Disable only when we're on a highlighted submenu item that has a sidemenu
-            let current_highlighted_has_sidemenu = if let Some(open_submenu_index) =
-                self.open_submenu
-            {
-                if let Some(menu_item) = self.menu_items_with_submenus.get(open_submenu_index) {
-                    if let Some(selected_index) = self.submenu_selections.get(&open_submenu_index) {
-                        if let Some(selected_item) = menu_item.subitems.get(*selected_index) {
-                            !selected_item.children.is_empty()
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                }
-            } else {
-                false
-            };
-
-            if !current_highlighted_has_sidemenu {
-                if ctx.input(|i| i.key_pressed(egui::Key::ArrowLeft)) {
-                    if let Some(current_index) = self.selected_menu_index {
-                        if current_index > 0 {
-                            self.selected_menu_index = Some(current_index - 1);
-                            self.open_submenu = None;
-                            self.selected_submenu_index = None;
-                        }
-                    }
-                }
+        self.handle_typeahead(ctx);
 
-                if ctx.input(|i| i.key_pressed(egui::Key::ArrowRight)) {
-                    let total_menus = self.menu_items.len() + self.menu_items_with_submenus.len();
-                    if let Some(current_index) = self.selected_menu_index {
-                        if current_index < total_menus - 1 {
-                            self.selected_menu_index = Some(current_index + 1);
-                            self.open_submenu = None;
-                            self.selected_submenu_index = None;
-                        }
-                    }
-                }
-            }
+        let mut keys = NavKeys::from_context(ctx);
+        // Menus run the other way in right-to-left layouts
+        if self.rtl {
+            std::mem::swap(&mut keys.left, &mut keys.right);
+        }
+        if !keys.activate && !self.keyboard_navigation_active {
+            return;
+        }
+        // The system menu has the keyboard while it is open
+        if self.is_system_menu_open() {
+            return;
+        }
 
-            // Handle Enter and Space keys - unified logic for all contexts
-            if ctx.input(|i| i.key_pressed(egui::Key::Enter))
-                || ctx.input(|i| i.key_pressed(egui::Key::Space))
-            {
-                // Priority 1: If we're in a child sidemenu, handle that first
-                if let Some(open_submenu_index) = self.open_submenu {
-                    if let Some(child_submenu_index) =
-                        self.child_submenu_selections.get(&open_submenu_index)
-                    {
-                        if let Some(menu_item) =
-                            self.menu_items_with_submenus.get(open_submenu_index)
-                        {
-                            if let Some(child_index) = self.force_open_child_subitem {
-                                if let Some(child_item) = menu_item.subitems.get(child_index) {
-                                    if let Some(child_subitem) =
-                                        child_item.children.get(*child_submenu_index)
-                                    {
-                                        if child_subitem.enabled {
-                                            if let Some(ref callback) = child_subitem.callback {
-                                                callback();
-                                            }
-                                            // Close all submenus after action
-                                            self.open_submenu = None;
-                                            self.selected_submenu_index = None;
-                                            self.force_open_child_subitem = None;
-                                            self.child_submenu_selections.clear();
-                                            return;
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+        if keys.activate && !self.keyboard_navigation_active {
+            self.last_keyboard_nav_time = ctx.input(|i| i.time);
+        }
 
-                // Priority 2: If we're in a submenu, handle submenu item
-                if let Some(open_submenu_index) = self.open_submenu {
-                    if self.submenu_just_opened_frame {
-                        // Skip if submenu was just opened this frame
-                        self.submenu_just_opened_frame = false;
-                        return;
-                    }
-                    if let Some(submenu_index) = self.submenu_selections.get(&open_submenu_index) {
-                        if let Some(menu_item) =
-                            self.menu_items_with_submenus.get(open_submenu_index)
-                        {
-                            if let Some(subitem) = menu_item.subitems.get(*submenu_index) {
-                                if subitem.enabled && subitem.children.is_empty() {
-                                    // Only trigger if it has no children (no sidemenu)
-                                    if let Some(ref callback) = subitem.callback {
-                                        callback();
-                                    }
-                                    // Close submenu after action
-                                    self.open_submenu = None;
-                                    self.submenu_selections.remove(&open_submenu_index);
-                                    return;
-                                }
-                            }
-                        }
-                    }
-                }
+        // Handle clicks outside menu areas to close menus (but keep keyboard nav active)
+        if self.keyboard_navigation_active && ctx.input(|i| i.pointer.primary_clicked()) {
+            let click_pos = ctx.input(|i| i.pointer.interact_pos()).unwrap_or_default();
+            let menu_bar_rect = self.bar_rect_or_default(ctx);
 
-                // Priority 3: Handle main menu items
-                if let Some(menu_index) = self.selected_menu_index {
-                    let total_simple_menus = self.menu_items.len();
+            // If click is outside menu bar and any submenu is open, close all menus
+            if !menu_bar_rect.contains(click_pos) && self.open_submenu.is_some() {
+                self.open_submenu = None;
+                self.submenu_selection.clear();
+            }
+        }
 
-                    if menu_index < total_simple_menus {
-                        // Simple menu item - trigger callback
-                        if let Some((_, callback)) = self.menu_items.get(menu_index) {
-                            if let Some(callback) = callback {
-                                callback();
-                            }
-                        }
-                    } else {
-                        // Menu with submenu
-                        let submenu_index = menu_index - total_simple_menus;
-                        if let Some(menu_item) = self.menu_items_with_submenus.get(submenu_index) {
-                            if !menu_item.subitems.is_empty() {
-                                self.open_submenu = Some(submenu_index);
-                                self.submenu_selections.insert(submenu_index, 0);
-                                // Mark as just opened to avoid immediately activating first item on Enter this frame
-                                self.submenu_just_opened_frame = true;
-                                // Only auto-open child submenu on keyboard navigation activation
-                                if self.keyboard_navigation_active {
-                                    if let Some(first_item) = menu_item.subitems.get(0) {
-                                        if !first_item.children.is_empty() {
-                                            self.force_open_child_subitem = Some(0);
-                                        } else {
-                                            self.force_open_child_subitem = None;
-                                        }
-                                    } else {
-                                        self.force_open_child_subitem = None;
-                                    }
-                                } else {
-                                    // Mouse-driven open: do not auto-open a child side menu
-                                    self.force_open_child_subitem = None;
-                                }
-                            }
-                        }
+        let mut navigator = MenuNavigator {
+            active: self.keyboard_navigation_active,
+            selected_menu: self.selected_menu_index,
+            open_submenu: self.open_submenu,
+            selection: std::mem::take(&mut self.submenu_selection),
+            just_opened: self.submenu_just_opened_frame,
+        };
+        let action = navigator.update(keys, &self.nav_model());
+
+        self.keyboard_navigation_active = navigator.active;
+        self.selected_menu_index = navigator.selected_menu;
+        self.open_submenu = navigator.open_submenu;
+        self.submenu_selection = navigator.selection;
+        self.submenu_just_opened_frame = navigator.just_opened;
+
+        match action {
+            Some(NavAction::Menu(index)) => {
+                if let Some((_, Some(callback))) = self.menu_items.get(index) {
+                    callback();
+                }
+                self.send_simple_menu_action(index);
+            }
+            Some(NavAction::Submenu { submenu, path }) => {
+                if let Some(menu) = self.menu_items_with_submenus.get(submenu)
+                    && let Some(entry) = SubMenuItem::at_path(&menu.subitems, &path)
+                {
+                    if let Some(callback) = &entry.callback {
+                        callback();
                     }
+                    self.send_menu_message(entry);
+                    self.send_menu_action(menu, &path);
+                    self.queue_state_callback(entry);
                 }
             }
+            None => {}
+        }
+    }
 
-            // Handle up/down/left/right keys for submenu navigation and side menus
-            if let Some(open_submenu_index) = self.open_submenu {
-                if let Some(menu_item) = self.menu_items_with_submenus.get(open_submenu_index) {
-                    // Handle up/down navigation in main submenu ONLY if no child sidemenu is open
-                    // Child submenu navigation is handled separately below
-                    if self.force_open_child_subitem.is_none()
-                        && !self
-                            .child_submenu_selections
-                            .contains_key(&open_submenu_index)
-                    {
-                        if ctx.input(|i| i.key_pressed(egui::Key::ArrowUp)) {
-                            if let Some(current_submenu_index) =
-                                self.submenu_selections.get(&open_submenu_index).copied()
-                            {
-                                if current_submenu_index > 0 {
-                                    self.submenu_selections
-                                        .insert(open_submenu_index, current_submenu_index - 1);
-                                }
-                            }
-                        }
+    /// Highlight the entry of the open menu matching the typed letters
+    ///
+    /// Typing jumps to the next entry whose label starts with the typed
+    /// text, wrapping around; letters typed within a second of each other
+    /// extend the prefix. Letters typed with Alt, Ctrl or Cmd are left to
+    /// mnemonics and shortcuts, and a focused text field keeps its input.
+    fn handle_typeahead(&mut self, ctx: &Context) {
+        let Some(open_index) = self.open_submenu else {
+            self.typeahead_prefix.clear();
+            return;
+        };
+        if ctx.memory(|m| m.focused().is_some()) {
+            return;
+        }
+        // Space is also Enter for the navigator, so it never extends the prefix
+        let typed: String = ctx.input(|i| {
+            if i.modifiers.alt || i.modifiers.ctrl || i.modifiers.command {
+                return String::new();
+            }
+            i.events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .flat_map(str::chars)
+                .filter(|c| !c.is_whitespace())
+                .flat_map(char::to_lowercase)
+                .collect()
+        });
+        if typed.is_empty() {
+            return;
+        }
 
-                        if ctx.input(|i| i.key_pressed(egui::Key::ArrowDown)) {
-                            if let Some(current_submenu_index) =
-                                self.submenu_selections.get(&open_submenu_index).copied()
-                            {
-                                if current_submenu_index < menu_item.subitems.len() - 1 {
-                                    self.submenu_selections
-                                        .insert(open_submenu_index, current_submenu_index + 1);
-                                }
-                            }
-                        }
-                    }
+        let now = ctx.input(|i| i.time);
+        if now - self.last_typeahead_time > TYPEAHEAD_TIMEOUT {
+            self.typeahead_prefix.clear();
+        }
+        self.last_typeahead_time = now;
+        self.typeahead_prefix.push_str(&typed);
 
-                    // Right arrow on a submenu item that has children -> force-open child sidemenu
-                    if ctx.input(|i| i.key_pressed(egui::Key::ArrowRight)) {
-                        if let Some(current_submenu_index) =
-                            self.submenu_selections.get(&open_submenu_index)
-                        {
-                            if let Some(current_item) =
-                                menu_item.subitems.get(*current_submenu_index)
-                            {
-                                if !current_item.children.is_empty() {
-                                    // Flag to force-open the child submenu in the renderer
-                                    self.force_open_child_subitem = Some(*current_submenu_index);
-                                    // Prevent immediate activation this frame
-                                    self.submenu_just_opened_frame = true;
-                                }
-                            }
-                        }
-                    }
+        let Some(menu) = self.menu_items_with_submenus.get(open_index) else {
+            return;
+        };
+        let labels = self.label_resolver.as_deref();
+        // Typing goes to the innermost side menu opened from the keyboard
+        let (current, parents) = match self.submenu_selection.split_last() {
+            Some((&current, parents)) => (Some(current), parents),
+            None => (None, &[][..]),
+        };
+        let entries = match parents {
+            [] => &menu.subitems,
+            _ => match SubMenuItem::at_path(&menu.subitems, parents) {
+                Some(parent) => &parent.children,
+                None => return,
+            },
+        };
+        if let Some(found) = typeahead_match(entries, current, &self.typeahead_prefix, labels) {
+            match self.submenu_selection.last_mut() {
+                Some(last) => *last = found,
+                None => self.submenu_selection.push(found),
+            }
+            self.keyboard_navigation_active = true;
+            self.selected_menu_index = Some(self.menu_items.len() + open_index);
+        }
+    }
 
-                    // Left arrow: back out of child sidemenu first; if none, close this submenu
-                    if ctx.input(|i| i.key_pressed(egui::Key::ArrowLeft)) {
-                        if self.force_open_child_subitem.is_some() {
-                            self.force_open_child_subitem = None;
-                            self.child_submenu_selections.remove(&open_submenu_index);
-                        } else if self.keyboard_navigation_active
-                            && self
-                                .child_submenu_selections
-                                .contains_key(&open_submenu_index)
-                        {
-                            // Close child submenu navigation but keep parent submenu open
-                            self.child_submenu_selections.remove(&open_submenu_index);
-                        } else {
-                            // Close current submenu, keep focus on the parent menu
-                            self.open_submenu = None;
-                            self.submenu_selections.remove(&open_submenu_index);
-                        }
+    /// Describe the current menus for the keyboard navigator.
+    fn nav_model(&self) -> MenuNavModel {
+        MenuNavModel {
+            simple_menus: self.menu_items.len(),
+            hidden_menus: self
+                .disabled_menu_items
+                .iter()
+                .copied()
+                .chain(
+                    self.menu_items_with_submenus
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, menu)| !menu.visible || !menu.enabled)
+                        .map(|(index, _)| self.menu_items.len() + index),
+                )
+                .collect(),
+            submenus: self
+                .menu_items_with_submenus
+                .iter()
+                .map(|menu| {
+                    // Dynamic menus that were never opened have no entries yet;
+                    // a placeholder lets Enter open them so they get generated
+                    if menu.subitems.is_empty() && menu.dynamic_subitems.is_some() {
+                        return vec![NavEntry::default()];
                     }
+                    let cells = layout::menu_cells(&menu.subitems, menu.columns);
+                    menu.subitems
+                        .iter()
+                        .zip(cells)
+                        .map(|(subitem, cell)| NavEntry {
+                            cell,
+                            ..Self::nav_entry(subitem)
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
 
-                    // Handle navigation within child submenu when it's open
-                    // Check if any child submenu is currently open (either forced or hovered)
-                    let mut active_child_index = self.force_open_child_subitem;
-
-                    // If no forced child, check if we should enable child navigation
-                    // This works for mouse hover, but NOT for keyboard navigation (keyboard needs explicit right arrow)
-                    if active_child_index.is_none() && !self.keyboard_navigation_active {
-                        // Find the first submenu item that has children and is currently selected
-                        if let Some(selected_index) =
-                            self.submenu_selections.get(&open_submenu_index)
-                        {
-                            if let Some(selected_item) = menu_item.subitems.get(*selected_index) {
-                                if !selected_item.children.is_empty() {
-                                    active_child_index = Some(*selected_index);
-                                }
-                            }
-                        }
-                    }
+    /// Describe a submenu item and its side menu for the keyboard navigator.
+    pub(crate) fn nav_entry(subitem: &SubMenuItem) -> NavEntry {
+        NavEntry {
+            enabled: subitem.enabled,
+            skip: !subitem.is_keyboard_navigable(),
+            children: subitem.children.iter().map(Self::nav_entry).collect(),
+            split: subitem.is_split(),
+            cell: (0, 0),
+        }
+    }
 
-                    if let Some(child_index) = active_child_index {
-                        if let Some(child_item) = menu_item.subitems.get(child_index) {
-                            if !child_item.children.is_empty() {
-                                // Initialize child submenu selection if not set
-                                if !self
-                                    .child_submenu_selections
-                                    .contains_key(&open_submenu_index)
-                                {
-                                    self.child_submenu_selections.insert(open_submenu_index, 0);
-                                }
-
-                                // Handle up/down navigation in child submenu
-                                if ctx.input(|i| i.key_pressed(egui::Key::ArrowUp)) {
-                                    if let Some(current_child_index) =
-                                        self.child_submenu_selections.get(&open_submenu_index)
-                                    {
-                                        if *current_child_index > 0 {
-                                            self.child_submenu_selections.insert(
-                                                open_submenu_index,
-                                                current_child_index - 1,
-                                            );
-                                        }
-                                    }
-                                }
-
-                                if ctx.input(|i| i.key_pressed(egui::Key::ArrowDown)) {
-                                    if let Some(current_child_index) =
-                                        self.child_submenu_selections.get(&open_submenu_index)
-                                    {
-                                        if *current_child_index < child_item.children.len() - 1 {
-                                            self.child_submenu_selections.insert(
-                                                open_submenu_index,
-                                                current_child_index + 1,
-                                            );
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+    /// Width of the menus drawn by `render_menu_items()` in `room`, hidden menus
+    /// excluded and menus that collapse into the "»" menu replaced by its button.
+    pub(crate) fn menu_bar_width(&mut self, ui: &Ui, room: f32) -> f32 {
+        if self.uses_native_menubar()
+            || (self.menu_items.is_empty() && self.menu_items_with_submenus.is_empty())
+        {
+            return 0.0;
+        }
+        let menu_font = FontId::proportional(self.scaled(self.menu_text_size));
+        let label_padding = self.compacted(16.0);
+        let badge_font = FontId::proportional(self.scaled(self.menu_text_size * 0.75));
+        let badge_gap = self.scaled(4.0);
+        let badge_height = badge_font.size + self.scaled(4.0);
+        let badge_padding = self.scaled(8.0);
+
+        let labels = self.label_resolver.as_deref();
+        let mut widths = Vec::new();
+        let mut pinned_width = 0.0;
+        for (label, _) in &self.menu_items {
+            let label = resolve_label(labels, label);
+            widths.push(Some(
+                self.text_widths.width(ui, &label, &menu_font) + label_padding,
+            ));
+        }
+        for menu_item in self
+            .menu_items_with_submenus
+            .iter()
+            .filter(|item| item.visible)
+        {
+            let label = resolve_label(labels, &menu_item.label);
+            let mut label_width = self.text_widths.width(ui, &label, &menu_font) + label_padding;
+            if let Some(badge) = menu_item.badge.filter(|&count| count > 0) {
+                let badge_text = badge_label(badge);
+                let text_width = self.text_widths.width(ui, &badge_text, &badge_font);
+                label_width += badge_gap + (text_width + badge_padding).max(badge_height);
             }
-            // Reset one-frame guards at the end of the keyboard nav cycle
-            if self.submenu_just_opened_frame {
-                self.submenu_just_opened_frame = false;
+            // Menus aligned right never collapse
+            if menu_item.align_right {
+                pinned_width += label_width;
+            } else {
+                widths.push(Some(label_width));
             }
-            // force_open_child_subitem persists until user navigates away or presses Left; do not reset here
         }
+
+        if !self.menu_overflow {
+            return widths.iter().flatten().sum::<f32>() + pinned_width;
+        }
+        let overflow_width = self.text_widths.width(ui, OVERFLOW_LABEL, &menu_font) + label_padding;
+        layout::bar_width(&widths, room - pinned_width, overflow_width) + pinned_width
     }
 
     /// Render menu items using native-style rendering (similar to Glitchine)
@@ -426,183 +1089,323 @@ impl TitleBar {
     /// # Arguments
     /// * `ui` - The egui UI context
     pub fn render_menu_items(&mut self, ui: &mut Ui, ctx: &Context) {
+        // The macOS menu bar handles shortcuts and navigation of its own
+        if self.uses_native_menubar() {
+            #[cfg(target_os = "macos")]
+            self.sync_native_menubar(ctx);
+            return;
+        }
+
         // Check for keyboard shortcuts and navigation first
         self.check_keyboard_shortcuts(ctx);
         self.handle_keyboard_navigation(ctx);
+        self.pointer_over_menu_label = false;
 
         if self.menu_items.is_empty() && self.menu_items_with_submenus.is_empty() {
             return;
         }
 
-        let menu_height = 28.0; // Standard menu height
-
-        // Calculate total width needed for all menus
-        let mut total_width = 0.0;
-        for (label, _) in &self.menu_items {
-            let label_width = ui.fonts_mut(|f| {
-                f.layout_no_wrap(
-                    label.clone(),
-                    FontId::proportional(self.menu_text_size),
-                    self.menu_text_color,
-                )
-                .size()
-                .x
-            }) + 16.0;
-            total_width += label_width;
+        let menu_height = self.compacted(28.0).min(self.target_bar_height());
+        let menu_font = FontId::proportional(self.scaled(self.menu_text_size));
+        let label_padding = self.compacted(16.0);
+        let badge_font = FontId::proportional(self.scaled(self.menu_text_size * 0.75));
+        let badge_gap = self.scaled(4.0);
+        let badge_height = badge_font.size + self.scaled(4.0);
+        let badge_padding = self.scaled(8.0);
+
+        let rtl = self.rtl;
+        let labels = self.label_resolver.clone();
+        let labels = labels.as_deref();
+
+        // Label width and badge (text and pill width) of each visible submenu
+        let submenu_labels: Vec<_> = self
+            .menu_items_with_submenus
+            .iter()
+            .map(|menu_item| {
+                if !menu_item.visible {
+                    return None;
+                }
+                let label = resolve_label(labels, &menu_item.label);
+                let text_width = self.text_widths.width(ui, &label, &menu_font);
+                let badge = menu_item.badge.filter(|&count| count > 0).map(|count| {
+                    let badge_text = badge_label(count);
+                    let badge_text_width = self.text_widths.width(ui, &badge_text, &badge_font);
+                    let pill_width = (badge_text_width + badge_padding).max(badge_height);
+                    (badge_text, pill_width)
+                });
+                Some((text_width, badge))
+            })
+            .collect();
+        let mut widths: Vec<Option<f32>> = self
+            .menu_items
+            .iter()
+            .map(|(label, _)| {
+                let label = resolve_label(labels, label);
+                Some(self.text_widths.width(ui, &label, &menu_font) + label_padding)
+            })
+            .chain(submenu_labels.iter().map(|label| {
+                label.as_ref().map(|(text_width, badge)| {
+                    text_width
+                        + label_padding
+                        + badge
+                            .as_ref()
+                            .map_or(0.0, |(_, pill_width)| badge_gap + pill_width)
+                })
+            }))
+            .collect();
+
+        // Menus aligned right are pinned to the end of the room left to the
+        // menus, apart from the others
+        let simple_menus = self.menu_items.len();
+        let pinned: Vec<Option<f32>> = widths
+            .iter_mut()
+            .enumerate()
+            .map(|(index, width)| {
+                let menu = index
+                    .checked_sub(simple_menus)
+                    .and_then(|index| self.menu_items_with_submenus.get(index));
+                menu.filter(|menu| menu.align_right)
+                    .and_then(|_| width.take())
+            })
+            .collect();
+        let pinned_width = pinned.iter().flatten().sum::<f32>();
+
+        // Menus that don't fit collapse into the "»" menu at the end of the bar
+        let room = self
+            .menu_room
+            .take()
+            .unwrap_or_else(|| ui.available_width());
+        let overflow_width = self.text_widths.width(ui, OVERFLOW_LABEL, &menu_font) + label_padding;
+        self.overflow_menus.clear();
+        if self.menu_overflow
+            && let Some(start) =
+                layout::overflow_start(&widths, room - pinned_width, overflow_width)
+        {
+            for (index, width) in widths.iter_mut().enumerate().skip(start) {
+                if width.take().is_some() {
+                    self.overflow_menus.push(index);
+                }
+            }
+            widths.push(Some(overflow_width));
         }
-        for menu_item in &self.menu_items_with_submenus {
-            let label_width = ui.fonts_mut(|f| {
-                f.layout_no_wrap(
-                    menu_item.label.clone(),
-                    FontId::proportional(self.menu_text_size),
-                    self.menu_text_color,
-                )
-                .size()
-                .x
-            }) + 16.0;
-            total_width += label_width;
+        if self.overflow_menus.is_empty() {
+            self.overflow_menu_open = false;
         }
 
         // Allocate space for the entire menu bar
+        let room_end = ui.cursor().min.x + room;
+        let total_width = widths.iter().flatten().sum::<f32>();
         let (menu_bar_rect, _) =
             ui.allocate_exact_size(egui::Vec2::new(total_width, menu_height), Sense::click());
+        self.exclude_from_drag(menu_bar_rect);
 
-        let mut current_x = menu_bar_rect.min.x;
-
-        // Clear and rebuild menu positions
-        self.menu_positions.clear();
+        // Rebuild menu positions, indexed like the menus; collapsed menus
+        // open under the "»" button
+        let mut menu_rects = layout::menu_rects(menu_bar_rect, &widths, rtl);
+        let overflow = if self.overflow_menus.is_empty() {
+            None
+        } else {
+            menu_rects.pop()
+        };
+        if pinned_width > 0.0 {
+            let pinned_start = (room_end - pinned_width).max(menu_bar_rect.max.x);
+            let pinned_rect = Rect::from_x_y_ranges(
+                pinned_start..=pinned_start + pinned_width,
+                menu_bar_rect.y_range(),
+            );
+            self.exclude_from_drag(pinned_rect);
+            for (index, placed) in layout::menu_rects(pinned_rect, &pinned, rtl)
+                .into_iter()
+                .enumerate()
+            {
+                if pinned[index].is_some() {
+                    menu_rects[index] = placed;
+                }
+            }
+        }
+        self.overflow_position = overflow.map(|(start, _)| start);
+        self.menu_positions = menu_rects
+            .iter()
+            .enumerate()
+            .map(|(index, &(start, _))| match overflow {
+                Some((overflow_start, _)) if self.overflow_menus.contains(&index) => overflow_start,
+                _ => start,
+            })
+            .collect();
 
         // Render simple menu items
         for (index, (label, callback)) in self.menu_items.iter().enumerate() {
-            let label_width = ui.fonts_mut(|f| {
-                f.layout_no_wrap(
-                    label.clone(),
-                    FontId::proportional(self.menu_text_size),
-                    self.menu_text_color,
-                )
-                .size()
-                .x
-            }) + 16.0;
-
-            // Store the position of this menu item
-            self.menu_positions.push(current_x);
-
-            // Create individual menu rect
-            let menu_rect = Rect::from_min_size(
-                Pos2::new(current_x, menu_bar_rect.min.y),
-                Vec2::new(label_width, menu_height),
-            );
+            let Some(menu_rect) = menu_rects[index].1 else {
+                continue;
+            };
+            let label = resolve_label(labels, label);
+            let enabled = self.is_menu_item_enabled(index);
 
             // Interact with the menu area
             let response = ui.interact(
                 menu_rect,
-                Id::new(format!("menu_{}", label)),
+                self.id.with(("simple_menu", index)),
                 Sense::click(),
             );
 
+            #[cfg(feature = "accesskit")]
+            describe_menu_item(
+                ctx,
+                response.id,
+                MenuNode {
+                    label: &label,
+                    enabled,
+                    checked: None,
+                    expanded: None,
+                    shortcut: None,
+                },
+            );
+
             // Check if this menu item is selected by keyboard navigation
             let is_keyboard_selected =
                 self.keyboard_navigation_active && self.selected_menu_index == Some(index);
 
             // Handle hover effect or keyboard selection (render background first)
-            if response.hovered() || is_keyboard_selected {
-                let highlight_color = if is_keyboard_selected {
-                    // Use configurable keyboard selection color
-                    self.keyboard_selection_color
-                } else {
-                    self.menu_hover_color
-                };
-                ui.painter()
-                    .rect_filled(menu_rect, CornerRadius::same(2), highlight_color);
-                ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+            if enabled {
+                self.paint_menu_label_highlight(ui, &response, menu_rect, is_keyboard_selected);
             }
 
             // Render menu text centered (always rendered on top)
             let text_color = if is_keyboard_selected {
                 Color32::WHITE // White text on keyboard selection background
-            } else {
+            } else if enabled {
                 self.menu_text_color
+            } else {
+                Color32::from_rgb(150, 150, 150) // Disabled color
             };
 
             ui.painter().text(
                 menu_rect.center(),
                 Align2::CENTER_CENTER,
-                label,
-                FontId::proportional(self.menu_text_size),
+                &label,
+                menu_font.clone(),
                 text_color,
             );
 
             // Handle click
-            if response.clicked() {
+            if response.clicked() && enabled {
                 if let Some(callback) = callback {
                     callback();
                 }
+                self.send_simple_menu_action(index);
             }
-
-            // Move to next menu position
-            current_x += label_width;
         }
 
         // Render menu items with submenus
-        for (index, menu_item) in self.menu_items_with_submenus.iter().enumerate() {
-            let label_width = ui.fonts_mut(|f| {
-                f.layout_no_wrap(
-                    menu_item.label.clone(),
-                    FontId::proportional(self.menu_text_size),
-                    self.menu_text_color,
-                )
-                .size()
-                .x
-            }) + 16.0;
-
-            // Store the position of this menu item (offset by simple menu count)
-            self.menu_positions.push(current_x);
-
-            // Create individual menu rect
-            let menu_rect = Rect::from_min_size(
-                Pos2::new(current_x, menu_bar_rect.min.y),
-                Vec2::new(label_width, menu_height),
-            );
+        for ((index, menu_item), label) in self
+            .menu_items_with_submenus
+            .iter()
+            .enumerate()
+            .zip(submenu_labels)
+        {
+            // Offset by the simple menu count
+            let menu_index = self.menu_items.len() + index;
+            let (Some((text_width, badge)), Some(menu_rect)) = (label, menu_rects[menu_index].1)
+            else {
+                continue;
+            };
+            let label = resolve_label(labels, &menu_item.label);
 
-            // Interact with the menu area
-            let response = ui.interact(
-                menu_rect,
-                Id::new(format!("submenu_{}", menu_item.label)),
-                Sense::click(),
-            );
+            // Interact with the menu area; tear-off menus can also be dragged
+            let sense = if menu_item.tear_off {
+                Sense::click_and_drag()
+            } else {
+                Sense::click()
+            };
+            let response = ui.interact(menu_rect, menu_item.widget_id(self.id, index), sense);
+            // Tooltips would cover the submenu, so only show them while closed
+            let response = match &menu_item.tooltip {
+                Some(tooltip) if self.open_submenu != Some(index) => {
+                    response.on_hover_text(tooltip)
+                }
+                _ => response,
+            };
 
             // Check if this menu item is selected by keyboard navigation
-            let menu_index = self.menu_items.len() + index;
             let is_keyboard_selected =
                 self.keyboard_navigation_active && self.selected_menu_index == Some(menu_index);
 
             // Handle hover effect or keyboard selection
-            if response.hovered() || is_keyboard_selected {
-                let highlight_color = if is_keyboard_selected {
-                    // Use configurable keyboard selection color
-                    self.keyboard_selection_color
-                } else {
-                    self.menu_hover_color
-                };
-                ui.painter()
-                    .rect_filled(menu_rect, CornerRadius::same(2), highlight_color);
-                ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+            if menu_item.enabled {
+                self.paint_menu_label_highlight(ui, &response, menu_rect, is_keyboard_selected);
             }
 
-            // Handle click to toggle submenu
-            if response.clicked() {
-                // Toggle submenu: close if same, open if different
+            // Menus opened by hovering stay open while the pointer is on a label
+            if response.hovered() {
+                self.pointer_over_menu_label = true;
+            }
+            let hover_activation = self.menu_activation == MenuActivation::Hover;
+
+            // Handle click to toggle submenu; disabled menus stay closed
+            if response.clicked() && menu_item.enabled {
+                // Toggle submenu: close if same, open if different. Menus
+                // opened by hovering stay open until the pointer leaves.
                 if self.open_submenu == Some(index) {
-                    self.open_submenu = None;
-                    self.submenu_just_opened_frame = false;
+                    if !hover_activation {
+                        self.open_submenu = None;
+                        self.submenu_just_opened_frame = false;
+                    }
                 } else {
                     self.open_submenu = Some(index);
+                    self.submenu_selection.clear();
+                    self.overflow_menu_open = false;
                     self.submenu_just_opened_frame = true;
                     // Generate unique click ID
-                    self.last_click_id = SUBMENU_CLICK_COUNTER.fetch_add(1, Ordering::Relaxed);
+                    self.last_click_id = self.submenu_click_counter;
+                    self.submenu_click_counter += 1;
                 }
+            } else if response.hovered()
+                && menu_item.enabled
+                && self.open_submenu != Some(index)
+                && (hover_activation || self.overflow_menu_open || self.open_submenu.is_some())
+            {
+                // Hovering opens menus, or switches to another once one is open
+                self.open_submenu = Some(index);
+                self.submenu_selection.clear();
+                self.overflow_menu_open = false;
+                self.submenu_just_opened_frame = true;
+                self.last_click_id = self.submenu_click_counter;
+                self.submenu_click_counter += 1;
+            }
+            // Dragged off the bar, the menu becomes a palette following the pointer
+            if menu_item.tear_off
+                && response.dragged()
+                && let Some(pointer) = response.interact_pointer_pos()
+                && !menu_bar_rect.contains(pointer)
+            {
+                self.torn_off_menus
+                    .insert(index, pointer - self.torn_off_grab_offset());
+                self.open_submenu = None;
+            }
+            if response.clicked_by(PointerButton::Middle)
+                && let Some(callback) = &menu_item.on_middle_click
+            {
+                callback();
+            }
+            if response.clicked_by(PointerButton::Secondary)
+                && let Some(callback) = &menu_item.on_secondary_click
+            {
+                callback();
             }
 
+            #[cfg(feature = "accesskit")]
+            describe_menu_item(
+                ctx,
+                response.id,
+                MenuNode {
+                    label: &label,
+                    enabled: menu_item.enabled,
+                    checked: None,
+                    expanded: Some(self.open_submenu == Some(index)),
+                    shortcut: None,
+                },
+            );
+
             // Render menu text centered (always rendered on top)
             let text_color = if is_keyboard_selected {
                 Color32::WHITE // White text on keyboard selection background
@@ -612,16 +1415,48 @@ impl TitleBar {
                 Color32::from_rgb(150, 150, 150) // Disabled color
             };
 
+            // The badge follows the label in reading order
+            let text_center_x = if rtl {
+                menu_rect.max.x - (label_padding + text_width) / 2.0
+            } else {
+                menu_rect.min.x + (label_padding + text_width) / 2.0
+            };
+            let text_center = Pos2::new(text_center_x, menu_rect.center().y);
             ui.painter().text(
-                menu_rect.center(),
+                text_center,
                 Align2::CENTER_CENTER,
-                &menu_item.label,
-                FontId::proportional(self.menu_text_size),
+                &label,
+                menu_font.clone(),
                 text_color,
             );
 
-            // Move to next menu position
-            current_x += label_width;
+            if let Some((badge_text, pill_width)) = badge {
+                let pill_min_x = if rtl {
+                    menu_rect.max.x - label_padding / 2.0 - text_width - badge_gap - pill_width
+                } else {
+                    menu_rect.min.x + label_padding / 2.0 + text_width + badge_gap
+                };
+                let pill_rect = Rect::from_min_size(
+                    Pos2::new(pill_min_x, menu_rect.center().y - badge_height / 2.0),
+                    Vec2::new(pill_width, badge_height),
+                );
+                ui.painter().rect_filled(
+                    pill_rect,
+                    CornerRadius::same((badge_height / 2.0).min(255.0) as u8),
+                    self.menu_badge_color,
+                );
+                ui.painter().text(
+                    pill_rect.center(),
+                    Align2::CENTER_CENTER,
+                    badge_text,
+                    badge_font.clone(),
+                    self.menu_badge_text_color,
+                );
+            }
+        }
+
+        if let Some((_, Some(overflow_rect))) = overflow {
+            self.render_overflow_button(ui, overflow_rect, &menu_font);
         }
     }
     /// Add a menu item to the title bar
@@ -646,135 +1481,351 @@ impl TitleBar {
         self.menu_items.push((label.to_string(), callback));
         self
     }
+
+    /// Enable or disable the simple menu item at `index`
+    ///
+    /// Disabled items are grayed out, ignore clicks and are passed over by
+    /// keyboard navigation, like menus with submenus whose `enabled` is
+    /// false.
+    ///
+    /// # Arguments
+    /// * `index` - Position of the item among those added with `add_menu_item()`
+    /// * `enabled` - Whether the item can be clicked
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let mut title_bar = TitleBar::default();
+    /// # let document: Option<String> = None;
+    /// title_bar.set_menu_item_enabled(0, document.is_some());
+    /// ```
+    pub fn set_menu_item_enabled(&mut self, index: usize, enabled: bool) {
+        self.disabled_menu_items
+            .retain(|&disabled| disabled != index);
+        if !enabled {
+            self.disabled_menu_items.push(index);
+        }
+    }
+
+    /// Whether the simple menu item at `index` can be clicked.
+    pub fn is_menu_item_enabled(&self, index: usize) -> bool {
+        !self.disabled_menu_items.contains(&index)
+    }
     /// Add a menu item with submenu support to the title bar
     ///
-    /// This method allows you to create dropdown menus with subitems that support
-    /// keyboard shortcuts, separators, and individual callbacks.
+    /// This method allows you to create dropdown menus with subitems that support
+    /// keyboard shortcuts, separators, and individual callbacks.
+    ///
+    /// # Arguments
+    /// * `menu_item` - A MenuItem struct containing the menu label and subitems
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let file_menu = MenuItem::new("File")
+    ///     .add_subitem(SubMenuItem::new("New")
+    ///         .with_shortcut(KeyboardShortcut::new("N").with_ctrl())
+    ///         .with_callback(Box::new(|| println!("New file!"))))
+    ///     .add_subitem(SubMenuItem::new("Open")
+    ///         .with_shortcut(KeyboardShortcut::new("O").with_ctrl())
+    ///         .with_callback(Box::new(|| println!("Open file!"))))
+    ///     .add_subitem(SubMenuItem::new("Save")
+    ///         .with_shortcut(KeyboardShortcut::new("S").with_ctrl())
+    ///         .with_callback(Box::new(|| println!("Save file!")))
+    ///         .with_separator())
+    ///     .add_subitem(SubMenuItem::new("Exit").disabled());
+    ///
+    /// title_bar.add_menu_with_submenu(file_menu);
+    /// ```
+    pub fn add_menu_with_submenu(mut self, menu_item: MenuItem) -> Self {
+        self.menu_items_with_submenus.push(menu_item);
+        self
+    }
+
+    /// Send every activated menu entry to a channel as a `MenuAction`
+    ///
+    /// Whenever a menu or submenu entry is clicked, chosen with the keyboard
+    /// or triggered by its shortcut, its label path and id are sent here
+    /// along with running its callback, so the app can handle all of its
+    /// menus in one `match` while draining the receiver each frame. Entries
+    /// need neither a callback nor a message to be reported, and a
+    /// disconnected receiver is not an error.
+    ///
+    /// # Arguments
+    /// * `sender` - Sending half of the app's action channel
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::{MenuItem, SubMenuItem, TitleBar, TitleBarOptions};
+    /// # struct App;
+    /// # impl App {
+    /// # fn save(&mut self) {}
+    /// # fn update(&mut self) {
+    /// let (sender, receiver) = std::sync::mpsc::channel();
+    /// let title_bar = TitleBar::new(TitleBarOptions::new())
+    ///     .with_action_sender(sender)
+    ///     .add_menu_with_submenu(
+    ///         MenuItem::new("File").add_subitem(SubMenuItem::new("Save").with_id("save")),
+    ///     );
+    ///
+    /// // In update()
+    /// for action in receiver.try_iter() {
+    ///     match action.id.as_deref() {
+    ///         Some("save") => self.save(),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # let _ = title_bar;
+    /// # }
+    /// # }
+    /// ```
+    pub fn with_action_sender(mut self, sender: Sender<MenuAction>) -> Self {
+        self.action_sender = Some(sender);
+        self
+    }
+
+    /// Treat menu labels as keys and show the text `resolver` returns for them
+    ///
+    /// Labels of the bar's menus, submenus and side menus are resolved each
+    /// frame, so a resolver reading the current language switches the menus
+    /// without rebuilding them. Widths are measured on the resolved text.
+    /// Without a resolver, labels are shown as written.
+    ///
+    /// # Arguments
+    /// * `resolver` - Maps a label key to the text to show
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::{MenuItem, SubMenuItem, TitleBar, TitleBarOptions};
+    /// # struct Translations;
+    /// # impl Translations { fn get(&self, key: &str) -> String { key.to_string() } }
+    /// # let translations = Translations;
+    /// let title_bar = TitleBar::new(TitleBarOptions::new())
+    ///     .with_label_resolver(Box::new(move |key| translations.get(key)))
+    ///     .add_menu_with_submenu(
+    ///         MenuItem::new("menu.file").add_subitem(SubMenuItem::new("menu.file.open")),
+    ///     );
+    /// ```
+    pub fn with_label_resolver(mut self, resolver: Box<LabelResolverFn>) -> Self {
+        self.label_resolver = Some(Arc::from(resolver));
+        self
+    }
+
+    /// Send the message of an activated item to its channel, if it has one.
+    pub(crate) fn send_menu_message(&self, item: &SubMenuItem) {
+        if let Some(send) = &item.message {
+            send();
+        }
+    }
+
+    /// Queue the state callback of an activated item on its handle, if it has one.
+    pub(crate) fn queue_state_callback(&self, item: &SubMenuItem) {
+        if let Some(queue) = &item.state_callback {
+            queue();
+        }
+    }
+
+    /// Send the entry at `path` below `menu` to the action channel, if set.
+    pub(crate) fn send_menu_action(&self, menu: &MenuItem, path: &[usize]) {
+        let Some(sender) = &self.action_sender else {
+            return;
+        };
+        let mut labels = vec![menu.label.clone()];
+        let mut id = menu.id.clone();
+        let mut items = &menu.subitems;
+        for &index in path {
+            let Some(item) = items.get(index) else {
+                return;
+            };
+            labels.push(item.label.clone());
+            id = item.id.clone();
+            items = &item.children;
+        }
+        let _ = sender.send(MenuAction { path: labels, id });
+    }
+
+    /// Send the simple menu at `index` to the action channel, if set.
+    pub(crate) fn send_simple_menu_action(&self, index: usize) {
+        let Some(sender) = &self.action_sender else {
+            return;
+        };
+        if let Some((label, _)) = self.menu_items.get(index) {
+            let _ = sender.send(MenuAction {
+                path: vec![label.clone()],
+                id: None,
+            });
+        }
+    }
+
+    /// Regenerate the entries of a dynamic submenu that has just opened
+    ///
+    /// Runs the generator once per opening; the entries are kept in `subitems`
+    /// until the menu is opened again. Keyboard selection is reset to match the
+    /// new entries.
+    fn refresh_dynamic_submenu(&mut self) {
+        if self.open_submenu == self.generated_submenu {
+            return;
+        }
+        self.generated_submenu = self.open_submenu;
+
+        let Some(open_index) = self.open_submenu else {
+            return;
+        };
+        let Some(menu_item) = self.menu_items_with_submenus.get_mut(open_index) else {
+            return;
+        };
+        let Some(generator) = &menu_item.dynamic_subitems else {
+            return;
+        };
+        menu_item.subitems = generator();
+        self.reset_submenu_selection(open_index);
+    }
+
+    /// Reset the keyboard selection of an open submenu after its entries changed
+    ///
+    /// Closes the submenu if it has no entries left; otherwise keyboard
+    /// navigation moves to the first entry it can land on, if there is one.
+    fn reset_submenu_selection(&mut self, index: usize) {
+        let Some(menu_item) = self.menu_items_with_submenus.get(index) else {
+            return;
+        };
+
+        self.submenu_selection.clear();
+        if menu_item.subitems.is_empty() {
+            self.open_submenu = None;
+            self.generated_submenu = None;
+        } else if self.keyboard_navigation_active
+            && let Some(first) = menu_item
+                .subitems
+                .iter()
+                .position(SubMenuItem::is_keyboard_navigable)
+        {
+            self.submenu_selection.push(first);
+        }
+    }
+
+    /// Replace the entries of a menu
+    ///
+    /// If the menu is open, its keyboard selection is reset so it never
+    /// points past the new entries. Menus with dynamic entries regenerate
+    /// them on their next opening. Returns `false` if no menu matches.
     ///
     /// # Arguments
-    /// * `menu_item` - A MenuItem struct containing the menu label and subitems
+    /// * `menu` - Id or label of the menu
+    /// * `items` - The new entries
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let file_menu = MenuItem::new("File")
-    ///     .add_subitem(SubMenuItem::new("New")
-    ///         .with_shortcut(KeyboardShortcut::new("N").with_ctrl())
-    ///         .with_callback(Box::new(|| println!("New file!"))))
-    ///     .add_subitem(SubMenuItem::new("Open")
-    ///         .with_shortcut(KeyboardShortcut::new("O").with_ctrl())
-    ///         .with_callback(Box::new(|| println!("Open file!"))))
-    ///     .add_subitem(SubMenuItem::new("Save")
-    ///         .with_shortcut(KeyboardShortcut::new("S").with_ctrl())
-    ///         .with_callback(Box::new(|| println!("Save file!")))
-    ///         .with_separator())
-    ///     .add_subitem(SubMenuItem::new("Exit").disabled());
-    ///
-    /// title_bar.add_menu_with_submenu(file_menu);
+    /// # use egui_desktop::{SubMenuItem, TitleBar};
+    /// # struct App { recent_files: Vec<String> }
+    /// # impl App {
+    /// # fn refresh(&self, title_bar: &mut TitleBar) {
+    /// let recent = self.recent_files.iter().map(|path| SubMenuItem::new(path)).collect();
+    /// title_bar.set_submenu_items("Open Recent", recent);
+    /// # }
+    /// # }
     /// ```
-    pub fn add_menu_with_submenu(mut self, menu_item: MenuItem) -> Self {
-        self.menu_items_with_submenus.push(menu_item);
-        self
+    pub fn set_submenu_items(&mut self, menu: &str, items: Vec<SubMenuItem>) -> bool {
+        let Some(index) = MenuItem::position_by_path_segment(&self.menu_items_with_submenus, menu)
+        else {
+            return false;
+        };
+        self.menu_items_with_submenus[index].subitems = items;
+        if self.open_submenu == Some(index) {
+            self.reset_submenu_selection(index);
+        }
+        true
     }
 
     /// Render the currently open submenu as an overlay
     pub fn render_open_submenu(&mut self, ctx: &Context) {
+        self.render_overflow_menu(ctx);
+        self.refresh_dynamic_submenu();
+        let open_progress = self.submenu_open_progress(ctx);
         if let Some(open_index) = self.open_submenu {
             if let Some(menu_item) = self.menu_items_with_submenus.get(open_index) {
-                if !menu_item.subitems.is_empty() {
-                    // Use reference instead of clone to preserve callbacks
-                    let menu_text_size = self.menu_text_size;
-                    let submenu_background_color = self.submenu_background_color;
-                    let submenu_text_color = self.submenu_text_color;
-                    let submenu_hover_color = self.submenu_hover_color;
-                    let submenu_shortcut_color = self.submenu_shortcut_color;
-                    let submenu_border_color = self.submenu_border_color;
-                    let submenu_keyboard_selection_color = self.submenu_keyboard_selection_color;
-                    let keyboard_navigation_active = self.keyboard_navigation_active;
-                    let submenu_selections = self.submenu_selections.clone();
-                    let force_open_child_subitem = self.force_open_child_subitem;
-                    let child_submenu_selections = self.child_submenu_selections.clone();
-
-                    // Calculate submenu position using stored menu positions
-                    let submenu_x = if let Some(menu_x) =
+                if menu_item.visible && menu_item.subitems.iter().any(|s| s.visible) {
+                    let menu_id = menu_item.widget_id(self.id, open_index);
+                    // Line up with the menu label laid out by `render_menu_items`. Until
+                    // the menus have been laid out, e.g. for a menu opened from code
+                    // before the bar was first shown, wait a frame instead of guessing
+                    // where the label is: the bars don't agree on what precedes it.
+                    let Some(&submenu_x) =
                         self.menu_positions.get(self.menu_items.len() + open_index)
-                    {
-                        *menu_x
+                    else {
+                        ctx.request_repaint();
+                        return;
+                    };
+                    // Below the bar, or above it when the bar is docked at the bottom
+                    let bar_rect = self.bar_rect_or_default(ctx);
+                    let open_upward = self.position == TitleBarEdge::Bottom;
+                    let anchor_y = if open_upward {
+                        bar_rect.min.y
                     } else {
-                        // Fallback to old calculation if positions not available
-                        let mut menu_x = 16.0 + 20.0 + 8.0; // icon + title space + padding
-                        for i in 0..open_index {
-                            if let Some(item) = self.menu_items_with_submenus.get(i) {
-                                // Fallback: use character count approximation for positioning
-                                menu_x += item.label.len() as f32 * (menu_text_size * 0.6) + 16.0;
-                            }
-                        }
-                        menu_x
+                        bar_rect.max.y
+                    };
+                    let submenu_position = Pos2::new(submenu_x, anchor_y);
+
+                    // An opening menu slides toward the bar into its place
+                    let slide = if self.submenu_animation_slide {
+                        (1.0 - open_progress) * self.scaled(6.0)
+                    } else {
+                        0.0
                     };
-                    let submenu_position = Pos2::new(submenu_x, 32.0); // Below title bar
+                    let drawn_position =
+                        submenu_position + Vec2::new(0.0, if open_upward { slide } else { -slide });
 
-                    // Use a RefCell to allow modification from within the closure
-                    let item_clicked = RefCell::new(false);
+                    let mut popup = self.popup_menu(menu_item, menu_id);
+                    if self.keyboard_navigation_active {
+                        popup = popup.with_keyboard(self.submenu_selection.clone());
+                    }
+                    let text_widths = &mut self.text_widths;
 
-                    // Create a full-screen area to capture clicks outside
-                    Area::new(egui::Id::new(format!("submenu_overlay_{}", open_index)))
+                    // The area only holds the menu widgets (clicks outside are read from
+                    // the input). It starts out empty so that its first frame doesn't
+                    // cover the resize handles along the window edges.
+                    let response = Area::new(self.id.with(("submenu_overlay", open_index)))
                         .fixed_pos(Pos2::ZERO)
+                        .default_size(Vec2::ZERO)
                         .order(Order::Foreground)
                         .show(ctx, |ui| {
-                            // Render the submenu at the calculated position
-                            let clicked = Self::render_submenu_overlay_static(
-                                ui,
-                                menu_item, // Pass reference instead of clone
-                                submenu_position,
-                                menu_text_size,
-                                submenu_background_color,
-                                submenu_text_color,
-                                submenu_hover_color,
-                                submenu_shortcut_color,
-                                submenu_border_color,
-                                submenu_keyboard_selection_color,
-                                keyboard_navigation_active,
-                                submenu_selections.get(&open_index).copied(),
-                                force_open_child_subitem,
-                                child_submenu_selections.get(&open_index).copied(),
-                                open_index, // Pass parent submenu index
-                            );
-
-                            // Store the click result
-                            *item_clicked.borrow_mut() = clicked;
-                        });
+                            ui.multiply_opacity(open_progress);
+                            popup.show_in(ui, drawn_position, text_widths)
+                        })
+                        .inner;
 
                     // Close submenu if an item was clicked
-                    if *item_clicked.borrow() {
+                    if let Some(path) = &response.activated {
+                        if let Some(menu) = self.menu_items_with_submenus.get(open_index)
+                            && let Some(item) = SubMenuItem::at_path(&menu.subitems, path)
+                        {
+                            self.send_menu_message(item);
+                            self.send_menu_action(menu, path);
+                            self.queue_state_callback(item);
+                        }
                         self.open_submenu = None;
                         self.submenu_just_opened_frame = false;
                     }
 
-                    // Check for clicks outside the submenu area using input detection
-                    if ctx.input(|i| i.pointer.primary_clicked()) {
-                        let current_click_id = SUBMENU_CLICK_COUNTER.load(Ordering::Relaxed);
-                        let click_pos = ctx.input(|i| i.pointer.interact_pos()).unwrap_or_default();
-                        let submenu_rect =
-                            Rect::from_min_size(submenu_position, Vec2::new(200.0, 100.0));
-
-                        // Only close if this is a different click than the one that opened the submenu
-                        if current_click_id > self.last_click_id {
-                            // Close if click is outside submenu and not in menu bar
-                            let menu_bar_rect = Rect::from_min_size(
-                                Pos2::new(0.0, 0.0),
-                                Vec2::new(ctx.content_rect().width(), 32.0),
-                            );
-
-                            if !submenu_rect.contains(click_pos)
-                                && !menu_bar_rect.contains(click_pos)
-                            {
-                                // Close all menus but keep keyboard navigation active
-                                self.open_submenu = None;
-                                self.force_open_child_subitem = None;
-                                self.child_submenu_selections.clear();
-                                // Keep keyboard_navigation_active = true (don't disable it)
-                            }
-                        }
+                    // Close on a click outside the menus and the menu bar, unless it is
+                    // the click that opened the submenu
+                    let current_click_id = self.submenu_click_counter;
+                    let click_pos = ctx.input(|i| i.pointer.interact_pos()).unwrap_or_default();
+                    if response.clicked_elsewhere
+                        && current_click_id > self.last_click_id
+                        && !bar_rect.contains(click_pos)
+                    {
+                        // Close all menus but keep keyboard navigation active
+                        self.open_submenu = None;
+                        self.submenu_selection.clear();
+                    }
+                    if self.hovered_away(ctx, &response.rects) {
+                        self.open_submenu = None;
+                        self.submenu_selection.clear();
                     }
 
                     // Reset the flag after first frame
@@ -786,127 +1837,455 @@ impl TitleBar {
         }
     }
 
+    /// Whether a menu opened by hovering should close
+    ///
+    /// True once the pointer has been off the menu labels and `rects` for
+    /// `HOVER_CLOSE_DELAY`; always false when menus open on click or while
+    /// keyboard navigation drives them.
+    pub(crate) fn hovered_away(&mut self, ctx: &Context, rects: &[Rect]) -> bool {
+        if self.menu_activation != MenuActivation::Hover || self.keyboard_navigation_active {
+            return false;
+        }
+        let over_menu = self.pointer_over_menu_label
+            || ctx
+                .input(|i| i.pointer.hover_pos())
+                .is_some_and(|pos| rects.iter().any(|rect| rect.contains(pos)));
+        if over_menu {
+            self.menu_hover_left_at = None;
+            return false;
+        }
+        let now = ctx.input(|i| i.time);
+        let away = now - *self.menu_hover_left_at.get_or_insert(now);
+        if away < HOVER_CLOSE_DELAY {
+            ctx.request_repaint_after(Duration::from_secs_f64(HOVER_CLOSE_DELAY - away));
+            return false;
+        }
+        self.menu_hover_left_at = None;
+        true
+    }
+
+    /// How far the open menu is through its open animation, from 0 to 1
+    ///
+    /// Every menu's animation is advanced each frame so one that was closed
+    /// starts from 0 when it opens again; egui repaints until it finishes.
+    fn submenu_open_progress(&self, ctx: &Context) -> f32 {
+        let Some(duration) = self.submenu_animation.filter(|_| self.animations) else {
+            return 1.0;
+        };
+        let mut progress = 1.0;
+        for index in 0..self.menu_items_with_submenus.len() {
+            let open = self.open_submenu == Some(index);
+            let value = ctx.animate_bool_with_time(
+                self.id.with(("submenu_animation", index)),
+                open,
+                duration.as_secs_f32(),
+            );
+            if open {
+                progress = value;
+            }
+        }
+        progress
+    }
+
+    /// Paint the background of a menu label: the keyboard selection, or the
+    /// hover highlight fading in and out.
+    pub(crate) fn paint_menu_label_highlight(
+        &self,
+        ui: &Ui,
+        response: &Response,
+        menu_rect: Rect,
+        is_keyboard_selected: bool,
+    ) {
+        let hover = Self::hover_fade(
+            ui.ctx(),
+            response.id,
+            response.hovered(),
+            self.hover_animation_time(),
+        );
+        let highlight_color = if is_keyboard_selected {
+            // Use configurable keyboard selection color
+            self.keyboard_selection_color
+        } else {
+            self.menu_hover_color.gamma_multiply(hover)
+        };
+        if is_keyboard_selected || hover > 0.0 {
+            ui.painter()
+                .rect_filled(menu_rect, CornerRadius::same(2), highlight_color);
+        }
+        if response.hovered() || is_keyboard_selected {
+            ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+        }
+    }
+
     /// Render submenu as an overlay at a specific position (static version)
-    /// Returns true if an item was clicked
-    fn render_submenu_overlay_static(
+    ///
+    /// Reports the entry clicked, if any, and the rects of the menu and its
+    /// open side menus; `clicked_elsewhere` is left for the caller.
+    pub(crate) fn render_submenu_overlay_static(
         ui: &mut Ui,
         menu_item: &MenuItem,
+        menu_id: Id,
         position: egui::Pos2,
-        menu_text_size: f32,
-        submenu_background_color: Color32,
-        submenu_text_color: Color32,
-        submenu_hover_color: Color32,
-        submenu_shortcut_color: Color32,
-        submenu_border_color: Color32,
-        submenu_keyboard_selection_color: Color32,
-        keyboard_navigation_active: bool,
-        selected_submenu_index: Option<usize>,
-        force_open_child_subitem: Option<usize>,
-        selected_child_submenu_index: Option<usize>,
-        parent_submenu_index: usize,
-    ) -> bool {
-        // Calculate submenu dimensions
-        let item_height = 24.0;
-        let padding = 8.0;
-        let separator_height = 1.0;
-
-        // Find the maximum width needed
-        let mut max_width: f32 = 120.0; // Minimum width
-        for subitem in &menu_item.subitems {
-            let label_width = ui.fonts_mut(|f| {
-                f.layout_no_wrap(
-                    subitem.label.clone(),
-                    FontId::proportional(menu_text_size),
-                    submenu_text_color,
-                )
-                .size()
-                .x
-            });
-            let shortcut_width = if let Some(ref shortcut) = subitem.shortcut {
-                ui.fonts_mut(|f| {
-                    f.layout_no_wrap(
-                        shortcut.display_string(),
-                        FontId::proportional(menu_text_size * 0.9),
-                        submenu_shortcut_color,
-                    )
-                    .size()
-                    .x
-                })
-            } else {
-                0.0
-            };
-            let total_width = label_width + shortcut_width + padding * 3.0 + 20.0; // Extra space for arrow
-            max_width = max_width.max(total_width);
-        }
+        style: &SubmenuStyle,
+        keyboard_path: Option<&[usize]>,
+        text_widths: &mut TextWidthCache,
+    ) -> PopupMenuResponse {
+        let SubmenuStyle {
+            text_size: menu_text_size,
+            item_height,
+            separator_height,
+            background_color: submenu_background_color,
+            text_color: submenu_text_color,
+            hover_color: submenu_hover_color,
+            shortcut_color: submenu_shortcut_color,
+            border_color: submenu_border_color,
+            separator_color: submenu_separator_color,
+            keyboard_selection_color: submenu_keyboard_selection_color,
+            chevron_color,
+            chevron_size,
+            chevron_style,
+            shadow,
+            corner_radius,
+            min_width,
+            ui_scale,
+            open_upward,
+            hover_delays,
+            hover_animation,
+            rtl,
+            max_visible_items,
+            max_width: max_menu_width,
+            label_resolver,
+        } = *style;
+        let keyboard_navigation_active = keyboard_path.is_some();
+        let keyboard_path = keyboard_path.unwrap_or_default();
+        let padding = 8.0 * ui_scale;
+
+        // Reserve room for check marks when any item is checkable
+        let check_gutter = Self::check_gutter(&menu_item.subitems) * ui_scale;
+
+        // Find the width needed, at least `min_width` and at most `max_menu_width` points
+        let width_cap = max_menu_width.map_or(f32::INFINITY, |limit| limit * ui_scale);
+        let max_width = Self::menu_width(
+            ui,
+            &menu_item.subitems,
+            menu_text_size,
+            chevron_size,
+            ui_scale,
+            label_resolver,
+            text_widths,
+        )
+        .max(min_width * ui_scale)
+        .min(width_cap);
+
+        // Multi-column menus repeat that width once per column in use
+        let visible_items = menu_item.subitems.iter().filter(|i| i.visible).count();
+        let columns = menu_item.columns.min(visible_items).max(1);
+        let column_width = max_width;
+        let max_width = column_width * columns as f32;
+
+        let content_height = Self::menu_height(
+            &menu_item.subitems,
+            item_height,
+            separator_height,
+            ui_scale,
+            columns,
+            None,
+        );
+        let total_height = Self::menu_height(
+            &menu_item.subitems,
+            item_height,
+            separator_height,
+            ui_scale,
+            columns,
+            max_visible_items,
+        );
 
-        let total_height = (item_height * menu_item.subitems.len() as f32)
-            + (separator_height
-                * menu_item
-                    .subitems
-                    .iter()
-                    .filter(|s| s.separator_after)
-                    .count() as f32);
-
-        // Position submenu
-        let submenu_rect = egui::Rect::from_min_size(position, Vec2::new(max_width, total_height));
-
-        // Ensure submenu stays within screen bounds
-        let content_rect = ui.ctx().content_rect();
-        let adjusted_rect = if submenu_rect.max.x > content_rect.max.x {
-            // Move left if it would go off screen
-            Rect::from_min_size(
-                Pos2::new(content_rect.max.x - max_width, submenu_rect.min.y),
-                submenu_rect.size(),
-            )
-        } else {
-            submenu_rect
-        };
+        let content_rect = layout::menu_bounds(ui.ctx().content_rect());
 
-        // Draw submenu background and border
-        ui.painter().rect_filled(
-            adjusted_rect,
-            CornerRadius::same(4),
-            submenu_background_color,
+        // Position submenu, kept within screen bounds
+        let adjusted_rect = layout::submenu_rect(
+            position,
+            Vec2::new(max_width, total_height),
+            open_upward,
+            rtl,
+            content_rect,
         );
+
+        // Draw submenu shadow, background and border
+        let radius = CornerRadius::same(corner_radius);
+        ui.painter().add(shadow.as_shape(adjusted_rect, radius));
+        ui.painter()
+            .rect_filled(adjusted_rect, radius, submenu_background_color);
         ui.painter().rect_stroke(
             adjusted_rect,
-            CornerRadius::same(4),
+            radius,
             Stroke::new(1.0, submenu_border_color),
             StrokeKind::Outside,
         );
 
+        // Long menus capped by `max_visible_items` scroll; the offset is kept
+        // per menu while it stays open
+        let max_scroll = content_height - total_height;
+        let scroll_id = menu_id.with("scroll");
+        let frame = ui.ctx().cumulative_frame_nr();
+        let previous = ui
+            .ctx()
+            .data(|d| d.get_temp::<MenuScroll>(scroll_id))
+            .filter(|state| state.frame + 1 >= frame)
+            .unwrap_or_default();
+        let mut scroll = previous.offset;
+        // The keyboard highlights the first entry of the path here, and the
+        // rest in the side menu it opens
+        let selected = keyboard_path
+            .first()
+            .copied()
+            .filter(|_| keyboard_navigation_active);
+        let arrow_height = 12.0 * ui_scale;
+        if max_scroll > 0.0 {
+            let (pointer, wheel, dt) = ui
+                .ctx()
+                .input(|i| (i.pointer.hover_pos(), i.smooth_scroll_delta.y, i.stable_dt));
+            let hovered = pointer.is_some_and(|pos| adjusted_rect.contains(pos));
+            if hovered && wheel != 0.0 {
+                scroll -= wheel;
+                // Keep the wheel from also scrolling what lies under the menu
+                ui.ctx().input_mut(|i| i.smooth_scroll_delta.y = 0.0);
+            }
+
+            // Resting on a scroll arrow keeps scrolling
+            let speed = item_height * 10.0 * dt;
+            if let Some(pos) = pointer.filter(|_| hovered) {
+                if scroll > 0.0 && pos.y < adjusted_rect.min.y + arrow_height {
+                    scroll -= speed;
+                    ui.ctx().request_repaint();
+                } else if scroll < max_scroll && pos.y > adjusted_rect.max.y - arrow_height {
+                    scroll += speed;
+                    ui.ctx().request_repaint();
+                }
+            }
+
+            // Bring a newly selected row into view; the wheel may move it out again
+            if selected != previous.selected
+                && let Some((top, bottom)) = selected.and_then(|index| {
+                    Self::row_span(
+                        &menu_item.subitems,
+                        index,
+                        item_height,
+                        separator_height,
+                        ui_scale,
+                    )
+                })
+            {
+                let view = total_height - arrow_height * 2.0;
+                if top - arrow_height < scroll {
+                    scroll = top - arrow_height;
+                } else if bottom - arrow_height > scroll + view {
+                    scroll = bottom - arrow_height - view;
+                }
+            }
+            scroll = scroll.clamp(0.0, max_scroll);
+        } else {
+            scroll = 0.0;
+        }
+        ui.ctx().data_mut(|d| {
+            d.insert_temp(
+                scroll_id,
+                MenuScroll {
+                    offset: scroll,
+                    selected,
+                    frame,
+                },
+            )
+        });
+        let show_up_arrow = scroll > 0.0;
+        let show_down_arrow = scroll < max_scroll;
+
+        // Rows are clipped to the menu, minus the visible scroll arrows
+        let parent_clip_rect = ui.clip_rect();
+        let rows_rect = Rect::from_min_max(
+            Pos2::new(
+                adjusted_rect.min.x,
+                adjusted_rect.min.y + if show_up_arrow { arrow_height } else { 0.0 },
+            ),
+            Pos2::new(
+                adjusted_rect.max.x,
+                adjusted_rect.max.y - if show_down_arrow { arrow_height } else { 0.0 },
+            ),
+        );
+        ui.set_clip_rect(rows_rect.intersect(parent_clip_rect));
+
+        // Where the pointer last was over a row with a side menu; while the
+        // pointer stays between there and that side menu, it is heading for it
+        let triangle_id = menu_id.with("safe_triangle");
+        let (pointer, now) = ui.ctx().input(|i| (i.pointer.hover_pos(), i.time));
+        let mut safe_triangle = ui
+            .ctx()
+            .data(|d| d.get_temp::<SafeTriangle>(triangle_id))
+            .filter(|triangle| triangle.frame + 1 >= frame);
+        let heading_to = safe_triangle
+            .filter(|triangle| now - triangle.time < SAFE_TRIANGLE_TIMEOUT)
+            .filter(|triangle| {
+                pointer.is_some_and(|pos| {
+                    layout::in_safe_triangle(triangle.apex, triangle.child_rect, pos)
+                })
+            })
+            .map(|triangle| triangle.item);
+        if let Some(triangle) = safe_triangle.filter(|_| heading_to.is_some()) {
+            // Let the rows crossed on the way take over once the triangle lapses
+            let remaining = SAFE_TRIANGLE_TIMEOUT - (now - triangle.time);
+            ui.ctx()
+                .request_repaint_after(Duration::from_secs_f64(remaining.max(0.0)));
+        }
+
         // Render submenu items
-        let mut current_y = adjusted_rect.min.y;
-        let mut item_clicked = false;
-        for (i, subitem) in menu_item.subitems.iter().enumerate() {
-            let item_rect = Rect::from_min_size(
-                Pos2::new(adjusted_rect.min.x, current_y),
-                Vec2::new(adjusted_rect.width(), item_height),
-            );
+        let mut rows = layout::submenu_rows(
+            &menu_item.subitems,
+            Pos2::new(adjusted_rect.min.x, adjusted_rect.min.y - scroll),
+            column_width,
+            item_height,
+            separator_height,
+            ui_scale,
+            columns,
+        );
+        // Right-to-left menus start their first column at the right
+        if rtl && columns > 1 {
+            let mirror = |rect: Rect| {
+                let (left, right) = (adjusted_rect.min.x, adjusted_rect.max.x);
+                Rect::from_x_y_ranges(
+                    left + right - rect.max.x..=left + right - rect.min.x,
+                    rect.y_range(),
+                )
+            };
+            for row in &mut rows {
+                row.rect = mirror(row.rect);
+                row.separator = row.separator.map(mirror);
+            }
+        }
+        let mut activated = None;
+        let mut rects = vec![adjusted_rect];
+        // Side menus are drawn after the rows, outside the row clipping
+        let mut open_children = Vec::new();
+        for row in rows {
+            let (i, item_rect) = (row.index, row.rect);
+            let subitem = &menu_item.subitems[i];
+            let item_id = subitem.widget_id(menu_id, i);
+
+            // Separator below the previous visible item
+            if let Some(separator_rect) = row.separator {
+                ui.painter().rect_filled(
+                    separator_rect.shrink2(Vec2::new(padding, 0.0)),
+                    CornerRadius::same(0),
+                    submenu_separator_color,
+                );
+            }
+
+            // Rows scrolled out of view are neither drawn nor hit-tested
+            if !item_rect.intersects(rows_rect) {
+                continue;
+            }
+
+            // Custom rows draw themselves and handle their own interaction
+            if let Some(custom) = &subitem.custom {
+                let is_keyboard_selected = custom.keyboard_focus && selected == Some(i);
+                if is_keyboard_selected {
+                    ui.painter().rect_stroke(
+                        item_rect.shrink(1.0),
+                        CornerRadius::same(2),
+                        Stroke::new(1.5, submenu_keyboard_selection_color),
+                        StrokeKind::Inside,
+                    );
+                }
+
+                let mut row_ui = ui.new_child(
+                    UiBuilder::new()
+                        .id_salt(item_id.with("custom_row"))
+                        .max_rect(item_rect.shrink2(Vec2::new(padding, 0.0))),
+                );
+                row_ui.set_clip_rect(item_rect.intersect(ui.clip_rect()));
+                (custom.render)(&mut row_ui, item_rect);
+                continue;
+            }
+
+            // Labels too long for a capped menu are cut, keeping the shortcut visible
+            let indent = subitem.indent_width(ui_scale);
+            let label_room = item_rect.width()
+                - check_gutter
+                - indent
+                - Self::trailing_width(
+                    ui,
+                    subitem,
+                    menu_text_size,
+                    chevron_size,
+                    ui_scale,
+                    text_widths,
+                );
+            let label = resolve_label(label_resolver, &subitem.label);
+            let label_font = subitem.label_font(menu_text_size, ui_scale);
+            let label_galley =
+                (text_widths.width(ui, &label, &label_font) > label_room).then(|| {
+                    Self::truncated_galley(
+                        ui,
+                        &label,
+                        label_font.clone(),
+                        submenu_text_color,
+                        label_room,
+                    )
+                });
 
             // Handle hover effect
-            let response = ui.interact(
-                item_rect,
-                Id::new(format!("subitem_overlay_{}_{}", menu_item.label, i)),
-                Sense::click(),
-            );
+            let response = ui.interact(item_rect, item_id, Sense::click());
+            let response = match (&subitem.tooltip, &label_galley) {
+                (Some(tooltip), _) => response.on_hover_text(tooltip),
+                (None, Some(_)) => response.on_hover_text(&*label),
+                (None, None) => response,
+            };
 
             // Check if this submenu item is selected by keyboard navigation
-            // Use main selection if available, otherwise use child selection
-            let is_keyboard_selected = keyboard_navigation_active
-                && (selected_submenu_index == Some(i)
-                    || (selected_submenu_index.is_none()
-                        && selected_child_submenu_index == Some(i)));
-
-            if (response.hovered() || is_keyboard_selected) && subitem.enabled {
+            let is_keyboard_selected = selected == Some(i);
+
+            // Split rows run their action from the label and open the side
+            // menu from the arrow; the pointer only lights up the part it is over
+            let split_arrow = subitem
+                .is_split()
+                .then(|| layout::split_arrow_rect(item_rect, rtl));
+            let over_arrow = split_arrow
+                .zip(pointer)
+                .is_some_and(|(arrow, pos)| arrow.contains(pos));
+
+            let hover = Self::hover_fade(
+                ui.ctx(),
+                item_id,
+                response.hovered() && subitem.enabled,
+                hover_animation,
+            );
+            if (hover > 0.0 || is_keyboard_selected) && subitem.enabled {
                 let highlight_color = if is_keyboard_selected {
                     // Use configurable keyboard selection color for submenus
                     submenu_keyboard_selection_color
                 } else {
-                    submenu_hover_color
+                    submenu_hover_color.gamma_multiply(hover)
+                };
+                let highlight_rect = match split_arrow {
+                    Some(arrow) if !is_keyboard_selected && over_arrow => arrow,
+                    Some(arrow) if !is_keyboard_selected => {
+                        if rtl {
+                            Rect::from_min_max(
+                                Pos2::new(arrow.max.x, item_rect.min.y),
+                                item_rect.max,
+                            )
+                        } else {
+                            Rect::from_min_max(
+                                item_rect.min,
+                                Pos2::new(arrow.min.x, item_rect.max.y),
+                            )
+                        }
+                    }
+                    _ => item_rect,
                 };
                 ui.painter()
-                    .rect_filled(item_rect, CornerRadius::same(2), highlight_color);
+                    .rect_filled(highlight_rect, CornerRadius::same(2), highlight_color);
+            }
+            if (response.hovered() || is_keyboard_selected) && subitem.enabled {
                 ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
             }
 
@@ -914,37 +2293,126 @@ impl TitleBar {
             let text_color = if is_keyboard_selected {
                 Color32::WHITE // White text on keyboard selection background
             } else if subitem.enabled {
-                submenu_text_color
+                subitem.text_color.unwrap_or(submenu_text_color)
             } else {
                 Color32::from_rgb(150, 150, 150)
             };
 
-            // Main label (left aligned)
-            ui.painter().text(
-                Pos2::new(item_rect.min.x + padding, item_rect.center().y),
-                Align2::LEFT_CENTER,
-                &subitem.label,
-                FontId::proportional(menu_text_size),
-                text_color,
-            );
+            // A description takes the lower part of the row, under the label
+            let label_y = if subitem.description.is_some() {
+                item_rect.min.y + item_rect.height() * 0.36
+            } else {
+                item_rect.center().y
+            };
+
+            // Check mark for checked items
+            if subitem.checked == Some(true) {
+                let center_x = if rtl {
+                    item_rect.max.x - padding - indent - check_gutter * 0.4
+                } else {
+                    item_rect.min.x + padding + indent + check_gutter * 0.4
+                };
+                let center = Pos2::new(center_x, label_y);
+                let size = menu_text_size * 0.35;
+                let stroke = Stroke::new(1.5, text_color);
+                let corner = Pos2::new(center.x - size * 0.2, center.y + size * 0.6);
+                ui.painter()
+                    .line_segment([Pos2::new(center.x - size, center.y), corner], stroke);
+                ui.painter().line_segment(
+                    [corner, Pos2::new(center.x + size, center.y - size * 0.8)],
+                    stroke,
+                );
+            }
+
+            // Main label (left aligned, right aligned in right-to-left layouts)
+            let (label_x, label_align) = if rtl {
+                (
+                    item_rect.max.x - padding - indent - check_gutter,
+                    Align2::RIGHT_CENTER,
+                )
+            } else {
+                (
+                    item_rect.min.x + padding + indent + check_gutter,
+                    Align2::LEFT_CENTER,
+                )
+            };
+            let label_pos = Pos2::new(label_x, label_y);
+            match label_galley {
+                Some(galley) => {
+                    let rect = label_align.anchor_size(label_pos, galley.size());
+                    ui.painter()
+                        .galley_with_override_text_color(rect.min, galley, text_color);
+                }
+                None => {
+                    ui.painter().text(
+                        label_pos,
+                        label_align,
+                        &label,
+                        label_font.clone(),
+                        text_color,
+                    );
+                }
+            }
+
+            if let Some(description) = &subitem.description {
+                let galley = Self::truncated_galley(
+                    ui,
+                    description,
+                    SubMenuItem::description_font(menu_text_size),
+                    text_color.gamma_multiply(0.7),
+                    label_room,
+                );
+                let description_pos =
+                    Pos2::new(label_x, item_rect.min.y + item_rect.height() * 0.7);
+                let rect = label_align.anchor_size(description_pos, galley.size());
+                ui.painter().galley(rect.min, galley, text_color);
+            }
 
-            // Shortcut or child arrow (right aligned)
+            // Shortcut or child arrow (right aligned, mirrored in right-to-left layouts)
+            let (trailing_x, trailing_align, chevron_dir) = if rtl {
+                (item_rect.min.x + padding, Align2::LEFT_CENTER, -1.0)
+            } else {
+                (item_rect.max.x - padding, Align2::RIGHT_CENTER, 1.0)
+            };
             if !subitem.children.is_empty() {
-                // Draw a chevron using two line segments for reliable rendering across fonts
-                let center = Pos2::new(item_rect.max.x - padding, item_rect.center().y);
-                let size = menu_text_size * 0.6;
-                let half = size * 0.5;
-                let p1 = Pos2::new(center.x - half, center.y - half);
+                // Draw the chevron with shapes for reliable rendering across fonts
+                let center = Pos2::new(trailing_x, item_rect.center().y);
+                let half = chevron_size * 0.5;
+                let p1 = Pos2::new(center.x - half * chevron_dir, center.y - half);
                 let p2 = center;
-                let p3 = Pos2::new(center.x - half, center.y + half);
-                let stroke_color = if is_keyboard_selected {
-                    Color32::WHITE
+                let p3 = Pos2::new(center.x - half * chevron_dir, center.y + half);
+                let (stroke_color, chevron_color) = if is_keyboard_selected {
+                    (Color32::WHITE, Color32::WHITE)
                 } else {
-                    submenu_text_color
+                    (submenu_text_color, chevron_color)
                 };
-                let stroke = Stroke::new(1.5, stroke_color);
-                ui.painter().line_segment([p1, p2], stroke);
-                ui.painter().line_segment([p2, p3], stroke);
+                match chevron_style {
+                    ChevronStyle::Stroke => {
+                        let stroke = Stroke::new(1.5, chevron_color);
+                        ui.painter().line_segment([p1, p2], stroke);
+                        ui.painter().line_segment([p2, p3], stroke);
+                    }
+                    ChevronStyle::Triangle => {
+                        ui.painter().add(Shape::convex_polygon(
+                            vec![p1, p2, p3],
+                            chevron_color,
+                            Stroke::NONE,
+                        ));
+                    }
+                }
+
+                // A short divider sets the arrow of split rows apart
+                if let Some(arrow) = split_arrow {
+                    let x = if rtl { arrow.max.x } else { arrow.min.x };
+                    let inset = item_rect.height() * 0.25;
+                    ui.painter().line_segment(
+                        [
+                            Pos2::new(x, item_rect.min.y + inset),
+                            Pos2::new(x, item_rect.max.y - inset),
+                        ],
+                        Stroke::new(1.0, stroke_color.gamma_multiply(0.4)),
+                    );
+                }
             } else if let Some(ref shortcut) = subitem.shortcut {
                 let shortcut_color = if is_keyboard_selected {
                     Color32::WHITE
@@ -952,150 +2420,497 @@ impl TitleBar {
                     submenu_shortcut_color
                 };
                 ui.painter().text(
-                    Pos2::new(item_rect.max.x - padding, item_rect.center().y),
-                    Align2::RIGHT_CENTER,
+                    Pos2::new(trailing_x, label_y),
+                    trailing_align,
                     &shortcut.display_string(),
                     FontId::proportional(menu_text_size * 0.9),
                     shortcut_color,
                 );
             }
 
-            // Handle click or hover-open for cascading child menus
-            // Keep child menu open while the pointer travels from parent row to child (hover corridor)
-            // and also while the pointer is inside the child submenu area itself.
+            // Handle click or hover-open for cascading child menus. The child
+            // stays open while the pointer is inside it, or heading for it
+            // through the safe triangle; rows crossed on the way don't open theirs.
             let mut open_child = false;
             if subitem.enabled && !subitem.children.is_empty() {
-                if response.hovered() {
-                    open_child = true;
-                } else if let Some(ptr) = ui.ctx().input(|i| i.pointer.interact_pos()) {
-                    // 1) Narrow corridor bridging parent item and child menu
-                    let corridor_width = 10.0;
-                    let corridor = Rect::from_min_max(
-                        Pos2::new(item_rect.max.x, item_rect.min.y - 6.0),
-                        Pos2::new(item_rect.max.x + corridor_width, item_rect.max.y + 6.0),
-                    );
-
-                    // 2) Approximate child submenu bounds (so moving into it keeps it open)
-                    let mut child_max_width: f32 = 120.0; // minimum width
-                    let padding = 8.0;
-                    let item_height = 24.0;
-                    let separator_height = 1.0;
-                    for c in &subitem.children {
-                        let label_width = ui.fonts_mut(|f| {
-                            f.layout_no_wrap(
-                                c.label.clone(),
-                                FontId::proportional(menu_text_size),
-                                submenu_text_color,
-                            )
-                            .size()
-                            .x
-                        });
-                        let shortcut_width = if let Some(ref s) = c.shortcut {
-                            ui.fonts_mut(|f| {
-                                f.layout_no_wrap(
-                                    s.display_string(),
-                                    FontId::proportional(menu_text_size * 0.9),
-                                    submenu_shortcut_color,
-                                )
-                                .size()
-                                .x
-                            })
-                        } else {
-                            0.0
-                        };
-                        let total_width = label_width + shortcut_width + padding * 3.0 + 20.0;
-                        child_max_width = child_max_width.max(total_width);
-                    }
-                    let child_total_height = (item_height * subitem.children.len() as f32)
-                        + (separator_height
-                            * subitem
-                                .children
-                                .iter()
-                                .filter(|s| s.separator_after)
-                                .count() as f32);
-
-                    let mut child_rect = Rect::from_min_size(
-                        Pos2::new(item_rect.max.x, item_rect.min.y),
-                        Vec2::new(child_max_width, child_total_height),
-                    );
-                    // Keep child rect on screen if needed
-                    let content_rect = ui.ctx().content_rect();
-                    if child_rect.max.x > content_rect.max.x {
-                        let shift = child_rect.max.x - content_rect.max.x;
-                        child_rect = child_rect.translate(Vec2::new(-shift, 0.0));
-                    }
+                let child_size = Vec2::new(
+                    Self::menu_width(
+                        ui,
+                        &subitem.children,
+                        menu_text_size,
+                        chevron_size,
+                        ui_scale,
+                        label_resolver,
+                        text_widths,
+                    )
+                    .max(min_width * ui_scale)
+                    .min(width_cap),
+                    Self::menu_height(
+                        &subitem.children,
+                        item_height,
+                        separator_height,
+                        ui_scale,
+                        1,
+                        max_visible_items,
+                    ),
+                );
+                let child_rect = layout::submenu_rect(
+                    Self::child_anchor(item_rect, open_upward, rtl),
+                    child_size,
+                    open_upward,
+                    rtl,
+                    layout::menu_bounds(ui.ctx().content_rect()),
+                );
 
-                    if corridor.contains(ptr) || child_rect.contains(ptr) {
+                // Split rows open their side menu from the arrow only
+                if response.hovered() && (split_arrow.is_none() || over_arrow) {
+                    if heading_to.is_none_or(|item| item == i)
+                        && let Some(apex) = pointer
+                    {
                         open_child = true;
+                        safe_triangle = Some(SafeTriangle {
+                            item: i,
+                            apex,
+                            child_rect,
+                            time: now,
+                            frame,
+                        });
                     }
+                } else {
+                    open_child = heading_to == Some(i)
+                        || pointer.is_some_and(|pos| child_rect.contains(pos));
                 }
+
+                // Apply the open/close delays; a zero delay switches on this frame
+                let hover_id = item_id.with("child_hover");
+                open_child = Self::delayed_child_open(ui.ctx(), hover_id, open_child, hover_delays);
             }
-            if response.clicked() && subitem.enabled && subitem.children.is_empty() {
-                if let Some(ref callback) = subitem.callback {
-                    callback();
-                }
-                item_clicked = true;
+            let clicked_action = match split_arrow {
+                Some(arrow) => response
+                    .interact_pointer_pos()
+                    .is_some_and(|pos| !arrow.contains(pos)),
+                None => subitem.children.is_empty(),
+            };
+            if response.clicked() && subitem.enabled && clicked_action {
+                activated = Some(vec![i]);
             }
 
+            let child_open = open_child || (selected == Some(i) && keyboard_path.len() > 1);
+
+            #[cfg(feature = "accesskit")]
+            describe_menu_item(
+                ui.ctx(),
+                response.id,
+                MenuNode {
+                    label: &label,
+                    enabled: subitem.enabled,
+                    checked: subitem.checked,
+                    expanded: (!subitem.children.is_empty()).then_some(child_open),
+                    shortcut: subitem.shortcut.as_ref().map(|s| s.display_string()),
+                },
+            );
+
             // Render cascading child menu if needed
             // Allow hover to open even in keyboard mode; keyboard can also force-open
-            if open_child || (keyboard_navigation_active && force_open_child_subitem == Some(i)) {
-                // Initialize child submenu selection if not set (for keyboard navigation)
-                if keyboard_navigation_active && selected_child_submenu_index.is_none() {
-                    // Initialize the first item as selected for this child submenu
-                    // Note: We can't modify title_bar_ref here due to borrowing constraints
-                    // This is a limitation of the current approach
-                }
+            if child_open {
+                open_children.push((i, item_id, item_rect));
+            }
+        }
+        ui.set_clip_rect(parent_clip_rect);
+        if let Some(triangle) = safe_triangle {
+            ui.ctx()
+                .data_mut(|d| d.insert_temp(triangle_id, SafeTriangle { frame, ..triangle }));
+        }
 
-                let child_position = Pos2::new(item_rect.max.x, item_rect.min.y);
-                let child_menu = MenuItem {
-                    label: format!("{}_child", menu_item.label),
-                    subitems: subitem.children.clone(),
-                    enabled: true,
-                };
+        // Scroll arrows over the hidden rows
+        for (shown, top) in [(show_up_arrow, true), (show_down_arrow, false)] {
+            if !shown {
+                continue;
+            }
+            let arrow_rect = if top {
+                Rect::from_min_size(
+                    adjusted_rect.min,
+                    Vec2::new(adjusted_rect.width(), arrow_height),
+                )
+            } else {
+                Rect::from_min_size(
+                    Pos2::new(adjusted_rect.min.x, adjusted_rect.max.y - arrow_height),
+                    Vec2::new(adjusted_rect.width(), arrow_height),
+                )
+            };
+            ui.painter()
+                .rect_filled(arrow_rect, radius, submenu_background_color);
+            let center = arrow_rect.center();
+            let half = arrow_height * 0.25;
+            let direction = if top { -1.0 } else { 1.0 };
+            ui.painter().add(egui::Shape::convex_polygon(
+                vec![
+                    Pos2::new(center.x - half * 1.5, center.y - half * direction),
+                    Pos2::new(center.x + half * 1.5, center.y - half * direction),
+                    Pos2::new(center.x, center.y + half * direction),
+                ],
+                submenu_text_color,
+                Stroke::NONE,
+            ));
+        }
 
-                // Draw child menu
-                let child_clicked = Self::render_submenu_overlay_static(
-                    ui,
-                    &child_menu,
-                    child_position,
-                    menu_text_size,
-                    submenu_background_color,
-                    submenu_text_color,
-                    submenu_hover_color,
-                    submenu_shortcut_color,
-                    submenu_border_color,
-                    submenu_keyboard_selection_color,
-                    keyboard_navigation_active,
-                    None,                         // Child menus don't use parent menu selection
-                    None,                         // Child menus don't have forced open items
-                    selected_child_submenu_index, // Pass child selection for highlighting
-                    parent_submenu_index,         // Pass parent submenu index
-                );
+        for (i, item_id, item_rect) in open_children {
+            let subitem = &menu_item.subitems[i];
+            // Side menus opened by hovering highlight nothing
+            let child_path = match keyboard_path {
+                [item, rest @ ..] if *item == i => rest,
+                _ => &[],
+            };
 
-                // Propagate child menu click to parent
-                if child_clicked {
-                    item_clicked = true;
-                }
+            let child_position = Self::child_anchor(item_rect, open_upward, rtl);
+            let child_menu = MenuItem {
+                label: format!("{}_child", menu_item.label),
+                id: None,
+                subitems: subitem.children.clone(),
+                enabled: true,
+                visible: true,
+                tooltip: None,
+                dynamic_subitems: None,
+                badge: None,
+                on_middle_click: None,
+                on_secondary_click: None,
+                tear_off: false,
+                columns: 1,
+                align_right: false,
+            };
+
+            // Draw child menu
+            let child = Self::render_submenu_overlay_static(
+                ui,
+                &child_menu,
+                item_id,
+                child_position,
+                style,
+                keyboard_navigation_active.then_some(child_path),
+                text_widths,
+            );
+
+            // Propagate child menu click to parent
+            rects.extend(child.rects);
+            if let Some(mut path) = child.activated {
+                path.insert(0, i);
+                activated = Some(path);
             }
+        }
+
+        PopupMenuResponse {
+            activated,
+            rects,
+            clicked_elsewhere: false,
+        }
+    }
 
-            current_y += item_height;
+    /// Where the side menu of the row at `item_rect` is anchored
+    ///
+    /// Upward side menus line up with the bottom of their row; right-to-left
+    /// ones hang off the left edge.
+    fn child_anchor(item_rect: Rect, open_upward: bool, rtl: bool) -> Pos2 {
+        match (open_upward, rtl) {
+            (false, false) => item_rect.right_top(),
+            (true, false) => item_rect.right_bottom(),
+            (false, true) => item_rect.left_top(),
+            (true, true) => item_rect.left_bottom(),
+        }
+    }
 
-            // Add separator if needed
-            if subitem.separator_after && i < menu_item.subitems.len() - 1 {
-                let separator_rect = Rect::from_min_size(
-                    Pos2::new(adjusted_rect.min.x + padding, current_y),
-                    Vec2::new(adjusted_rect.width() - padding * 2.0, separator_height),
-                );
-                ui.painter().rect_filled(
-                    separator_rect,
-                    CornerRadius::same(0),
-                    Color32::from_rgb(200, 200, 200),
-                );
-                current_y += separator_height;
+    /// Whether a side menu is open, given whether the pointer wants it open
+    /// this frame.
+    ///
+    /// Hover timers live in egui temp memory under `id`; one that wasn't
+    /// updated last frame belongs to a menu that was closed and starts over.
+    fn delayed_child_open(
+        ctx: &Context,
+        id: Id,
+        wants_open: bool,
+        (open_delay, close_delay): (Duration, Duration),
+    ) -> bool {
+        let now = ctx.input(|i| i.time);
+        let frame = ctx.cumulative_frame_nr();
+        let mut timer = ctx
+            .data(|d| d.get_temp::<ChildHoverTimer>(id))
+            .filter(|timer| timer.frame + 1 >= frame)
+            .unwrap_or_default();
+        timer.frame = frame;
+
+        let (since, delay) = if wants_open {
+            timer.left_at = None;
+            (*timer.hovered_at.get_or_insert(now), open_delay)
+        } else {
+            timer.hovered_at = None;
+            (*timer.left_at.get_or_insert(now), close_delay)
+        };
+        if timer.open != wants_open {
+            let remaining = delay.as_secs_f64() - (now - since);
+            if remaining <= 0.0 {
+                timer.open = wants_open;
+            } else {
+                ctx.request_repaint_after(Duration::from_secs_f64(remaining));
             }
         }
 
-        item_clicked
+        let open = timer.open;
+        ctx.data_mut(|d| d.insert_temp(id, timer));
+        open
+    }
+
+    /// Total height of a list of rows, honoring custom row heights (separators excluded)
+    fn rows_height(items: &[SubMenuItem], item_height: f32, ui_scale: f32) -> f32 {
+        items
+            .iter()
+            .filter(|item| item.visible)
+            .map(|item| item.row_height(item_height, ui_scale))
+            .sum()
+    }
+
+    /// Width of a menu fitting its widest row
+    fn menu_width(
+        ui: &Ui,
+        items: &[SubMenuItem],
+        menu_text_size: f32,
+        chevron_size: f32,
+        ui_scale: f32,
+        label_resolver: Option<&LabelResolverFn>,
+        text_widths: &mut TextWidthCache,
+    ) -> f32 {
+        let check_gutter = Self::check_gutter(items) * ui_scale;
+        items
+            .iter()
+            .filter(|item| item.visible)
+            .map(|item| {
+                let label = resolve_label(label_resolver, &item.label);
+                let description_width = item.description.as_ref().map_or(0.0, |description| {
+                    text_widths.width(
+                        ui,
+                        description,
+                        &SubMenuItem::description_font(menu_text_size),
+                    )
+                });
+                text_widths
+                    .width(ui, &label, &item.label_font(menu_text_size, ui_scale))
+                    .max(description_width)
+                    + item.indent_width(ui_scale)
+                    + Self::trailing_width(
+                        ui,
+                        item,
+                        menu_text_size,
+                        chevron_size,
+                        ui_scale,
+                        text_widths,
+                    )
+                    + check_gutter
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// Room a row needs besides its label: padding, shortcut and side-menu arrow
+    fn trailing_width(
+        ui: &Ui,
+        item: &SubMenuItem,
+        menu_text_size: f32,
+        chevron_size: f32,
+        ui_scale: f32,
+        text_widths: &mut TextWidthCache,
+    ) -> f32 {
+        let shortcut_width = item.shortcut.as_ref().map_or(0.0, |shortcut| {
+            text_widths.width(
+                ui,
+                &shortcut.display_string(),
+                &FontId::proportional(menu_text_size * 0.9),
+            )
+        });
+        let padding = 8.0 * ui_scale;
+        // Extra space for the arrow, growing with its size
+        shortcut_width + padding * 3.0 + chevron_size + 12.0 * ui_scale
+    }
+
+    /// Height of a menu's rows and separators, capped at `max_visible_items` rows
+    ///
+    /// Menus of several columns are as tall as their longest column and
+    /// are never capped.
+    fn menu_height(
+        items: &[SubMenuItem],
+        item_height: f32,
+        separator_height: f32,
+        ui_scale: f32,
+        columns: usize,
+        max_visible_items: Option<usize>,
+    ) -> f32 {
+        if columns > 1 {
+            return layout::submenu_rows(
+                items,
+                Pos2::ZERO,
+                0.0,
+                item_height,
+                separator_height,
+                ui_scale,
+                columns,
+            )
+            .iter()
+            .map(|row| row.rect.max.y)
+            .fold(0.0, f32::max);
+        }
+        let separators = items
+            .iter()
+            .filter(|item| item.visible && item.separator_after)
+            .count() as f32;
+        let height =
+            Self::rows_height(items, item_height, ui_scale) + separators * separator_height;
+        max_visible_items.map_or(height, |rows| height.min(rows as f32 * item_height))
+    }
+
+    /// Top and bottom of row `index` measured from the top of its menu
+    fn row_span(
+        items: &[SubMenuItem],
+        index: usize,
+        item_height: f32,
+        separator_height: f32,
+        ui_scale: f32,
+    ) -> Option<(f32, f32)> {
+        layout::submenu_rows(
+            items,
+            Pos2::ZERO,
+            0.0,
+            item_height,
+            separator_height,
+            ui_scale,
+            1,
+        )
+        .into_iter()
+        .find(|row| row.index == index)
+        .map(|row| (row.rect.min.y, row.rect.max.y))
+    }
+
+    /// Width reserved in front of the labels for check marks, if any item is checkable
+    fn check_gutter(items: &[SubMenuItem]) -> f32 {
+        if items
+            .iter()
+            .any(|item| item.visible && item.checked.is_some())
+        {
+            16.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Next entry whose shown label, lowercased, starts with `prefix`, wrapping around.
+///
+/// A prefix of one repeated letter cycles through the entries starting with
+/// that letter; a longer prefix keeps the current entry while it matches.
+fn typeahead_match(
+    entries: &[SubMenuItem],
+    current: Option<usize>,
+    prefix: &str,
+    labels: Option<&LabelResolverFn>,
+) -> Option<usize> {
+    let mut chars = prefix.chars();
+    let first = chars.next()?;
+    let (needle, start) = if chars.all(|c| c == first) {
+        (
+            &prefix[..first.len_utf8()],
+            current.map_or(0, |index| index + 1),
+        )
+    } else {
+        (prefix, current.unwrap_or(0))
+    };
+    let len = entries.len();
+    (0..len)
+        .map(|offset| (start + offset) % len)
+        .find(|&index| {
+            let entry = &entries[index];
+            entry.is_keyboard_navigable()
+                && resolve_label(labels, &entry.label)
+                    .to_lowercase()
+                    .starts_with(needle)
+        })
+}
+
+/// Text shown in a menu badge; large counts are capped at "99+".
+fn badge_label(count: u32) -> String {
+    if count > 99 {
+        "99+".to_string()
+    } else {
+        count.to_string()
     }
 }
+
+/// Look and behavior of a submenu and its side menus, as drawn by
+/// `render_submenu_overlay_static`.
+///
+/// Sizes are in points with the UI scale already applied, except
+/// `min_width` and `max_width` which are scaled when the menu is laid out.
+#[derive(Clone, Copy)]
+pub(crate) struct SubmenuStyle<'a> {
+    pub text_size: f32,
+    pub item_height: f32,
+    pub separator_height: f32,
+    pub background_color: Color32,
+    pub text_color: Color32,
+    pub hover_color: Color32,
+    pub shortcut_color: Color32,
+    pub border_color: Color32,
+    pub separator_color: Color32,
+    pub keyboard_selection_color: Color32,
+    pub chevron_color: Color32,
+    pub chevron_size: f32,
+    pub chevron_style: ChevronStyle,
+    pub shadow: Shadow,
+    pub corner_radius: u8,
+    pub min_width: f32,
+    pub ui_scale: f32,
+    pub open_upward: bool,
+    /// Delays before a side menu opens and closes on hover.
+    pub hover_delays: (Duration, Duration),
+    pub hover_animation: Duration,
+    pub rtl: bool,
+    pub max_visible_items: Option<usize>,
+    pub max_width: Option<f32>,
+    pub label_resolver: Option<&'a LabelResolverFn>,
+}
+
+/// Scroll position of a menu capped by `max_visible_items`, kept across frames.
+#[derive(Debug, Clone, Copy, Default)]
+struct MenuScroll {
+    /// Points scrolled past the top row.
+    offset: f32,
+    /// Keyboard selection the offset was last adjusted to.
+    selected: Option<usize>,
+    /// Frame the offset was last updated in.
+    frame: u64,
+}
+
+/// Seconds the safe triangle keeps a side menu open after the pointer leaves its row.
+const SAFE_TRIANGLE_TIMEOUT: f64 = 0.4;
+
+/// Last pointer position over a row with a side menu, kept across frames.
+#[derive(Debug, Clone, Copy)]
+struct SafeTriangle {
+    /// Index of the row.
+    item: usize,
+    /// Pointer position over the row, the tip of the triangle.
+    apex: Pos2,
+    /// Area of the row's side menu, whose near edge is the triangle's base.
+    child_rect: Rect,
+    /// When the pointer was last over the row.
+    time: f64,
+    /// Frame the state was last updated in.
+    frame: u64,
+}
+
+/// Hover state of a submenu entry with a side menu, kept across frames.
+#[derive(Debug, Clone, Copy, Default)]
+struct ChildHoverTimer {
+    /// Whether the side menu is shown.
+    open: bool,
+    /// When the pointer started wanting the side menu open.
+    hovered_at: Option<f64>,
+    /// When the pointer stopped wanting the side menu open.
+    left_at: Option<f64>,
+    /// Frame the timer was last updated in.
+    frame: u64,
+}
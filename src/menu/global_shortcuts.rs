@@ -0,0 +1,887 @@
+use crate::{KeyboardShortcut, TitleBar};
+use egui::Key;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+type Callback = Arc<dyn Fn() + Send + Sync>;
+
+// Callbacks of every registered global shortcut, keyed by registration id
+static CALLBACKS: LazyLock<Mutex<HashMap<u32, Callback>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Handle to a shortcut registered with [`TitleBar::register_global_shortcut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlobalShortcutId(u32);
+
+/// Reason a global shortcut could not be registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlobalShortcutError {
+    /// The platform or session offers no global hotkey API (e.g. Wayland).
+    Unsupported(String),
+    /// The key has no global hotkey equivalent on this platform.
+    UnsupportedKey(Key),
+    /// The OS refused the registration, usually because another application
+    /// already owns the combination.
+    Denied(String),
+}
+
+impl fmt::Display for GlobalShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported(reason) => write!(f, "global shortcuts are unsupported: {reason}"),
+            Self::UnsupportedKey(key) => {
+                write!(f, "key {} cannot be used as a global shortcut", key.name())
+            }
+            Self::Denied(reason) => write!(f, "global shortcut registration denied: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for GlobalShortcutError {}
+
+/// Global shortcuts owned by a title bar, unregistered when it is dropped.
+#[derive(Debug, Default)]
+pub(crate) struct GlobalShortcuts {
+    ids: Vec<GlobalShortcutId>,
+}
+
+impl Drop for GlobalShortcuts {
+    fn drop(&mut self) {
+        for id in self.ids.drain(..) {
+            unregister(id);
+        }
+    }
+}
+
+impl TitleBar {
+    /// Register a shortcut with the OS so it fires even while the window is unfocused
+    ///
+    /// Menu shortcuts handled by `check_keyboard_shortcuts()` only see keys
+    /// while the egui window has focus. A global shortcut is grabbed
+    /// system-wide instead, like media keys. The registration lasts until
+    /// `unregister_global_shortcut()` is called or the title bar is dropped.
+    ///
+    /// The callback runs outside the egui frame (on a listener thread on
+    /// Windows and X11, on the main run loop on macOS), so send the work to
+    /// your app, e.g. through a channel, and call `Context::request_repaint()`.
+    ///
+    /// Platform notes:
+    /// - Windows: uses `RegisterHotKey`; fails if another application owns the combination.
+    /// - macOS: uses Carbon `RegisterEventHotKey`; must be called from the main thread.
+    /// - Linux X11: grabs the key on the root window; fails if another client grabbed it.
+    /// - Linux Wayland: unsupported, since only the compositor sees global keys.
+    ///
+    /// `cmd` maps to ⌘ on macOS and to the Windows/Super key elsewhere.
+    ///
+    /// # Arguments
+    /// * `shortcut` - Key combination to grab
+    /// * `callback` - Called every time the combination is pressed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use egui::Context;
+    /// # use egui_desktop::{GlobalShortcutId, KeyboardShortcut, TitleBar};
+    /// # use std::sync::Arc;
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # struct App {
+    /// #     title_bar: TitleBar,
+    /// #     toggle_shortcut: Option<GlobalShortcutId>,
+    /// #     toggle_requested: Arc<AtomicBool>,
+    /// # }
+    /// # impl App {
+    /// # fn setup(&mut self, ctx: &Context) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let title_bar = &mut self.title_bar;
+    /// # let toggle_requested = self.toggle_requested.clone();
+    /// let ctx = ctx.clone();
+    /// match title_bar.register_global_shortcut(
+    ///     KeyboardShortcut::from_string("ctrl+alt+space")?,
+    ///     Box::new(move || {
+    ///         toggle_requested.store(true, Ordering::Relaxed);
+    ///         ctx.request_repaint();
+    ///     }),
+    /// ) {
+    ///     Ok(id) => self.toggle_shortcut = Some(id),
+    ///     Err(err) => eprintln!("{err}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub fn register_global_shortcut(
+        &mut self,
+        shortcut: KeyboardShortcut,
+        callback: Box<dyn Fn() + Send + Sync>,
+    ) -> Result<GlobalShortcutId, GlobalShortcutError> {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        // Store the callback first so a press right after registration is not lost
+        CALLBACKS.lock().unwrap().insert(id, Arc::from(callback));
+        if let Err(err) = platform::register(id, &shortcut) {
+            CALLBACKS.lock().unwrap().remove(&id);
+            return Err(err);
+        }
+
+        let id = GlobalShortcutId(id);
+        self.global_shortcuts.ids.push(id);
+        Ok(id)
+    }
+
+    /// Release a global shortcut. Returns `false` if this bar did not register it.
+    pub fn unregister_global_shortcut(&mut self, id: GlobalShortcutId) -> bool {
+        let ids = &mut self.global_shortcuts.ids;
+        let Some(index) = ids.iter().position(|registered| *registered == id) else {
+            return false;
+        };
+        ids.remove(index);
+        unregister(id);
+        true
+    }
+}
+
+fn unregister(id: GlobalShortcutId) {
+    platform::unregister(id.0);
+    CALLBACKS.lock().unwrap().remove(&id.0);
+}
+
+/// Run the callback of a pressed global shortcut.
+#[cfg_attr(
+    not(any(target_os = "windows", target_os = "macos", target_os = "linux")),
+    allow(dead_code)
+)]
+fn dispatch(id: u32) {
+    // Clone out of the lock so the callback may register or unregister shortcuts
+    let callback = CALLBACKS.lock().unwrap().get(&id).cloned();
+    if let Some(callback) = callback {
+        callback();
+    }
+}
+
+/// Unshifted ASCII character produced by a key, for letters, digits and punctuation.
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn ascii_char(key: Key) -> Option<u8> {
+    Some(match key {
+        Key::Minus => b'-',
+        Key::Equals => b'=',
+        Key::Plus => b'+',
+        Key::Comma => b',',
+        Key::Period => b'.',
+        Key::Slash => b'/',
+        Key::Backslash => b'\\',
+        Key::Semicolon => b';',
+        Key::Quote => b'\'',
+        Key::Backtick => b'`',
+        Key::OpenBracket => b'[',
+        Key::CloseBracket => b']',
+        _ => match key.name().as_bytes() {
+            [c] if c.is_ascii_alphanumeric() => c.to_ascii_uppercase(),
+            _ => return None,
+        },
+    })
+}
+
+/// Number of a function key, e.g. 3 for `Key::F3`.
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn function_key_number(key: Key) -> Option<u32> {
+    key.name().strip_prefix('F')?.parse().ok()
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::*;
+    use std::sync::mpsc::{self, Sender};
+    use windows::Win32::Foundation::{LPARAM, WPARAM};
+    use windows::Win32::System::Threading::GetCurrentThreadId;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        HOT_KEY_MODIFIERS, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT, MOD_SHIFT, MOD_WIN, RegisterHotKey,
+        UnregisterHotKey,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetMessageW, MSG, PM_NOREMOVE, PeekMessageW, PostThreadMessageW, WM_APP, WM_HOTKEY, WM_USER,
+    };
+
+    type Reply = Sender<Result<(), GlobalShortcutError>>;
+
+    enum Command {
+        Register(u32, HOT_KEY_MODIFIERS, u32, Reply),
+        Unregister(u32),
+    }
+
+    /// Thread owning every hotkey; `WM_HOTKEY` is posted to the registering thread.
+    struct Listener {
+        thread_id: u32,
+        commands: Sender<Command>,
+    }
+
+    static LISTENER: LazyLock<Mutex<Option<Listener>>> =
+        LazyLock::new(|| Mutex::new(Listener::spawn()));
+
+    impl Listener {
+        fn spawn() -> Option<Self> {
+            let (commands, receiver) = mpsc::channel::<Command>();
+            let (ready, thread_id) = mpsc::channel();
+            std::thread::Builder::new()
+                .name("egui-desktop-hotkeys".into())
+                .spawn(move || unsafe {
+                    let mut msg = MSG::default();
+                    // Create the message queue before other threads post to it
+                    let _ = PeekMessageW(&mut msg, None, WM_USER, WM_USER, PM_NOREMOVE);
+                    let _ = ready.send(GetCurrentThreadId());
+
+                    while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                        match msg.message {
+                            WM_HOTKEY => dispatch(msg.wParam.0 as u32),
+                            WM_APP => {
+                                for command in receiver.try_iter() {
+                                    match command {
+                                        Command::Register(id, modifiers, vk, reply) => {
+                                            let result = RegisterHotKey(
+                                                None,
+                                                id as i32,
+                                                modifiers | MOD_NOREPEAT,
+                                                vk,
+                                            )
+                                            .map_err(|err| {
+                                                GlobalShortcutError::Denied(err.message())
+                                            });
+                                            let _ = reply.send(result);
+                                        }
+                                        Command::Unregister(id) => {
+                                            let _ = UnregisterHotKey(None, id as i32);
+                                        }
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                })
+                .ok()?;
+
+            Some(Self {
+                thread_id: thread_id.recv().ok()?,
+                commands,
+            })
+        }
+
+        fn send(&self, command: Command) -> bool {
+            self.commands.send(command).is_ok()
+                && unsafe { PostThreadMessageW(self.thread_id, WM_APP, WPARAM(0), LPARAM(0)) }
+                    .is_ok()
+        }
+    }
+
+    /// Windows virtual-key code for a key.
+    fn virtual_key(key: Key) -> Option<u32> {
+        if let Some(n) = function_key_number(key) {
+            // VK_F1..VK_F24
+            return (n <= 24).then_some(0x70 + n - 1);
+        }
+        Some(match key {
+            Key::Enter => 0x0D,
+            Key::Space => 0x20,
+            Key::Tab => 0x09,
+            Key::Escape => 0x1B,
+            Key::Backspace => 0x08,
+            Key::Delete => 0x2E,
+            Key::Insert => 0x2D,
+            Key::Home => 0x24,
+            Key::End => 0x23,
+            Key::PageUp => 0x21,
+            Key::PageDown => 0x22,
+            Key::ArrowLeft => 0x25,
+            Key::ArrowUp => 0x26,
+            Key::ArrowRight => 0x27,
+            Key::ArrowDown => 0x28,
+            // OEM keys on a US layout
+            Key::Semicolon => 0xBA,
+            Key::Equals | Key::Plus => 0xBB,
+            Key::Comma => 0xBC,
+            Key::Minus => 0xBD,
+            Key::Period => 0xBE,
+            Key::Slash => 0xBF,
+            Key::Backtick => 0xC0,
+            Key::OpenBracket => 0xDB,
+            Key::Backslash => 0xDC,
+            Key::CloseBracket => 0xDD,
+            Key::Quote => 0xDE,
+            // Letters and digits use their ASCII code
+            _ => ascii_char(key).filter(u8::is_ascii_alphanumeric)? as u32,
+        })
+    }
+
+    pub fn register(id: u32, shortcut: &KeyboardShortcut) -> Result<(), GlobalShortcutError> {
+        let vk =
+            virtual_key(shortcut.key).ok_or(GlobalShortcutError::UnsupportedKey(shortcut.key))?;
+        let mut modifiers = HOT_KEY_MODIFIERS(0);
+        if shortcut.modifiers.ctrl {
+            modifiers |= MOD_CONTROL;
+        }
+        if shortcut.modifiers.alt {
+            modifiers |= MOD_ALT;
+        }
+        if shortcut.modifiers.shift {
+            modifiers |= MOD_SHIFT;
+        }
+        if shortcut.modifiers.command {
+            modifiers |= MOD_WIN;
+        }
+
+        let listener = LISTENER.lock().unwrap();
+        let (reply, result) = mpsc::channel();
+        if !listener
+            .as_ref()
+            .is_some_and(|listener| listener.send(Command::Register(id, modifiers, vk, reply)))
+        {
+            return Err(GlobalShortcutError::Unsupported(
+                "the hotkey listener thread is not running".into(),
+            ));
+        }
+        result.recv().unwrap_or_else(|_| {
+            Err(GlobalShortcutError::Unsupported(
+                "the hotkey listener thread stopped".into(),
+            ))
+        })
+    }
+
+    pub fn unregister(id: u32) {
+        if let Some(listener) = LISTENER.lock().unwrap().as_ref() {
+            listener.send(Command::Unregister(id));
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::*;
+    use std::ffi::c_void;
+    use std::sync::Once;
+    use std::sync::atomic::AtomicI32;
+
+    #[repr(C)]
+    struct EventTypeSpec {
+        event_class: u32,
+        event_kind: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct EventHotKeyID {
+        signature: u32,
+        id: u32,
+    }
+
+    type EventHandlerProc = unsafe extern "C" fn(*mut c_void, *mut c_void, *mut c_void) -> i32;
+
+    #[link(name = "Carbon", kind = "framework")]
+    unsafe extern "C" {
+        fn GetApplicationEventTarget() -> *mut c_void;
+        fn InstallEventHandler(
+            target: *mut c_void,
+            handler: EventHandlerProc,
+            num_types: u32,
+            list: *const EventTypeSpec,
+            user_data: *mut c_void,
+            out_ref: *mut *mut c_void,
+        ) -> i32;
+        fn RegisterEventHotKey(
+            key_code: u32,
+            modifiers: u32,
+            id: EventHotKeyID,
+            target: *mut c_void,
+            options: u32,
+            out_ref: *mut *mut c_void,
+        ) -> i32;
+        fn UnregisterEventHotKey(hot_key: *mut c_void) -> i32;
+        fn GetEventParameter(
+            event: *mut c_void,
+            name: u32,
+            desired_type: u32,
+            actual_type: *mut u32,
+            buffer_size: usize,
+            actual_size: *mut usize,
+            data: *mut c_void,
+        ) -> i32;
+    }
+
+    const fn four_char_code(code: &[u8; 4]) -> u32 {
+        u32::from_be_bytes(*code)
+    }
+
+    /// Marks hotkeys registered by this crate.
+    const SIGNATURE: u32 = four_char_code(b"egdk");
+    const EVENT_CLASS_KEYBOARD: u32 = four_char_code(b"keyb");
+    const EVENT_HOT_KEY_PRESSED: u32 = 5;
+    const EVENT_PARAM_DIRECT_OBJECT: u32 = four_char_code(b"----");
+    const TYPE_EVENT_HOT_KEY_ID: u32 = four_char_code(b"hkid");
+    const EVENT_HOT_KEY_EXISTS_ERR: i32 = -9878;
+
+    const CMD_KEY: u32 = 1 << 8;
+    const SHIFT_KEY: u32 = 1 << 9;
+    const OPTION_KEY: u32 = 1 << 11;
+    const CONTROL_KEY: u32 = 1 << 12;
+
+    // EventHotKeyRef of each registration, stored as an address to stay `Send`
+    static HOT_KEYS: LazyLock<Mutex<HashMap<u32, usize>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+    static HANDLER: Once = Once::new();
+    static HANDLER_STATUS: AtomicI32 = AtomicI32::new(0);
+
+    unsafe extern "C" fn hot_key_pressed(
+        _next: *mut c_void,
+        event: *mut c_void,
+        _user_data: *mut c_void,
+    ) -> i32 {
+        let mut hot_key = EventHotKeyID::default();
+        let status = unsafe {
+            GetEventParameter(
+                event,
+                EVENT_PARAM_DIRECT_OBJECT,
+                TYPE_EVENT_HOT_KEY_ID,
+                std::ptr::null_mut(),
+                size_of::<EventHotKeyID>(),
+                std::ptr::null_mut(),
+                &mut hot_key as *mut _ as *mut c_void,
+            )
+        };
+        if status == 0 && hot_key.signature == SIGNATURE {
+            dispatch(hot_key.id);
+        }
+        0
+    }
+
+    /// Virtual key code (`kVK_*`) of a key on an ANSI keyboard.
+    fn key_code(key: Key) -> Option<u32> {
+        Some(match key {
+            Key::A => 0x00,
+            Key::S => 0x01,
+            Key::D => 0x02,
+            Key::F => 0x03,
+            Key::H => 0x04,
+            Key::G => 0x05,
+            Key::Z => 0x06,
+            Key::X => 0x07,
+            Key::C => 0x08,
+            Key::V => 0x09,
+            Key::B => 0x0B,
+            Key::Q => 0x0C,
+            Key::W => 0x0D,
+            Key::E => 0x0E,
+            Key::R => 0x0F,
+            Key::Y => 0x10,
+            Key::T => 0x11,
+            Key::Num1 => 0x12,
+            Key::Num2 => 0x13,
+            Key::Num3 => 0x14,
+            Key::Num4 => 0x15,
+            Key::Num6 => 0x16,
+            Key::Num5 => 0x17,
+            Key::Equals | Key::Plus => 0x18,
+            Key::Num9 => 0x19,
+            Key::Num7 => 0x1A,
+            Key::Minus => 0x1B,
+            Key::Num8 => 0x1C,
+            Key::Num0 => 0x1D,
+            Key::CloseBracket => 0x1E,
+            Key::O => 0x1F,
+            Key::U => 0x20,
+            Key::OpenBracket => 0x21,
+            Key::I => 0x22,
+            Key::P => 0x23,
+            Key::Enter => 0x24,
+            Key::L => 0x25,
+            Key::J => 0x26,
+            Key::Quote => 0x27,
+            Key::K => 0x28,
+            Key::Semicolon => 0x29,
+            Key::Backslash => 0x2A,
+            Key::Comma => 0x2B,
+            Key::Slash => 0x2C,
+            Key::N => 0x2D,
+            Key::M => 0x2E,
+            Key::Period => 0x2F,
+            Key::Tab => 0x30,
+            Key::Space => 0x31,
+            Key::Backtick => 0x32,
+            Key::Backspace => 0x33,
+            Key::Escape => 0x35,
+            Key::F17 => 0x40,
+            Key::F18 => 0x4F,
+            Key::F19 => 0x50,
+            Key::F20 => 0x5A,
+            Key::F5 => 0x60,
+            Key::F6 => 0x61,
+            Key::F7 => 0x62,
+            Key::F3 => 0x63,
+            Key::F8 => 0x64,
+            Key::F9 => 0x65,
+            Key::F11 => 0x67,
+            Key::F13 => 0x69,
+            Key::F16 => 0x6A,
+            Key::F14 => 0x6B,
+            Key::F10 => 0x6D,
+            Key::F12 => 0x6F,
+            Key::F15 => 0x71,
+            Key::Insert => 0x72,
+            Key::Home => 0x73,
+            Key::PageUp => 0x74,
+            Key::Delete => 0x75,
+            Key::F4 => 0x76,
+            Key::End => 0x77,
+            Key::F2 => 0x78,
+            Key::PageDown => 0x79,
+            Key::F1 => 0x7A,
+            Key::ArrowLeft => 0x7B,
+            Key::ArrowRight => 0x7C,
+            Key::ArrowDown => 0x7D,
+            Key::ArrowUp => 0x7E,
+            _ => return None,
+        })
+    }
+
+    pub fn register(id: u32, shortcut: &KeyboardShortcut) -> Result<(), GlobalShortcutError> {
+        let code =
+            key_code(shortcut.key).ok_or(GlobalShortcutError::UnsupportedKey(shortcut.key))?;
+        let mut modifiers = 0;
+        if shortcut.modifiers.ctrl {
+            modifiers |= CONTROL_KEY;
+        }
+        if shortcut.modifiers.alt {
+            modifiers |= OPTION_KEY;
+        }
+        if shortcut.modifiers.shift {
+            modifiers |= SHIFT_KEY;
+        }
+        if shortcut.modifiers.command {
+            modifiers |= CMD_KEY;
+        }
+
+        unsafe {
+            HANDLER.call_once(|| {
+                let spec = EventTypeSpec {
+                    event_class: EVENT_CLASS_KEYBOARD,
+                    event_kind: EVENT_HOT_KEY_PRESSED,
+                };
+                let status = InstallEventHandler(
+                    GetApplicationEventTarget(),
+                    hot_key_pressed,
+                    1,
+                    &spec,
+                    std::ptr::null_mut(),
+                    std::ptr::null_mut(),
+                );
+                HANDLER_STATUS.store(status, Ordering::Relaxed);
+            });
+            let status = HANDLER_STATUS.load(Ordering::Relaxed);
+            if status != 0 {
+                return Err(GlobalShortcutError::Unsupported(format!(
+                    "InstallEventHandler failed with status {status}"
+                )));
+            }
+
+            let mut hot_key = std::ptr::null_mut();
+            let status = RegisterEventHotKey(
+                code,
+                modifiers,
+                EventHotKeyID {
+                    signature: SIGNATURE,
+                    id,
+                },
+                GetApplicationEventTarget(),
+                0,
+                &mut hot_key,
+            );
+            match status {
+                0 => {
+                    HOT_KEYS.lock().unwrap().insert(id, hot_key as usize);
+                    Ok(())
+                }
+                EVENT_HOT_KEY_EXISTS_ERR => Err(GlobalShortcutError::Denied(
+                    "the combination is already registered".into(),
+                )),
+                _ => Err(GlobalShortcutError::Denied(format!(
+                    "RegisterEventHotKey failed with status {status}"
+                ))),
+            }
+        }
+    }
+
+    pub fn unregister(id: u32) {
+        if let Some(hot_key) = HOT_KEYS.lock().unwrap().remove(&id) {
+            unsafe {
+                UnregisterEventHotKey(hot_key as *mut c_void);
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::*;
+    use std::ffi::{c_int, c_uint};
+    use std::sync::atomic::AtomicBool;
+    use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+    use std::time::Duration;
+    use x11_dl::{keysym, xlib};
+
+    type Reply = Sender<Result<(), GlobalShortcutError>>;
+
+    enum Command {
+        Register(u32, c_uint, c_uint, Reply),
+        Unregister(u32),
+    }
+
+    /// Modifiers that are matched; everything else (Caps Lock, Num Lock) is ignored.
+    const MODIFIER_MASK: c_uint =
+        xlib::ControlMask | xlib::ShiftMask | xlib::Mod1Mask | xlib::Mod4Mask;
+    /// Lock states grabbed alongside each shortcut so it fires regardless of them.
+    const LOCK_VARIANTS: [c_uint; 4] = [
+        0,
+        xlib::LockMask,
+        xlib::Mod2Mask,
+        xlib::LockMask | xlib::Mod2Mask,
+    ];
+
+    static LISTENER: LazyLock<Mutex<Result<Sender<Command>, String>>> =
+        LazyLock::new(|| Mutex::new(KeyGrabber::spawn()));
+    static GRAB_FAILED: AtomicBool = AtomicBool::new(false);
+
+    /// Grabs keys on the root window through a private X connection.
+    struct KeyGrabber {
+        xlib: xlib::Xlib,
+        display: *mut xlib::Display,
+        root: xlib::Window,
+        /// Keycode and modifiers grabbed per registration.
+        grabs: HashMap<u32, (c_int, c_uint)>,
+    }
+
+    // The display connection is only used by the listener thread
+    unsafe impl Send for KeyGrabber {}
+
+    unsafe extern "C" fn record_grab_error(
+        _display: *mut xlib::Display,
+        event: *mut xlib::XErrorEvent,
+    ) -> c_int {
+        if unsafe { (*event).error_code } == xlib::BadAccess {
+            GRAB_FAILED.store(true, Ordering::Relaxed);
+        }
+        0
+    }
+
+    impl KeyGrabber {
+        fn spawn() -> Result<Sender<Command>, String> {
+            let xlib = xlib::Xlib::open().map_err(|err| err.to_string())?;
+            let display = unsafe { (xlib.XOpenDisplay)(std::ptr::null()) };
+            if display.is_null() {
+                return Err("cannot open the X display".into());
+            }
+            let root = unsafe { (xlib.XDefaultRootWindow)(display) };
+            let grabber = Self {
+                xlib,
+                display,
+                root,
+                grabs: HashMap::new(),
+            };
+
+            let (commands, receiver) = mpsc::channel();
+            std::thread::Builder::new()
+                .name("egui-desktop-hotkeys".into())
+                .spawn(move || grabber.run(receiver))
+                .map_err(|err| err.to_string())?;
+            Ok(commands)
+        }
+
+        fn run(mut self, commands: Receiver<Command>) {
+            loop {
+                loop {
+                    match commands.try_recv() {
+                        Ok(Command::Register(id, keysym, modifiers, reply)) => {
+                            let _ = reply.send(self.grab(id, keysym, modifiers));
+                        }
+                        Ok(Command::Unregister(id)) => self.ungrab(id),
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => return,
+                    }
+                }
+
+                unsafe {
+                    while (self.xlib.XPending)(self.display) > 0 {
+                        let mut event: xlib::XEvent = std::mem::zeroed();
+                        (self.xlib.XNextEvent)(self.display, &mut event);
+                        if event.get_type() != xlib::KeyPress {
+                            continue;
+                        }
+                        let key = event.key;
+                        let pressed = (key.keycode as c_int, key.state & MODIFIER_MASK);
+                        for (id, grab) in &self.grabs {
+                            if *grab == pressed {
+                                dispatch(*id);
+                            }
+                        }
+                    }
+                }
+
+                // XGrabKey has no wake-up mechanism we can share with the
+                // command channel, so poll both at a rate fine for hotkeys
+                std::thread::sleep(Duration::from_millis(15));
+            }
+        }
+
+        fn grab(
+            &mut self,
+            id: u32,
+            keysym: c_uint,
+            modifiers: c_uint,
+        ) -> Result<(), GlobalShortcutError> {
+            let keycode =
+                unsafe { (self.xlib.XKeysymToKeycode)(self.display, keysym.into()) } as c_int;
+            if keycode == 0 {
+                return Err(GlobalShortcutError::Denied(
+                    "the key is not on the current keyboard layout".into(),
+                ));
+            }
+
+            unsafe {
+                // Grab errors arrive asynchronously; catch them with a temporary
+                // handler and a round trip to the server
+                GRAB_FAILED.store(false, Ordering::Relaxed);
+                let previous = (self.xlib.XSetErrorHandler)(Some(record_grab_error));
+                for lock in LOCK_VARIANTS {
+                    (self.xlib.XGrabKey)(
+                        self.display,
+                        keycode,
+                        modifiers | lock,
+                        self.root,
+                        xlib::True,
+                        xlib::GrabModeAsync,
+                        xlib::GrabModeAsync,
+                    );
+                }
+                (self.xlib.XSync)(self.display, xlib::False);
+                (self.xlib.XSetErrorHandler)(previous);
+
+                if GRAB_FAILED.load(Ordering::Relaxed) {
+                    self.ungrab_key(keycode, modifiers);
+                    return Err(GlobalShortcutError::Denied(
+                        "another X client already grabbed the combination".into(),
+                    ));
+                }
+            }
+            self.grabs.insert(id, (keycode, modifiers));
+            Ok(())
+        }
+
+        fn ungrab(&mut self, id: u32) {
+            if let Some((keycode, modifiers)) = self.grabs.remove(&id) {
+                // Keep the grab if another registration uses the same combination
+                if !self
+                    .grabs
+                    .values()
+                    .any(|grab| *grab == (keycode, modifiers))
+                {
+                    self.ungrab_key(keycode, modifiers);
+                }
+            }
+        }
+
+        fn ungrab_key(&self, keycode: c_int, modifiers: c_uint) {
+            unsafe {
+                for lock in LOCK_VARIANTS {
+                    (self.xlib.XUngrabKey)(self.display, keycode, modifiers | lock, self.root);
+                }
+                (self.xlib.XFlush)(self.display);
+            }
+        }
+    }
+
+    /// X keysym for a key.
+    fn keysym(key: Key) -> Option<c_uint> {
+        if let Some(n) = function_key_number(key) {
+            return (n <= 35).then_some(keysym::XK_F1 + n - 1);
+        }
+        Some(match key {
+            Key::Enter => keysym::XK_Return,
+            Key::Space => keysym::XK_space,
+            Key::Tab => keysym::XK_Tab,
+            Key::Escape => keysym::XK_Escape,
+            Key::Backspace => keysym::XK_BackSpace,
+            Key::Delete => keysym::XK_Delete,
+            Key::Insert => keysym::XK_Insert,
+            Key::Home => keysym::XK_Home,
+            Key::End => keysym::XK_End,
+            Key::PageUp => keysym::XK_Page_Up,
+            Key::PageDown => keysym::XK_Page_Down,
+            Key::ArrowLeft => keysym::XK_Left,
+            Key::ArrowUp => keysym::XK_Up,
+            Key::ArrowRight => keysym::XK_Right,
+            Key::ArrowDown => keysym::XK_Down,
+            // Latin-1 keysyms equal the (lowercase) character code
+            _ => ascii_char(key)?.to_ascii_lowercase() as c_uint,
+        })
+    }
+
+    pub fn register(id: u32, shortcut: &KeyboardShortcut) -> Result<(), GlobalShortcutError> {
+        if crate::utils::os::is_wayland_session() {
+            return Err(GlobalShortcutError::Unsupported(
+                "Wayland only lets the compositor grab global keys; bind the action there instead"
+                    .into(),
+            ));
+        }
+        let keysym =
+            keysym(shortcut.key).ok_or(GlobalShortcutError::UnsupportedKey(shortcut.key))?;
+        let mut modifiers = 0;
+        if shortcut.modifiers.ctrl {
+            modifiers |= xlib::ControlMask;
+        }
+        if shortcut.modifiers.alt {
+            modifiers |= xlib::Mod1Mask;
+        }
+        if shortcut.modifiers.shift {
+            modifiers |= xlib::ShiftMask;
+        }
+        if shortcut.modifiers.command {
+            modifiers |= xlib::Mod4Mask;
+        }
+
+        let (reply, result) = mpsc::channel();
+        match &*LISTENER.lock().unwrap() {
+            Ok(listener) => {
+                if listener
+                    .send(Command::Register(id, keysym, modifiers, reply))
+                    .is_err()
+                {
+                    return Err(GlobalShortcutError::Unsupported(
+                        "the hotkey listener thread stopped".into(),
+                    ));
+                }
+            }
+            Err(reason) => return Err(GlobalShortcutError::Unsupported(reason.clone())),
+        }
+        result.recv().unwrap_or_else(|_| {
+            Err(GlobalShortcutError::Unsupported(
+                "the hotkey listener thread stopped".into(),
+            ))
+        })
+    }
+
+    pub fn unregister(id: u32) {
+        if let Ok(listener) = &*LISTENER.lock().unwrap() {
+            let _ = listener.send(Command::Unregister(id));
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+mod platform {
+    use super::*;
+
+    pub fn register(_id: u32, _shortcut: &KeyboardShortcut) -> Result<(), GlobalShortcutError> {
+        Err(GlobalShortcutError::Unsupported(
+            "no global hotkey API on this platform".into(),
+        ))
+    }
+
+    pub fn unregister(_id: u32) {}
+}
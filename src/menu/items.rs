@@ -1,33 +1,153 @@
+use crate::menu::messages::MenuMessages;
 use crate::menu::shortcuts::KeyboardShortcut;
+use crate::menu::state_callbacks::StateCallbacks;
+use egui::{Color32, FontId, Id, Rect, Ui};
+use std::borrow::Cow;
 use std::fmt::{Debug, Formatter, Result};
+use std::sync::Arc;
+
+/// Callback drawing a custom submenu row into a `Ui` clipped to the row rect.
+pub type CustomRowFn = dyn Fn(&mut Ui, Rect) + Send + Sync;
+
+/// Callback sending an item's message, as set with `SubMenuItem::with_message()`.
+pub type SendMessageFn = dyn Fn() + Send + Sync;
+
+/// Callback queuing an item's state callback, as set with `SubMenuItem::with_state_callback()`.
+pub type QueueStateCallbackFn = dyn Fn() + Send + Sync;
+
+/// Width of one indentation step of a submenu row, in points.
+const INDENT_STEP: f32 = 12.0;
+
+/// Height of a row with a description line, relative to a plain row.
+const DESCRIPTION_ROW_SCALE: f32 = 1.7;
+
+/// Menu entry activated in the title bar, as sent to `TitleBar::with_action_sender()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MenuAction {
+    /// Labels from the top-level menu down to the entry, as written before
+    /// any label resolver is applied.
+    pub path: Vec<String>,
+    /// Id of the entry set with `with_id()`, if any.
+    pub id: Option<String>,
+}
+
+/// Turns a menu label used as key, like "menu.file.open", into the text shown.
+pub type LabelResolverFn = dyn Fn(&str) -> String + Send + Sync;
+
+/// Text shown for `label`: resolved when a resolver is set, verbatim otherwise.
+pub(crate) fn resolve_label<'a>(
+    resolver: Option<&LabelResolverFn>,
+    label: &'a str,
+) -> Cow<'a, str> {
+    match resolver {
+        Some(resolve) => Cow::Owned(resolve(label)),
+        None => Cow::Borrowed(label),
+    }
+}
+
+/// Custom widget row drawn inside a submenu instead of a label.
+#[derive(Clone)]
+pub struct CustomRow {
+    /// Row height in points (scaled by the title bar's `ui_scale`).
+    pub height: f32,
+    /// Draws the row; receives a `Ui` clipped to the row and the row rect.
+    pub render: Arc<CustomRowFn>,
+    /// Whether keyboard navigation stops on this row.
+    pub keyboard_focus: bool,
+}
+
+impl Debug for CustomRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("CustomRow")
+            .field("height", &self.height)
+            .field("render", &"<function>")
+            .field("keyboard_focus", &self.keyboard_focus)
+            .finish()
+    }
+}
 
 /// A single submenu item with customization options.
 ///
 /// Represents an entry inside a dropdown menu, with optional keyboard shortcut,
 /// enabled/disabled state, separator, callback, and nested children.
+///
+/// With the `serde` feature, items can be saved and loaded as plain data.
+/// Callbacks, messages and custom rows are left out; attach them after
+/// loading, e.g. through `TitleBar::item_by_path_mut()`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct SubMenuItem {
     /// The visible label for this submenu item.
     pub label: String,
+    /// Optional stable id; items without one are identified by their position.
+    pub id: Option<String>,
     /// Optional keyboard shortcut that triggers this item.
     pub shortcut: Option<KeyboardShortcut>,
     /// Whether the item can be interacted with.
     pub enabled: bool,
+    /// Whether the item is shown; hidden items take no space and are skipped
+    /// by keyboard navigation and shortcuts.
+    pub visible: bool,
     /// If true, draws a separator line after this item.
     pub separator_after: bool,
+    /// Check state for checkable items; `None` means the item is not checkable.
+    pub checked: Option<bool>,
+    /// Optional text shown when hovering the item, even while disabled.
+    pub tooltip: Option<String>,
+    /// Optional second line under the label, in a smaller, dimmer font; rows
+    /// with one are taller.
+    pub description: Option<String>,
+    /// Font of the label instead of the menu font; its size is scaled by the
+    /// title bar's `ui_scale`.
+    pub text_style: Option<FontId>,
+    /// Label color instead of the submenu text color, unless disabled or
+    /// highlighted by keyboard navigation.
+    pub text_color: Option<Color32>,
+    /// Steps the label and check mark are shifted in by, to group options
+    /// under a header without a side menu.
+    pub indent_level: u8,
     /// Optional callback executed when the item is activated.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub callback: Option<Box<dyn Fn() + Send + Sync>>,
+    /// Optional message sent to the app's `MenuMessages` channel when the item is activated.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub message: Option<Arc<SendMessageFn>>,
+    /// Optional callback given the app state when the item is activated.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub state_callback: Option<Arc<QueueStateCallbackFn>>,
     /// Optional nested submenu items.
     pub children: Vec<SubMenuItem>,
+    /// Whether the row is split into the item's own action and an arrow
+    /// opening `children`, instead of only opening them.
+    pub split: bool,
+    /// Custom widget drawn instead of the label, for rows like a volume slider.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub custom: Option<CustomRow>,
 }
 
 impl Debug for SubMenuItem {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.debug_struct("SubMenuItem")
             .field("label", &self.label)
+            .field("id", &self.id)
             .field("shortcut", &self.shortcut)
             .field("enabled", &self.enabled)
+            .field("visible", &self.visible)
             .field("separator_after", &self.separator_after)
+            .field("checked", &self.checked)
+            .field("tooltip", &self.tooltip)
+            .field("description", &self.description)
+            .field("text_style", &self.text_style)
+            .field("text_color", &self.text_color)
+            .field("indent_level", &self.indent_level)
             .field("callback", &"<function>")
+            .field("message", &self.message.as_ref().map(|_| "<message>"))
+            .field(
+                "state_callback",
+                &self.state_callback.as_ref().map(|_| "<function>"),
+            )
+            .field("split", &self.split)
+            .field("custom", &self.custom)
             .finish()
     }
 }
@@ -36,26 +156,137 @@ impl Clone for SubMenuItem {
     fn clone(&self) -> Self {
         Self {
             label: self.label.clone(),
+            id: self.id.clone(),
             shortcut: self.shortcut.clone(),
             enabled: self.enabled,
+            visible: self.visible,
             separator_after: self.separator_after,
+            checked: self.checked,
+            tooltip: self.tooltip.clone(),
+            description: self.description.clone(),
+            text_style: self.text_style.clone(),
+            text_color: self.text_color,
+            indent_level: self.indent_level,
             callback: None, // Can't clone callbacks, set to None
+            message: self.message.clone(),
+            state_callback: self.state_callback.clone(),
             children: self.children.clone(),
+            split: self.split,
+            custom: self.custom.clone(),
         }
     }
 }
 
+impl Default for SubMenuItem {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
 impl SubMenuItem {
     /// Create a new submenu item with a text label.
     pub fn new(label: &str) -> Self {
         Self {
             label: label.to_string(),
+            id: None,
             shortcut: None,
             enabled: true,
+            visible: true,
             separator_after: false,
+            checked: None,
+            tooltip: None,
+            description: None,
+            text_style: None,
+            text_color: None,
+            indent_level: 0,
             callback: None,
+            message: None,
+            state_callback: None,
             children: Vec::new(),
+            split: false,
+            custom: None,
+        }
+    }
+
+    /// Create a row drawn by a custom widget callback.
+    ///
+    /// The row is `height` points tall and replaces the label, shortcut and
+    /// the usual hover/click handling; the callback gets a `Ui` clipped to the
+    /// row. Keyboard navigation skips the row unless `with_keyboard_focus()`
+    /// is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::SubMenuItem;
+    /// # use std::sync::{Arc, Mutex};
+    /// # let volume = Arc::new(Mutex::new(0.5_f32));
+    /// SubMenuItem::custom(32.0, Box::new(move |ui, _rect| {
+    ///     ui.add(egui::Slider::new(&mut *volume.lock().unwrap(), 0.0..=1.0));
+    /// }))
+    /// # ;
+    /// ```
+    pub fn custom(height: f32, render: Box<CustomRowFn>) -> Self {
+        let mut item = Self::new("");
+        item.custom = Some(CustomRow {
+            height,
+            render: Arc::from(render),
+            keyboard_focus: false,
+        });
+        item
+    }
+
+    /// Let keyboard navigation stop on this custom row.
+    ///
+    /// Enter on a focused row runs the item's callback, if any.
+    pub fn with_keyboard_focus(mut self) -> Self {
+        if let Some(custom) = &mut self.custom {
+            custom.keyboard_focus = true;
         }
+        self
+    }
+
+    /// Whether Up/Down keyboard navigation can land on this item.
+    pub(crate) fn is_keyboard_navigable(&self) -> bool {
+        self.visible
+            && self
+                .custom
+                .as_ref()
+                .is_none_or(|custom| custom.keyboard_focus)
+    }
+
+    /// Give this item a stable id
+    ///
+    /// Ids keep egui state (hover timers, focus) attached to the item when
+    /// entries are inserted before it. Without one, the item is identified by
+    /// its position, which is always unique, so repeated labels are fine.
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// egui id of this item, below its parent menu's id.
+    pub(crate) fn widget_id(&self, parent: Id, index: usize) -> Id {
+        match &self.id {
+            Some(id) => parent.with(("item_id", id)),
+            None => parent.with(("item", index)),
+        }
+    }
+
+    /// Index of the item whose id, or failing that label, is `segment`.
+    pub(crate) fn position_by_path_segment(items: &[Self], segment: &str) -> Option<usize> {
+        items
+            .iter()
+            .position(|item| item.id.as_deref() == Some(segment))
+            .or_else(|| items.iter().position(|item| item.label == segment))
+    }
+
+    /// Item reached by following `path`: an index into `items`, then into
+    /// the children of each item on the way down.
+    pub(crate) fn at_path<'a>(items: &'a [Self], path: &[usize]) -> Option<&'a Self> {
+        let (&first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(items.get(first)?, |item, &index| item.children.get(index))
     }
 
     /// Assign a keyboard shortcut to this item.
@@ -70,24 +301,205 @@ impl SubMenuItem {
         self
     }
 
+    /// Send `message` to the `messages` channel when this item is activated
+    ///
+    /// A clone of the message is sent whenever the callback would run, so
+    /// the app can drain the receiver in its update loop instead of sharing
+    /// its state with closures. Both can be set; the callback runs first.
+    /// Unlike callbacks, messages are kept when the item is cloned.
+    ///
+    /// # Arguments
+    /// * `messages` - Handle to the app's message channel
+    /// * `message` - Message sent when the item is activated
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::{MenuMessages, SubMenuItem};
+    /// # #[derive(Clone)]
+    /// # enum Message { Save }
+    /// let (sender, receiver) = std::sync::mpsc::channel();
+    /// let messages = MenuMessages::new(sender);
+    /// let save = SubMenuItem::new("Save").with_message(&messages, Message::Save);
+    /// # let _ = (save, receiver);
+    /// ```
+    pub fn with_message<M: Clone + Send + Sync + 'static>(
+        mut self,
+        messages: &MenuMessages<M>,
+        message: M,
+    ) -> Self {
+        self.message = Some(messages.sender_of(message));
+        self
+    }
+
+    /// Set a callback given mutable access to the app state when this item is activated
+    ///
+    /// The callback is queued on `callbacks` and runs at the end of
+    /// `TitleBar::show_with()`, with the state passed there, so it can change
+    /// the app directly instead of going through shared `Rc<RefCell<...>>`
+    /// handles. The handle ties the callback to the state type, so a callback
+    /// taking another type doesn't compile. Unlike plain callbacks, state
+    /// callbacks are kept when the item is cloned.
+    ///
+    /// # Arguments
+    /// * `callbacks` - Queue run by `show_with()`
+    /// * `callback` - Callback given the app state
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::{StateCallbacks, SubMenuItem};
+    /// # struct Document { text: String }
+    /// let callbacks = StateCallbacks::new();
+    /// let clear = SubMenuItem::new("Clear").with_state_callback(
+    ///     &callbacks,
+    ///     Box::new(|document: &mut Document| document.text.clear()),
+    /// );
+    /// # let _ = clear;
+    /// ```
+    pub fn with_state_callback<S: 'static>(
+        mut self,
+        callbacks: &StateCallbacks<S>,
+        callback: Box<dyn Fn(&mut S) + Send + Sync>,
+    ) -> Self {
+        self.state_callback = Some(callbacks.queue_of(callback));
+        self
+    }
+
     /// Disable this item (non-interactive, rendered as disabled).
     pub fn disabled(mut self) -> Self {
         self.enabled = false;
         self
     }
 
+    /// Show or hide this item without removing it.
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
     /// Draw a separator line after this item.
     pub fn with_separator(mut self) -> Self {
         self.separator_after = true;
         self
     }
 
+    /// Make this item checkable and set its check state.
+    ///
+    /// Checked items draw a check mark in front of the label.
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Show a tooltip when hovering this item.
+    ///
+    /// Disabled items still show it, which makes it a good place to explain
+    /// why an action is unavailable.
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Show a short description under the label, like in a command palette
+    ///
+    /// The row grows to fit the second line; rows without a description
+    /// keep the usual height.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Draw the label in `font` instead of the menu font
+    ///
+    /// Use it to make items like a default action stand out, e.g. with a
+    /// bold font family registered in egui. The size is in points and
+    /// scaled with the rest of the menu.
+    pub fn with_text_style(mut self, font: FontId) -> Self {
+        self.text_style = Some(font);
+        self
+    }
+
+    /// Draw the label in `color`, e.g. red for a destructive action
+    ///
+    /// Disabled items and the keyboard highlight keep their own colors.
+    pub fn with_text_color(mut self, color: Color32) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+
+    /// Shift the label and check mark in by `level` steps
+    ///
+    /// Groups options under a header row in preference-style menus, while
+    /// keeping them in the same menu; keyboard navigation walks indented
+    /// rows like any other. The menu widens to fit.
+    pub fn with_indent_level(mut self, level: u8) -> Self {
+        self.indent_level = level;
+        self
+    }
+
+    /// Room taken by the indentation, in points at `ui_scale`.
+    pub(crate) fn indent_width(&self, ui_scale: f32) -> f32 {
+        f32::from(self.indent_level) * INDENT_STEP * ui_scale
+    }
+
+    /// Height of the row at the menu's scale; `item_height` is already scaled.
+    pub(crate) fn row_height(&self, item_height: f32, ui_scale: f32) -> f32 {
+        match (&self.custom, &self.description) {
+            (Some(custom), _) => custom.height * ui_scale,
+            (None, Some(_)) => item_height * DESCRIPTION_ROW_SCALE,
+            (None, None) => item_height,
+        }
+    }
+
+    /// Font of the description line; `menu_text_size` is already scaled.
+    pub(crate) fn description_font(menu_text_size: f32) -> FontId {
+        FontId::proportional(menu_text_size * 0.8)
+    }
+
+    /// Font of the label at the menu's scale; `menu_text_size` is already scaled.
+    pub(crate) fn label_font(&self, menu_text_size: f32, ui_scale: f32) -> FontId {
+        match &self.text_style {
+            Some(font) => FontId::new(font.size * ui_scale, font.family.clone()),
+            None => FontId::proportional(menu_text_size),
+        }
+    }
+
     /// Append a child item to this submenu.
     pub fn add_child(mut self, child: SubMenuItem) -> Self {
         self.children.push(child);
         self
     }
 
+    /// Split the row into an action and an arrow opening the children
+    ///
+    /// Clicking the label runs the item's callback, while hovering or
+    /// clicking the arrow at the end of the row opens the side menu. With the
+    /// keyboard, Enter runs the callback and Right opens the side menu.
+    /// Items without children are drawn as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::SubMenuItem;
+    /// # fn export_with_last_format() {}
+    /// SubMenuItem::new("Export")
+    ///     .with_split_button()
+    ///     .with_callback(Box::new(|| export_with_last_format()))
+    ///     .add_child(SubMenuItem::new("PNG"))
+    ///     .add_child(SubMenuItem::new("SVG"))
+    /// # ;
+    /// ```
+    pub fn with_split_button(mut self) -> Self {
+        self.split = true;
+        self
+    }
+
+    /// Whether the row is drawn as a split button: an action and an arrow.
+    pub(crate) fn is_split(&self) -> bool {
+        self.split && !self.children.is_empty()
+    }
+
     /// Replace the children of this submenu.
     pub fn with_children(mut self, children: Vec<SubMenuItem>) -> Self {
         self.children = children;
@@ -95,15 +507,101 @@ impl SubMenuItem {
     }
 }
 
+/// Generator producing submenu entries on demand.
+pub type SubItemsGenerator = Box<dyn Fn() -> Vec<SubMenuItem> + Send + Sync>;
+
 /// A menu item with submenu support.
-#[derive(Debug, Clone)]
+///
+/// With the `serde` feature, whole menus can be saved and loaded as plain
+/// data, with the same exclusions as [`SubMenuItem`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct MenuItem {
     /// Top-level menu label.
     pub label: String,
+    /// Optional stable id; menus without one are identified by their position.
+    pub id: Option<String>,
     /// Submenu entries displayed when this menu is opened.
     pub subitems: Vec<SubMenuItem>,
     /// Whether the top-level menu is enabled.
     pub enabled: bool,
+    /// Whether the menu is shown; hidden menus take no space in the bar and
+    /// are skipped by keyboard navigation and shortcuts.
+    pub visible: bool,
+    /// Optional text shown when hovering the menu while it is closed.
+    pub tooltip: Option<String>,
+    /// Optional generator that replaces `subitems` each time the menu opens.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub dynamic_subitems: Option<SubItemsGenerator>,
+    /// Optional count drawn as a pill next to the label; `0` hides it.
+    pub badge: Option<u32>,
+    /// Optional callback executed when the menu is middle-clicked.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_middle_click: Option<Box<dyn Fn() + Send + Sync>>,
+    /// Optional callback executed when the menu is right-clicked.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_secondary_click: Option<Box<dyn Fn() + Send + Sync>>,
+    /// Whether dragging the menu off the bar detaches it into a floating palette.
+    pub tear_off: bool,
+    /// Number of columns the submenu entries are laid out in.
+    pub columns: usize,
+    /// Whether the menu is pinned to the far end of the bar, like a Help menu.
+    pub align_right: bool,
+}
+
+impl Debug for MenuItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("MenuItem")
+            .field("label", &self.label)
+            .field("id", &self.id)
+            .field("subitems", &self.subitems)
+            .field("enabled", &self.enabled)
+            .field("visible", &self.visible)
+            .field("tooltip", &self.tooltip)
+            .field("badge", &self.badge)
+            .field("tear_off", &self.tear_off)
+            .field("columns", &self.columns)
+            .field("align_right", &self.align_right)
+            .field(
+                "dynamic_subitems",
+                &self.dynamic_subitems.as_ref().map(|_| "<function>"),
+            )
+            .field(
+                "on_middle_click",
+                &self.on_middle_click.as_ref().map(|_| "<function>"),
+            )
+            .field(
+                "on_secondary_click",
+                &self.on_secondary_click.as_ref().map(|_| "<function>"),
+            )
+            .finish()
+    }
+}
+
+impl Clone for MenuItem {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label.clone(),
+            id: self.id.clone(),
+            subitems: self.subitems.clone(),
+            enabled: self.enabled,
+            visible: self.visible,
+            tooltip: self.tooltip.clone(),
+            dynamic_subitems: None, // Can't clone generators, keep the last generated items
+            badge: self.badge,
+            on_middle_click: None, // Can't clone callbacks, set to None
+            on_secondary_click: None,
+            tear_off: self.tear_off,
+            columns: self.columns,
+            align_right: self.align_right,
+        }
+    }
+}
+
+impl Default for MenuItem {
+    fn default() -> Self {
+        Self::new("")
+    }
 }
 
 impl MenuItem {
@@ -111,11 +609,46 @@ impl MenuItem {
     pub fn new(label: &str) -> Self {
         Self {
             label: label.to_string(),
+            id: None,
             subitems: Vec::new(),
             enabled: true,
+            visible: true,
+            tooltip: None,
+            dynamic_subitems: None,
+            badge: None,
+            on_middle_click: None,
+            on_secondary_click: None,
+            tear_off: false,
+            columns: 1,
+            align_right: false,
+        }
+    }
+
+    /// Give this menu a stable id
+    ///
+    /// Without one, the menu is identified by its position in the bar, so
+    /// menus sharing a label never collide.
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// egui id of this menu, below the title bar's id.
+    pub(crate) fn widget_id(&self, parent: Id, index: usize) -> Id {
+        match &self.id {
+            Some(id) => parent.with(("menu_id", id)),
+            None => parent.with(("menu", index)),
         }
     }
 
+    /// Index of the menu whose id, or failing that label, is `segment`.
+    pub(crate) fn position_by_path_segment(menus: &[Self], segment: &str) -> Option<usize> {
+        menus
+            .iter()
+            .position(|menu| menu.id.as_deref() == Some(segment))
+            .or_else(|| menus.iter().position(|menu| menu.label == segment))
+    }
+
     /// Append a submenu item to this menu.
     pub fn add_subitem(mut self, subitem: SubMenuItem) -> Self {
         self.subitems.push(subitem);
@@ -127,4 +660,195 @@ impl MenuItem {
         self.enabled = false;
         self
     }
+
+    /// Show or hide this menu without removing it.
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Show a tooltip when hovering this menu while it is closed.
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Set the callback executed when this menu is middle-clicked.
+    ///
+    /// Primary clicks still open the submenu.
+    pub fn with_middle_click_callback(mut self, callback: Box<dyn Fn() + Send + Sync>) -> Self {
+        self.on_middle_click = Some(callback);
+        self
+    }
+
+    /// Set the callback executed when this menu is right-clicked.
+    ///
+    /// Primary clicks still open the submenu.
+    pub fn with_secondary_click_callback(mut self, callback: Box<dyn Fn() + Send + Sync>) -> Self {
+        self.on_secondary_click = Some(callback);
+        self
+    }
+
+    /// Let this menu be torn off the bar
+    ///
+    /// Dragging the menu label away from the bar detaches the menu into a
+    /// floating palette that stays open until its close button is clicked.
+    /// Items chosen in the palette run their callbacks without closing it.
+    pub fn with_tear_off(mut self) -> Self {
+        self.tear_off = true;
+        self
+    }
+
+    /// Lay the submenu out in `columns` side-by-side columns
+    ///
+    /// Entries fill each column top to bottom before moving to the next, and
+    /// every column is as wide as the widest entry. Left/Right move between
+    /// columns from the keyboard. Meant for long, picker-style menus such
+    /// as symbols or colors; multi-column menus never scroll.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::{MenuItem, SubMenuItem};
+    /// let symbols = ["α", "β", "γ", "δ", "ε", "ζ", "η", "θ", "ι"]
+    ///     .iter()
+    ///     .fold(MenuItem::new("Symbols").with_columns(3), |menu, symbol| {
+    ///         menu.add_subitem(SubMenuItem::new(symbol))
+    ///     });
+    /// ```
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Pin this menu to the far end of the bar, like the Help menu of some apps
+    ///
+    /// Pinned menus sit right before the custom icons and window controls,
+    /// in the order they were added, while the other menus keep flowing
+    /// from the title. Keyboard navigation still walks the menus in the
+    /// order they were added, so pinned menus are best added last.
+    pub fn with_align_right(mut self) -> Self {
+        self.align_right = true;
+        self
+    }
+
+    /// Show a notification count next to the label.
+    ///
+    /// `0` shows nothing and counts above 99 are drawn as "99+".
+    pub fn with_badge(mut self, count: u32) -> Self {
+        self.badge = Some(count);
+        self
+    }
+
+    /// Build the submenu entries each time the menu opens.
+    ///
+    /// The generator runs once per opening and its entries stay in place
+    /// while the menu is visible, so layout and keyboard navigation remain
+    /// stable. Useful for lists that change between openings, like "Open
+    /// Recent".
+    pub fn with_dynamic_subitems(mut self, generator: SubItemsGenerator) -> Self {
+        self.dynamic_subitems = Some(generator);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::{TitleBar, TitleBarOptions};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Menus are compared through their `Debug` output, which covers every
+    /// field but the callbacks.
+    fn assert_same(left: &MenuItem, right: &MenuItem) {
+        assert_eq!(format!("{left:?}"), format!("{right:?}"));
+    }
+
+    fn shortcut(shortcut: &str) -> KeyboardShortcut {
+        KeyboardShortcut::from_string(shortcut).unwrap()
+    }
+
+    /// Menu with the options of each kind hand-written files use.
+    const EDIT_MENU: &str = r#"
+        label = "Edit"
+
+        [[subitems]]
+        id = "undo"
+        label = "Undo"
+        shortcut = "ctrl+z"
+
+        [[subitems]]
+        label = "Redo"
+        enabled = false
+    "#;
+
+    #[test]
+    fn menus_round_trip_through_toml() {
+        let menu = MenuItem::new("File")
+            .with_id("file")
+            .with_tooltip("Documents")
+            .with_badge(1)
+            .add_subitem(
+                SubMenuItem::new("Save")
+                    .with_id("save")
+                    .with_shortcut(shortcut("ctrl+s"))
+                    .with_callback(Box::new(|| println!("Save")))
+                    .with_separator(),
+            )
+            .add_subitem(SubMenuItem::new("Autosave").with_checked(true))
+            .add_subitem(
+                SubMenuItem::new("Export")
+                    .with_split_button()
+                    .add_child(SubMenuItem::new("PNG").with_shortcut(shortcut("ctrl+shift+e")))
+                    .add_child(SubMenuItem::new("PDF").disabled().with_visible(false)),
+            );
+
+        // Everything but the callbacks survives the round trip
+        let text = toml::to_string(&menu).unwrap();
+        let loaded: MenuItem = toml::from_str(&text).unwrap();
+        assert_same(&loaded, &menu);
+        assert!(loaded.subitems[0].callback.is_none());
+    }
+
+    #[test]
+    fn missing_fields_take_their_defaults() {
+        let loaded: MenuItem = toml::from_str(EDIT_MENU).unwrap();
+        let expected = MenuItem::new("Edit")
+            .add_subitem(
+                SubMenuItem::new("Undo")
+                    .with_id("undo")
+                    .with_shortcut(shortcut("ctrl+z")),
+            )
+            .add_subitem(SubMenuItem::new("Redo").disabled());
+        assert_same(&loaded, &expected);
+    }
+
+    #[test]
+    fn invalid_shortcuts_fail_to_load() {
+        let invalid = toml::from_str::<MenuItem>(
+            r#"
+            label = "Edit"
+            [[subitems]]
+            label = "Undo"
+            shortcut = "ctrl+nokey"
+            "#,
+        );
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn callbacks_attach_to_loaded_items_by_path() {
+        let loaded: MenuItem = toml::from_str(EDIT_MENU).unwrap();
+        let undone = Arc::new(AtomicBool::new(false));
+        let undone_flag = undone.clone();
+        let mut title_bar = TitleBar::new(TitleBarOptions::new()).add_menu_with_submenu(loaded);
+        title_bar
+            .item_by_path_mut(&["Edit", "undo"])
+            .expect("loaded item is found by id")
+            .callback = Some(Box::new(move || undone_flag.store(true, Ordering::Relaxed)));
+        if let Some(callback) = &title_bar.item_by_path(&["Edit", "undo"]).unwrap().callback {
+            callback();
+        }
+        assert!(undone.load(Ordering::Relaxed));
+    }
 }
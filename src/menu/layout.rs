@@ -0,0 +1,319 @@
+//! Menu geometry, kept apart from painting so it can be computed without a frame.
+
+use egui::{Pos2, Rect, Vec2};
+
+use crate::menu::items::SubMenuItem;
+use crate::utils::resize_handles::RESIZE_HANDLE_SIZE;
+
+/// A visible row of a submenu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SubmenuRow {
+    /// Index of the item in the submenu, hidden items included.
+    pub index: usize,
+    /// Area of the row.
+    pub rect: Rect,
+    /// Separator drawn right above the row, if the previous visible item asks for one.
+    pub separator: Option<Rect>,
+}
+
+/// Lay out the top-level menus along `bar`
+///
+/// Returns, per menu, the x where it starts (its right edge in right-to-left
+/// layouts) and its rect. `None` widths are hidden menus: they get no rect
+/// and take no room, but still report the position they would start at.
+pub(crate) fn menu_rects(bar: Rect, widths: &[Option<f32>], rtl: bool) -> Vec<(f32, Option<Rect>)> {
+    let mut current_x = if rtl { bar.max.x } else { bar.min.x };
+    widths
+        .iter()
+        .map(|width| {
+            let start = current_x;
+            let rect = width.map(|width| {
+                let min_x = if rtl { current_x - width } else { current_x };
+                if rtl {
+                    current_x -= width;
+                } else {
+                    current_x += width;
+                }
+                Rect::from_min_size(Pos2::new(min_x, bar.min.y), Vec2::new(width, bar.height()))
+            });
+            (start, rect)
+        })
+        .collect()
+}
+
+/// First menu to collapse into the overflow menu so the bar fits in `room`
+///
+/// Menus are kept from the start while they fit next to an overflow button
+/// `button` wide; `None` widths are hidden menus. Returns `None` when all
+/// visible menus fit without the button.
+pub(crate) fn overflow_start(widths: &[Option<f32>], room: f32, button: f32) -> Option<usize> {
+    if widths.iter().flatten().sum::<f32>() <= room {
+        return None;
+    }
+    let mut used = button;
+    widths.iter().position(|width| {
+        used += width.unwrap_or(0.0);
+        used > room
+    })
+}
+
+/// Width the menus take in `room`, counting the overflow button `button` wide
+/// instead of the menus that collapse into it
+pub(crate) fn bar_width(widths: &[Option<f32>], room: f32, button: f32) -> f32 {
+    match overflow_start(widths, room, button) {
+        Some(start) => widths[..start].iter().flatten().sum::<f32>() + button,
+        None => widths.iter().flatten().sum(),
+    }
+}
+
+/// Room submenus may take on `screen`
+///
+/// Menus float above the rest of the UI, so they stay clear of the resize
+/// handles along the window edges instead of swallowing their presses.
+pub(crate) fn menu_bounds(screen: Rect) -> Rect {
+    screen.shrink(RESIZE_HANDLE_SIZE)
+}
+
+/// Place a submenu of `size` at `anchor`, kept horizontally inside `screen`
+///
+/// `anchor` is the top-left corner of the submenu, its bottom-left corner
+/// when opening upward (never above the screen top), and the corresponding
+/// right corner in right-to-left layouts.
+pub(crate) fn submenu_rect(
+    anchor: Pos2,
+    size: Vec2,
+    open_upward: bool,
+    rtl: bool,
+    screen: Rect,
+) -> Rect {
+    let top = if open_upward {
+        (anchor.y - size.y).max(screen.min.y)
+    } else {
+        anchor.y
+    };
+    let left = if rtl { anchor.x - size.x } else { anchor.x };
+    // Move left if it would go off the right edge, right if off the left edge
+    let left = if left + size.x > screen.max.x {
+        screen.max.x - size.x
+    } else if left < screen.min.x {
+        screen.min.x
+    } else {
+        left
+    };
+    Rect::from_min_size(Pos2::new(left, top), size)
+}
+
+/// Column and row of each item of a menu split into `columns` columns
+///
+/// Visible items fill the columns top to bottom, then left to right, with
+/// the same number of items per column but the last. Hidden items share
+/// the cell of the next visible one.
+pub(crate) fn menu_cells(items: &[SubMenuItem], columns: usize) -> Vec<(usize, usize)> {
+    let visible = items.iter().filter(|item| item.visible).count();
+    let per_column = visible.div_ceil(columns.max(1)).max(1);
+    let mut position = 0;
+    items
+        .iter()
+        .map(|item| {
+            let cell = (position / per_column, position % per_column);
+            if item.visible {
+                position += 1;
+            }
+            cell
+        })
+        .collect()
+}
+
+/// Stack the visible rows of a submenu from `origin` downward, in `columns`
+/// columns of `width` each
+///
+/// Custom rows and rows with a description take their own height;
+/// separators are `separator_height` tall and span the column. A separator
+/// falling at the top of a column is dropped.
+pub(crate) fn submenu_rows(
+    items: &[SubMenuItem],
+    origin: Pos2,
+    width: f32,
+    item_height: f32,
+    separator_height: f32,
+    ui_scale: f32,
+    columns: usize,
+) -> Vec<SubmenuRow> {
+    let cells = menu_cells(items, columns);
+    let mut current_y = origin.y;
+    let mut separator_pending = false;
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.visible)
+        .map(|(index, item)| {
+            let (column, row) = cells[index];
+            let left = origin.x + column as f32 * width;
+            if row == 0 {
+                current_y = origin.y;
+                separator_pending = false;
+            }
+            let separator =
+                std::mem::replace(&mut separator_pending, item.separator_after).then(|| {
+                    let rect = Rect::from_min_size(
+                        Pos2::new(left, current_y),
+                        Vec2::new(width, separator_height),
+                    );
+                    current_y += separator_height;
+                    rect
+                });
+            let row_height = item.row_height(item_height, ui_scale);
+            let rect =
+                Rect::from_min_size(Pos2::new(left, current_y), Vec2::new(width, row_height));
+            current_y += row_height;
+            SubmenuRow {
+                index,
+                rect,
+                separator,
+            }
+        })
+        .collect()
+}
+
+/// Arrow part of a split-button row: a square at the trailing end of `row`
+///
+/// The rest of the row runs the item's own action.
+pub(crate) fn split_arrow_rect(row: Rect, rtl: bool) -> Rect {
+    let size = Vec2::new(row.height(), row.height());
+    if rtl {
+        Rect::from_min_size(row.min, size)
+    } else {
+        Rect::from_min_size(Pos2::new(row.max.x - row.height(), row.min.y), size)
+    }
+}
+
+/// Whether `point` lies between `apex` and the near edge of `target`
+///
+/// This is the "safe triangle" of cascading menus: a pointer moving from a
+/// row at `apex` toward its side menu `target` stays inside it, even when it
+/// crosses other rows on the way.
+pub(crate) fn in_safe_triangle(apex: Pos2, target: Rect, point: Pos2) -> bool {
+    let near_x = if target.center().x >= apex.x {
+        target.min.x
+    } else {
+        target.max.x
+    };
+    let (b, c) = (
+        Pos2::new(near_x, target.min.y),
+        Pos2::new(near_x, target.max.y),
+    );
+    let side =
+        |from: Pos2, to: Pos2| (to - from).x * (point - from).y - (to - from).y * (point - from).x;
+    let (d1, d2, d3) = (side(apex, b), side(b, c), side(c, apex));
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen() -> Rect {
+        Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0))
+    }
+
+    #[test]
+    fn empty_menus_take_no_room() {
+        let bar = Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 32.0));
+        assert!(menu_rects(bar, &[], false).is_empty());
+        assert_eq!(overflow_start(&[], 100.0, 20.0), None);
+        assert!(submenu_rows(&[], Pos2::ZERO, 120.0, 24.0, 1.0, 1.0, 1).is_empty());
+
+        let hidden = [SubMenuItem::new("Hidden").with_visible(false)];
+        assert!(submenu_rows(&hidden, Pos2::ZERO, 120.0, 24.0, 1.0, 1.0, 1).is_empty());
+    }
+
+    #[test]
+    fn hidden_menus_are_skipped_in_the_bar() {
+        let bar = Rect::from_min_size(Pos2::new(10.0, 0.0), Vec2::new(400.0, 32.0));
+        let rects = menu_rects(bar, &[Some(50.0), None, Some(30.0)], false);
+        assert_eq!(rects[0].0, 10.0);
+        assert_eq!(rects[1], (60.0, None));
+        assert_eq!(
+            rects[2].1,
+            Some(Rect::from_min_size(
+                Pos2::new(60.0, 0.0),
+                Vec2::new(30.0, 32.0)
+            ))
+        );
+
+        let rects = menu_rects(bar, &[Some(50.0), Some(30.0)], true);
+        assert_eq!(rects[0].1.map(|rect| rect.min.x), Some(360.0));
+        assert_eq!(rects[1].0, 360.0);
+    }
+
+    #[test]
+    fn submenus_are_clamped_onto_the_screen() {
+        let size = Vec2::new(200.0, 100.0);
+        let past_right = submenu_rect(Pos2::new(700.0, 32.0), size, false, false, screen());
+        assert_eq!(past_right.min, Pos2::new(600.0, 32.0));
+
+        let past_left = submenu_rect(Pos2::new(50.0, 32.0), size, false, true, screen());
+        assert_eq!(past_left.min, Pos2::new(0.0, 32.0));
+
+        let above_top = submenu_rect(Pos2::new(100.0, 40.0), size, true, false, screen());
+        assert_eq!(above_top.min, Pos2::new(100.0, 0.0));
+
+        let inside = submenu_rect(Pos2::new(100.0, 32.0), size, false, false, screen());
+        assert_eq!(inside, Rect::from_min_size(Pos2::new(100.0, 32.0), size));
+    }
+
+    #[test]
+    fn separators_sit_between_rows() {
+        let items = [
+            SubMenuItem::new("Cut").with_separator(),
+            SubMenuItem::new("Hidden").with_visible(false),
+            SubMenuItem::new("Paste").with_separator(),
+        ];
+        let rows = submenu_rows(&items, Pos2::new(0.0, 10.0), 120.0, 24.0, 1.0, 1.0, 1);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].separator, None);
+        assert_eq!(rows[0].rect.y_range(), 10.0..=34.0);
+        assert_eq!(rows[1].index, 2);
+        assert_eq!(
+            rows[1].separator,
+            Some(Rect::from_min_size(
+                Pos2::new(0.0, 34.0),
+                Vec2::new(120.0, 1.0)
+            ))
+        );
+        assert_eq!(rows[1].rect.y_range(), 35.0..=59.0);
+    }
+
+    #[test]
+    fn separators_at_the_top_of_a_column_are_dropped() {
+        let items = [
+            SubMenuItem::new("A"),
+            SubMenuItem::new("B").with_separator(),
+            SubMenuItem::new("C"),
+            SubMenuItem::new("D"),
+        ];
+        let rows = submenu_rows(&items, Pos2::ZERO, 100.0, 20.0, 1.0, 1.0, 2);
+        assert_eq!(rows[2].rect.min, Pos2::new(100.0, 0.0));
+        assert_eq!(rows[2].separator, None);
+    }
+
+    #[test]
+    fn overflow_starts_at_the_first_menu_that_does_not_fit() {
+        let widths = [Some(50.0), Some(50.0), None, Some(50.0)];
+        assert_eq!(overflow_start(&widths, 150.0, 20.0), None);
+        assert_eq!(overflow_start(&widths, 120.0, 20.0), Some(3));
+        assert_eq!(overflow_start(&widths, 100.0, 20.0), Some(1));
+        assert_eq!(overflow_start(&widths, 10.0, 20.0), Some(0));
+    }
+
+    #[test]
+    fn bar_width_counts_the_overflow_button_instead_of_collapsed_menus() {
+        let widths = [Some(50.0), Some(50.0), None, Some(50.0)];
+        assert_eq!(bar_width(&widths, 150.0, 20.0), 150.0);
+        assert_eq!(bar_width(&widths, 120.0, 20.0), 120.0);
+        assert_eq!(bar_width(&widths, 100.0, 20.0), 70.0);
+        assert_eq!(bar_width(&widths, 10.0, 20.0), 20.0);
+    }
+}
@@ -0,0 +1,200 @@
+/// Build a `MenuItem` tree in one block
+///
+/// Expands to the usual builder calls. A menu is a label, optional options
+/// in parentheses and its entries in braces; each entry is a label with
+/// optional options and, for side menus, its own entries in braces.
+///
+/// Options are comma separated:
+/// * `shortcut = "ctrl+s"` - `with_shortcut(KeyboardShortcut::from_string(..))`,
+///   panicking on an invalid shortcut
+/// * `callback = || save()` - `with_callback(Box::new(..))`
+/// * `message = (messages, Message::Save)` - `with_message(&messages, ..)`
+/// * `id = "save"`, `tooltip = ".."`, `description = ".."`, `checked = true`,
+///   `badge = 3`, `indent = 1`
+/// * `disabled`, `hidden`, `separator` (after the entry), `split`
+///   (split button), `tear_off` and `align_right`
+///
+/// # Examples
+///
+/// ```rust
+/// # use egui_desktop::menu;
+/// # fn new_file() {}
+/// # fn open_file() {}
+/// # fn export_png() {}
+/// let file_menu = menu!("File" {
+///     "New" (shortcut = "ctrl+n", callback = || new_file()),
+///     "Open" (shortcut = "ctrl+o", callback = || open_file(), separator),
+///     "Export" {
+///         "PNG" (callback = || export_png()),
+///         "SVG" (disabled),
+///     },
+///     "Quit" (shortcut = "ctrl+q"),
+/// });
+/// ```
+#[macro_export]
+macro_rules! menu {
+    ($label:literal $(( $($option:tt)* ))? { $($entries:tt)* }) => {
+        $crate::menu!(@entries add_subitem
+            $crate::menu!(@options $crate::MenuItem::new($label); $($($option)*)?);
+            $($entries)*
+        )
+    };
+
+    // Entries, added one by one with `add_subitem` or `add_child`
+    (@entries $add:ident $parent:expr;) => {
+        $parent
+    };
+    (@entries $add:ident $parent:expr;
+        $label:literal $(( $($option:tt)* ))? $({ $($children:tt)* })? $(, $($rest:tt)*)?
+    ) => {
+        $crate::menu!(@entries $add
+            $parent.$add($crate::menu!(@entries add_child
+                $crate::menu!(@options $crate::SubMenuItem::new($label); $($($option)*)?);
+                $($($children)*)?
+            ));
+            $($($rest)*)?
+        )
+    };
+
+    // Options, applied in order
+    (@options $item:expr;) => {
+        $item
+    };
+    (@options $item:expr; shortcut = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options
+            $item.with_shortcut($crate::KeyboardShortcut::from_string($value)
+                .unwrap_or_else(|error| panic!("invalid shortcut in menu!: {error}")));
+            $($($rest)*)?)
+    };
+    (@options $item:expr; callback = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_callback(Box::new($value)); $($($rest)*)?)
+    };
+    (@options $item:expr; message = ($messages:expr, $value:expr) $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_message(&$messages, $value); $($($rest)*)?)
+    };
+    (@options $item:expr; id = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_id($value); $($($rest)*)?)
+    };
+    (@options $item:expr; tooltip = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_tooltip($value); $($($rest)*)?)
+    };
+    (@options $item:expr; description = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_description($value); $($($rest)*)?)
+    };
+    (@options $item:expr; checked = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_checked($value); $($($rest)*)?)
+    };
+    (@options $item:expr; badge = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_badge($value); $($($rest)*)?)
+    };
+    (@options $item:expr; indent = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_indent_level($value); $($($rest)*)?)
+    };
+    (@options $item:expr; disabled $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.disabled(); $($($rest)*)?)
+    };
+    (@options $item:expr; hidden $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_visible(false); $($($rest)*)?)
+    };
+    (@options $item:expr; separator $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_separator(); $($($rest)*)?)
+    };
+    (@options $item:expr; split $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_split_button(); $($($rest)*)?)
+    };
+    (@options $item:expr; tear_off $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_tear_off(); $($($rest)*)?)
+    };
+    (@options $item:expr; align_right $(, $($rest:tt)*)?) => {
+        $crate::menu!(@options $item.with_align_right(); $($($rest)*)?)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{KeyboardShortcut, MenuItem, MenuMessages, SubMenuItem};
+
+    /// Menus are compared through their `Debug` output, which covers every
+    /// field but the callbacks.
+    fn assert_same(from_macro: MenuItem, from_builders: MenuItem) {
+        assert_eq!(format!("{from_macro:?}"), format!("{from_builders:?}"));
+    }
+
+    #[test]
+    fn empty_menu() {
+        assert_same(menu!("Help" {}), MenuItem::new("Help"));
+    }
+
+    #[test]
+    fn menu_options() {
+        assert_same(
+            menu!("View" (id = "view", tooltip = "Layout", badge = 2, tear_off) {}),
+            MenuItem::new("View")
+                .with_id("view")
+                .with_tooltip("Layout")
+                .with_badge(2)
+                .with_tear_off(),
+        );
+    }
+
+    #[test]
+    fn nested_entries() {
+        let messages = MenuMessages::new(std::sync::mpsc::channel().0);
+        assert_same(
+            menu!("File" {
+                "New" (shortcut = "ctrl+n", callback = || println!("New")),
+                "Open" (shortcut = "ctrl+o", separator),
+                "Autosave" (checked = true, tooltip = "Save every minute"),
+                "Export" (split, callback = || println!("Export")) {
+                    "PNG" (id = "png"),
+                    "SVG" (disabled),
+                    "PDF" {
+                        "A4",
+                        "Letter" (hidden),
+                    },
+                },
+                "Quit" (shortcut = "ctrl+q", message = (messages, "quit")),
+            }),
+            MenuItem::new("File")
+                .add_subitem(
+                    SubMenuItem::new("New")
+                        .with_shortcut(KeyboardShortcut::from_string("ctrl+n").unwrap())
+                        .with_callback(Box::new(|| println!("New"))),
+                )
+                .add_subitem(
+                    SubMenuItem::new("Open")
+                        .with_shortcut(KeyboardShortcut::from_string("ctrl+o").unwrap())
+                        .with_separator(),
+                )
+                .add_subitem(
+                    SubMenuItem::new("Autosave")
+                        .with_checked(true)
+                        .with_tooltip("Save every minute"),
+                )
+                .add_subitem(
+                    SubMenuItem::new("Export")
+                        .with_split_button()
+                        .with_callback(Box::new(|| println!("Export")))
+                        .add_child(SubMenuItem::new("PNG").with_id("png"))
+                        .add_child(SubMenuItem::new("SVG").disabled())
+                        .add_child(
+                            SubMenuItem::new("PDF")
+                                .add_child(SubMenuItem::new("A4"))
+                                .add_child(SubMenuItem::new("Letter").with_visible(false)),
+                        ),
+                )
+                .add_subitem(
+                    SubMenuItem::new("Quit")
+                        .with_shortcut(KeyboardShortcut::from_string("ctrl+q").unwrap())
+                        .with_message(&messages, "quit"),
+                ),
+        );
+    }
+
+    #[test]
+    fn callbacks_are_set() {
+        // Callbacks can't be compared, so check that they are wired up
+        let menu = menu!("Edit" { "Undo" (callback = || {}) });
+        assert!(menu.subitems[0].callback.is_some());
+    }
+}
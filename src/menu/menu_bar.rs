@@ -1,11 +1,18 @@
-use egui::{Align2, Color32, CursorIcon, FontId, Sense, TextStyle, Ui, Vec2};
+use egui::{Align2, Color32, CursorIcon, FontId, Id, Sense, TextStyle, Ui, Vec2};
+
+use crate::menu::navigation::{MenuNavModel, MenuNavigator, NavAction, NavKeys};
 
 /// A minimal horizontal menu bar composed of clickable items.
 ///
 /// This is a lightweight helper primarily used by the title bar menu system.
 /// Each `MenuItem` can optionally have an action callback that is invoked on click.
+///
+/// Supports the same keyboard navigation as the title bar: Alt or Ctrl+F2
+/// activates it, Left/Right move between items, Enter/Space triggers the
+/// highlighted item and Escape leaves navigation mode.
 pub struct MenuBar {
     items: Vec<MenuItem>,
+    id: Id,
 }
 
 /// A single item displayed in the `MenuBar`.
@@ -19,7 +26,21 @@ pub struct MenuItem {
 impl MenuBar {
     /// Create an empty `MenuBar`.
     pub fn new() -> Self {
-        Self { items: Vec::new() }
+        Self {
+            items: Vec::new(),
+            id: Id::new("egui_desktop_menu_bar"),
+        }
+    }
+
+    /// Set the id under which the keyboard navigation state is stored.
+    ///
+    /// Only needed when several `MenuBar`s are shown at once.
+    ///
+    /// # Arguments
+    /// - `id_salt`: Any hashable value unique to this bar
+    pub fn with_id(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id = Id::new(id_salt);
+        self
     }
 
     /// Append a new clickable item to the menu bar.
@@ -41,10 +62,29 @@ impl MenuBar {
     ///
     /// Each item is laid out horizontally and becomes highlighted on hover.
     /// When clicked, the item's `action` callback (if any) is executed.
+    /// Keyboard navigation state lives in egui memory, so the bar can be
+    /// rebuilt every frame.
     pub fn render(&self, ui: &mut Ui) {
         let item_height = 28.0; // Match title bar height
 
-        for item in &self.items {
+        let ctx = ui.ctx().clone();
+        let mut navigator: MenuNavigator = ctx.data(|d| d.get_temp(self.id)).unwrap_or_default();
+        let model = MenuNavModel {
+            simple_menus: self.items.len(),
+            hidden_menus: Vec::new(),
+            submenus: Vec::new(),
+        };
+        let action = if self.items.is_empty() {
+            None
+        } else {
+            navigator.update(NavKeys::from_context(&ctx), &model)
+        };
+        let selected = navigator
+            .active
+            .then_some(navigator.selected_menu)
+            .flatten();
+
+        for (index, item) in self.items.iter().enumerate() {
             let item_width = ui.fonts_mut(|f| {
                 f.layout_no_wrap(
                     item.label.clone(),
@@ -57,9 +97,11 @@ impl MenuBar {
             let (rect, response) =
                 ui.allocate_exact_size(Vec2::new(item_width, item_height), Sense::click());
 
-            if response.hovered() {
+            if response.hovered() || selected == Some(index) {
                 ui.painter()
                     .rect_filled(rect, 2.0, Color32::from_rgb(50, 50, 50));
+            }
+            if response.hovered() {
                 ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
             }
 
@@ -71,12 +113,14 @@ impl MenuBar {
                 Color32::from_rgb(200, 200, 200),
             );
 
-            if response.clicked() {
-                if let Some(action) = &item.action {
-                    action();
-                }
+            if (response.clicked() || action == Some(NavAction::Menu(index)))
+                && let Some(action) = &item.action
+            {
+                action();
             }
         }
+
+        ctx.data_mut(|d| d.insert_temp(self.id, navigator));
     }
 }
 
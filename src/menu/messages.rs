@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use std::sync::mpsc::Sender;
+
+use crate::menu::items::SendMessageFn;
+
+/// Typed handle to the app's menu message channel
+///
+/// Items attach a message with `SubMenuItem::with_message()`, which takes
+/// this handle, so every message must be an `M` before the code compiles.
+/// Clones share the same channel.
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_desktop::{MenuItem, MenuMessages, SubMenuItem, TitleBar, TitleBarOptions};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Message {
+///     Save,
+/// }
+///
+/// let (sender, receiver) = std::sync::mpsc::channel();
+/// let messages = MenuMessages::new(sender);
+/// let title_bar = TitleBar::new(TitleBarOptions::new()).add_menu_with_submenu(
+///     MenuItem::new("File").add_subitem(SubMenuItem::new("Save").with_message(&messages, Message::Save)),
+/// );
+///
+/// // In update()
+/// for message in receiver.try_iter() {
+///     assert_eq!(message, Message::Save);
+/// }
+/// # let _ = title_bar;
+/// ```
+pub struct MenuMessages<M> {
+    sender: Sender<M>,
+}
+
+impl<M> Clone for MenuMessages<M> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<M: Clone + Send + 'static> MenuMessages<M> {
+    /// Create a handle sending to `sender`
+    ///
+    /// # Arguments
+    /// * `sender` - Sending half of the app's message channel
+    pub fn new(sender: Sender<M>) -> Self {
+        Self { sender }
+    }
+
+    /// Callback sending a clone of `message`; a disconnected receiver is not an error.
+    pub(crate) fn sender_of(&self, message: M) -> Arc<SendMessageFn>
+    where
+        M: Sync,
+    {
+        let sender = self.sender.clone();
+        Arc::new(move || {
+            let _ = sender.send(message.clone());
+        })
+    }
+}
@@ -1,10 +1,47 @@
+/// AccessKit nodes for the painter-drawn menus.
+#[cfg(feature = "accesskit")]
+mod accessibility;
 /// Public API for rendering menus in the title bar.
 pub mod api;
+/// OS-level shortcuts that fire while the window is unfocused.
+#[cfg(feature = "global-shortcuts")]
+pub mod global_shortcuts;
 /// Menu item types and submenu structures.
 pub mod items;
+/// Pure geometry of the menu bar and submenus.
+pub(crate) mod layout;
+/// `menu!` macro building menu trees declaratively.
+mod macros;
 /// Minimal horizontal menu bar component.
 pub mod menu_bar;
+/// Typed channel handle for the messages of activated menu items.
+pub mod messages;
+/// Menus mirrored into the macOS menu bar.
+#[cfg(target_os = "macos")]
+pub(crate) mod native_macos;
+/// Keyboard navigation state machine shared by the title bar and `MenuBar`.
+pub mod navigation;
+/// "»" menu holding the menus that don't fit in the bar.
+mod overflow;
+/// Cascading menu that can be shown anywhere.
+pub mod popup;
+/// Bounded list of recently opened paths for "Open Recent" menus.
+pub mod recent;
 /// Keyboard shortcuts parsing and handling.
 pub mod shortcuts;
+/// Typed queue for the state callbacks of activated menu items.
+pub mod state_callbacks;
+/// Menus detached from the bar into floating palettes.
+mod tear_off;
+/// Cached label widths for menu layout.
+pub(crate) mod text_cache;
 
-pub use items::{MenuItem, SubMenuItem};
+pub use items::{
+    CustomRow, CustomRowFn, LabelResolverFn, MenuAction, MenuItem, QueueStateCallbackFn,
+    SendMessageFn, SubItemsGenerator, SubMenuItem,
+};
+pub use messages::MenuMessages;
+pub use navigation::{MenuNavModel, MenuNavigator, NavAction, NavEntry, NavKeys};
+pub use popup::{PopupMenu, PopupMenuResponse};
+pub use recent::{OpenRecentFn, RecentMenu};
+pub use state_callbacks::StateCallbacks;
@@ -0,0 +1,370 @@
+use std::cell::{OnceCell, RefCell};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+use egui::Context;
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{MainThreadMarker, MainThreadOnly, define_class, msg_send, sel};
+use objc2_app_kit::{
+    NSApplication, NSControlStateValueOff, NSControlStateValueOn, NSEventModifierFlags, NSMenu,
+    NSMenuItem,
+};
+use objc2_foundation::{NSObject, NSString};
+
+use crate::TitleBar;
+use crate::menu::items::{LabelResolverFn, SubMenuItem, resolve_label};
+use crate::menu::shortcuts::KeyboardShortcut;
+
+/// Tags of the entries chosen in the menu bar since the last frame.
+static CHOSEN_TAGS: Mutex<Vec<isize>> = Mutex::new(Vec::new());
+
+/// Tag given to the first entry of the next mirrored menu bar; tags are
+/// never reused, so entries chosen in a replaced menu bar are told apart.
+static NEXT_TAG: AtomicIsize = AtomicIsize::new(1);
+
+thread_local! {
+    /// Receiver of the menu bar actions; menu items don't retain their target.
+    static TARGET: OnceCell<Retained<MenuTarget>> = const { OnceCell::new() };
+    /// Context woken up when an entry is chosen, so it runs without waiting
+    /// for other input.
+    static REPAINT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+define_class!(
+    // SAFETY: NSObject has no subclassing requirements and `MenuTarget`
+    // does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[thread_kind = MainThreadOnly]
+    #[name = "EguiDesktopMenuTarget"]
+    struct MenuTarget;
+
+    impl MenuTarget {
+        #[unsafe(method(menuItemSelected:))]
+        fn menu_item_selected(&self, sender: &NSMenuItem) {
+            CHOSEN_TAGS.lock().unwrap().push(sender.tag());
+            REPAINT.with(|ctx| {
+                if let Some(ctx) = &*ctx.borrow() {
+                    ctx.request_repaint();
+                }
+            });
+        }
+    }
+);
+
+impl MenuTarget {
+    fn new(mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(());
+        unsafe { msg_send![super(this), init] }
+    }
+}
+
+/// What a menu bar entry runs when chosen.
+#[derive(Debug, Clone)]
+enum NativeAction {
+    /// A top-level menu without a submenu.
+    Menu(usize),
+    /// An entry of a menu, by its index in each submenu on the way down to it.
+    Submenu { menu: usize, path: Vec<usize> },
+}
+
+/// The menus mirrored into the macOS menu bar.
+#[derive(Debug, Default)]
+pub(crate) struct NativeMenuState {
+    /// Hash of the menus last mirrored; the menu bar is rebuilt when it changes.
+    fingerprint: Option<u64>,
+    /// Tag of the first mirrored entry; the others follow in `actions` order.
+    first_tag: isize,
+    /// What each mirrored entry runs.
+    actions: Vec<NativeAction>,
+}
+
+impl TitleBar {
+    /// Mirror the menus into the macOS menu bar and run the entries chosen there
+    ///
+    /// The menu bar is only rebuilt when labels, states or entries changed
+    /// since the last frame. Must run on the main thread, like the rest of
+    /// the UI; elsewhere it does nothing.
+    pub(crate) fn sync_native_menubar(&mut self, ctx: &Context) {
+        let Some(mtm) = MainThreadMarker::new() else {
+            return;
+        };
+        REPAINT.with(|repaint| *repaint.borrow_mut() = Some(ctx.clone()));
+        self.run_native_menu_actions();
+
+        // Entries of dynamic menus are generated when mirrored
+        for menu in &mut self.menu_items_with_submenus {
+            if menu.subitems.is_empty()
+                && let Some(generator) = &menu.dynamic_subitems
+            {
+                menu.subitems = generator();
+            }
+        }
+
+        let fingerprint = self.native_menu_fingerprint();
+        if self.native_menu.fingerprint != Some(fingerprint) {
+            self.native_menu.fingerprint = Some(fingerprint);
+            self.rebuild_native_menubar(mtm);
+        }
+    }
+
+    /// Run the callbacks, messages and state callbacks of the entries chosen
+    /// in the menu bar since the last frame.
+    fn run_native_menu_actions(&mut self) {
+        let state = &self.native_menu;
+        let tags = state.first_tag..state.first_tag + state.actions.len() as isize;
+        let mut chosen = Vec::new();
+        CHOSEN_TAGS.lock().unwrap().retain(|tag| {
+            let ours = tags.contains(tag);
+            if ours {
+                chosen.push((tag - state.first_tag) as usize);
+            }
+            !ours
+        });
+
+        for index in chosen {
+            match &self.native_menu.actions[index] {
+                NativeAction::Menu(menu) => {
+                    if let Some((_, Some(callback))) = self.menu_items.get(*menu) {
+                        callback();
+                    }
+                    self.send_simple_menu_action(*menu);
+                }
+                NativeAction::Submenu { menu, path } => {
+                    if let Some(menu) = self.menu_items_with_submenus.get(*menu)
+                        && let Some(item) = SubMenuItem::at_path(&menu.subitems, path)
+                    {
+                        if let Some(callback) = &item.callback {
+                            callback();
+                        }
+                        self.send_menu_message(item);
+                        self.send_menu_action(menu, path);
+                        self.queue_state_callback(item);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Hash of everything the menu bar shows, labels as shown.
+    fn native_menu_fingerprint(&self) -> u64 {
+        fn hash_items(
+            items: &[SubMenuItem],
+            labels: Option<&LabelResolverFn>,
+            hasher: &mut DefaultHasher,
+        ) {
+            for item in items {
+                resolve_label(labels, &item.label).hash(hasher);
+                (
+                    item.enabled,
+                    item.visible,
+                    item.checked,
+                    item.separator_after,
+                    item.indent_level,
+                )
+                    .hash(hasher);
+                (item.custom.is_some(), item.split).hash(hasher);
+                item.tooltip.hash(hasher);
+                item.shortcut
+                    .as_ref()
+                    .map(|s| s.display_string())
+                    .hash(hasher);
+                hash_items(&item.children, labels, hasher);
+            }
+        }
+
+        let labels = self.label_resolver.as_deref();
+        let mut hasher = DefaultHasher::new();
+        for (label, _) in &self.menu_items {
+            resolve_label(labels, label).hash(&mut hasher);
+        }
+        self.disabled_menu_items.hash(&mut hasher);
+        for menu in &self.menu_items_with_submenus {
+            resolve_label(labels, &menu.label).hash(&mut hasher);
+            (menu.enabled, menu.visible).hash(&mut hasher);
+            hash_items(&menu.subitems, labels, &mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Replace the menus of the menu bar, keeping the application menu in front.
+    fn rebuild_native_menubar(&mut self, mtm: MainThreadMarker) {
+        let target = TARGET.with(|target| target.get_or_init(|| MenuTarget::new(mtm)).clone());
+        let app = NSApplication::sharedApplication(mtm);
+        let main_menu = app.mainMenu().unwrap_or_else(|| {
+            let menu = NSMenu::new(mtm);
+            app.setMainMenu(Some(&menu));
+            menu
+        });
+        if main_menu.numberOfItems() == 0 {
+            main_menu.addItem(&NSMenuItem::new(mtm));
+        }
+        while main_menu.numberOfItems() > 1 {
+            main_menu.removeItemAtIndex(1);
+        }
+
+        let mut builder = NativeMenuBuilder {
+            mtm,
+            target: &target,
+            first_tag: NEXT_TAG.load(Ordering::Relaxed),
+            actions: Vec::new(),
+            labels: self.label_resolver.as_deref(),
+        };
+
+        // The menu bar only opens menus, so simple menus get one entry running them
+        for (index, (label, _)) in self.menu_items.iter().enumerate() {
+            let label = resolve_label(builder.labels, label);
+            let menu = builder.menu(&label);
+            let entry = builder.entry(&label, NativeAction::Menu(index), None);
+            menu.addItem(&entry);
+            main_menu.addItem(&builder.top_level(&label, &menu, self.is_menu_item_enabled(index)));
+        }
+        for (index, menu_item) in self.menu_items_with_submenus.iter().enumerate() {
+            if !menu_item.visible {
+                continue;
+            }
+            let label = resolve_label(builder.labels, &menu_item.label);
+            let menu = builder.menu(&label);
+            builder.fill(&menu, index, &[], &menu_item.subitems);
+            main_menu.addItem(&builder.top_level(&label, &menu, menu_item.enabled));
+        }
+
+        NEXT_TAG.fetch_add(builder.actions.len() as isize, Ordering::Relaxed);
+        self.native_menu.first_tag = builder.first_tag;
+        self.native_menu.actions = builder.actions;
+    }
+}
+
+/// Creates the menu bar entries and numbers them.
+struct NativeMenuBuilder<'a> {
+    mtm: MainThreadMarker,
+    target: &'a MenuTarget,
+    first_tag: isize,
+    actions: Vec<NativeAction>,
+    labels: Option<&'a LabelResolverFn>,
+}
+
+impl NativeMenuBuilder<'_> {
+    /// An empty menu that keeps the enabled state given to its entries.
+    fn menu(&self, title: &str) -> Retained<NSMenu> {
+        let menu = NSMenu::initWithTitle(NSMenu::alloc(self.mtm), &NSString::from_str(title));
+        menu.setAutoenablesItems(false);
+        menu
+    }
+
+    /// A menu bar entry opening `menu`.
+    fn top_level(&self, title: &str, menu: &NSMenu, enabled: bool) -> Retained<NSMenuItem> {
+        let item = NSMenuItem::new(self.mtm);
+        item.setTitle(&NSString::from_str(title));
+        item.setSubmenu(Some(menu));
+        item.setEnabled(enabled);
+        item
+    }
+
+    /// An entry running `action` when chosen.
+    fn entry(
+        &mut self,
+        title: &str,
+        action: NativeAction,
+        shortcut: Option<&KeyboardShortcut>,
+    ) -> Retained<NSMenuItem> {
+        let (key, modifiers) = shortcut
+            .and_then(key_equivalent)
+            .unwrap_or((String::new(), NSEventModifierFlags::empty()));
+        let item = unsafe {
+            NSMenuItem::initWithTitle_action_keyEquivalent(
+                NSMenuItem::alloc(self.mtm),
+                &NSString::from_str(title),
+                Some(sel!(menuItemSelected:)),
+                &NSString::from_str(&key),
+            )
+        };
+        item.setKeyEquivalentModifierMask(modifiers);
+        let target: &AnyObject = self.target;
+        unsafe { item.setTarget(Some(target)) };
+        item.setTag(self.first_tag + self.actions.len() as isize);
+        self.actions.push(action);
+        item
+    }
+
+    /// Add the visible entries of `items`, found at `path` in menu `menu`, to `native`.
+    fn fill(&mut self, native: &NSMenu, menu: usize, path: &[usize], items: &[SubMenuItem]) {
+        for (index, item) in items.iter().enumerate() {
+            // Custom widget rows have no native counterpart
+            if !item.visible || item.custom.is_some() {
+                continue;
+            }
+            let label = resolve_label(self.labels, &item.label);
+            let item_path = [path, &[index]].concat();
+            let entry = self.entry(
+                &label,
+                NativeAction::Submenu {
+                    menu,
+                    path: item_path.clone(),
+                },
+                item.shortcut.as_ref(),
+            );
+            entry.setEnabled(item.enabled);
+            entry.setIndentationLevel(item.indent_level.into());
+            if let Some(checked) = item.checked {
+                entry.setState(if checked {
+                    NSControlStateValueOn
+                } else {
+                    NSControlStateValueOff
+                });
+            }
+            if let Some(tooltip) = &item.tooltip {
+                entry.setToolTip(Some(&NSString::from_str(tooltip)));
+            }
+
+            if !item.children.is_empty() {
+                let side_menu = self.menu(&label);
+                // Native side menus can't be split, so the item's own action
+                // comes first in its side menu
+                if item.split {
+                    let own = self.entry(
+                        &label,
+                        NativeAction::Submenu {
+                            menu,
+                            path: item_path.clone(),
+                        },
+                        None,
+                    );
+                    side_menu.addItem(&own);
+                    side_menu.addItem(&NSMenuItem::separatorItem(self.mtm));
+                }
+                self.fill(&side_menu, menu, &item_path, &item.children);
+                entry.setSubmenu(Some(&side_menu));
+            }
+
+            native.addItem(&entry);
+            if item.separator_after {
+                native.addItem(&NSMenuItem::separatorItem(self.mtm));
+            }
+        }
+    }
+}
+
+/// Key equivalent and modifier mask of `shortcut`, for letter, digit and
+/// punctuation keys; Ctrl shortcuts use Cmd, like they do in the bar.
+fn key_equivalent(shortcut: &KeyboardShortcut) -> Option<(String, NSEventModifierFlags)> {
+    let key = shortcut.key.name();
+    let mut chars = key.chars();
+    let key = match (chars.next(), chars.next()) {
+        (Some(c), None) => c.to_lowercase().to_string(),
+        _ => return None,
+    };
+    let modifiers = shortcut.modifiers;
+    let mut mask = NSEventModifierFlags::empty();
+    if modifiers.ctrl || modifiers.command || modifiers.mac_cmd {
+        mask |= NSEventModifierFlags::Command;
+    }
+    if modifiers.alt {
+        mask |= NSEventModifierFlags::Option;
+    }
+    if modifiers.shift {
+        mask |= NSEventModifierFlags::Shift;
+    }
+    Some((key, mask))
+}
@@ -0,0 +1,497 @@
+use egui::{Context, Key};
+
+/// Keys that drive menu navigation during a single frame.
+///
+/// Decoupled from `egui::InputState` so the state machine can be driven by
+/// any input source.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NavKeys {
+    /// Alt held, or Ctrl+F2 pressed.
+    pub activate: bool,
+    /// Escape pressed.
+    pub escape: bool,
+    /// Arrow left pressed.
+    pub left: bool,
+    /// Arrow right pressed.
+    pub right: bool,
+    /// Arrow up pressed.
+    pub up: bool,
+    /// Arrow down pressed.
+    pub down: bool,
+    /// Enter or Space pressed.
+    pub enter: bool,
+}
+
+impl NavKeys {
+    /// Read the navigation keys for the current frame from egui.
+    pub fn from_context(ctx: &Context) -> Self {
+        ctx.input(|i| Self {
+            activate: i.modifiers.alt || (i.modifiers.ctrl && i.key_pressed(Key::F2)),
+            escape: i.key_pressed(Key::Escape),
+            left: i.key_pressed(Key::ArrowLeft),
+            right: i.key_pressed(Key::ArrowRight),
+            up: i.key_pressed(Key::ArrowUp),
+            down: i.key_pressed(Key::ArrowDown),
+            enter: i.key_pressed(Key::Enter) || i.key_pressed(Key::Space),
+        })
+    }
+}
+
+/// A submenu entry as seen by the navigator.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NavEntry {
+    /// Whether the entry can be activated.
+    pub enabled: bool,
+    /// Whether Up/Down pass over this entry, e.g. for custom widget rows.
+    pub skip: bool,
+    /// Entries of the side menu; empty when there is none.
+    pub children: Vec<NavEntry>,
+    /// Whether Enter activates the entry itself even though it has a side
+    /// menu, which then only opens with Right.
+    pub split: bool,
+    /// Column and row of the entry in a multi-column menu; Left/Right move
+    /// between columns and Up/Down stay within one. `(0, 0)` everywhere
+    /// for single-column menus.
+    pub cell: (usize, usize),
+}
+
+/// Shape of a menu bar as seen by the navigator.
+///
+/// Top-level indices cover the simple menus first, followed by the menus
+/// with submenus, matching the order the title bar draws them in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MenuNavModel {
+    /// Number of top-level menus without a submenu.
+    pub simple_menus: usize,
+    /// Top-level indices of hidden or disabled menus, which Left/Right pass over.
+    pub hidden_menus: Vec<usize>,
+    /// Entries of each menu that owns a submenu.
+    pub submenus: Vec<Vec<NavEntry>>,
+}
+
+impl MenuNavModel {
+    /// Total number of top-level menus.
+    pub fn top_level_count(&self) -> usize {
+        self.simple_menus + self.submenus.len()
+    }
+
+    /// Whether keyboard navigation can highlight the top-level menu `index`.
+    fn is_visible(&self, index: usize) -> bool {
+        !self.hidden_menus.contains(&index)
+    }
+
+    /// Whether `index` is an existing menu keyboard navigation can highlight.
+    fn is_selectable(&self, index: usize) -> bool {
+        index < self.top_level_count() && self.is_visible(index)
+    }
+
+    /// First menu keyboard navigation can highlight, if any.
+    fn first_visible(&self) -> Option<usize> {
+        (0..self.top_level_count()).find(|&i| self.is_visible(i))
+    }
+
+    /// Entries of the side menu reached from `submenu` through `parents`, or
+    /// of `submenu` itself when `parents` is empty.
+    fn entries_at(&self, submenu: usize, parents: &[usize]) -> Option<&[NavEntry]> {
+        parents
+            .iter()
+            .try_fold(self.submenus.get(submenu)?.as_slice(), |entries, &item| {
+                Some(entries.get(item)?.children.as_slice())
+            })
+    }
+}
+
+/// Something the user activated from the keyboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NavAction {
+    /// A top-level menu without a submenu.
+    Menu(usize),
+    /// An entry of an open submenu or of one of its side menus.
+    Submenu {
+        /// Index of the submenu.
+        submenu: usize,
+        /// Index of the entry in the submenu, followed by its index in each
+        /// side menu on the way down to it.
+        path: Vec<usize>,
+    },
+}
+
+/// Keyboard navigation state machine shared by `TitleBar` and `MenuBar`.
+///
+/// Handles Alt/Ctrl+F2 activation, Left/Right across top-level menus,
+/// Up/Down inside submenus and side menus nested to any depth, Enter/Space
+/// activation and Escape. It never touches egui rendering: feed it `NavKeys`
+/// and a `MenuNavModel` each frame and act on the returned `NavAction`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MenuNavigator {
+    /// Whether keyboard navigation is active.
+    pub active: bool,
+    /// Highlighted top-level menu.
+    pub selected_menu: Option<usize>,
+    /// Index of the open submenu, if any.
+    pub open_submenu: Option<usize>,
+    /// Highlighted entry of the open submenu, followed by the highlighted
+    /// entry of each side menu opened from it. Every entry but the last has
+    /// its side menu open.
+    pub selection: Vec<usize>,
+    /// Set for one frame after a submenu opens so the same Enter press does
+    /// not also activate its first entry.
+    pub just_opened: bool,
+}
+
+impl MenuNavigator {
+    /// Advance the state machine by one frame.
+    ///
+    /// Returns the entry activated with Enter/Space, if any.
+    pub fn update(&mut self, keys: NavKeys, model: &MenuNavModel) -> Option<NavAction> {
+        if keys.activate && !self.active {
+            self.active = true;
+            self.selected_menu = model.first_visible();
+        }
+
+        if !self.active {
+            return None;
+        }
+
+        // Menus may have been removed or hidden since the last frame
+        if let Some(current) = self.selected_menu
+            && !model.is_selectable(current)
+        {
+            self.selected_menu = (0..current)
+                .rev()
+                .find(|&i| model.is_selectable(i))
+                .or_else(|| model.first_visible());
+            self.close_submenu();
+        }
+
+        // ...and entries along the selection with them
+        match self.open_submenu {
+            Some(menu) => {
+                let valid = (0..self.selection.len())
+                    .take_while(|&depth| {
+                        model
+                            .entries_at(menu, &self.selection[..depth])
+                            .is_some_and(|entries| self.selection[depth] < entries.len())
+                    })
+                    .count();
+                self.selection.truncate(valid);
+            }
+            None => self.selection.clear(),
+        }
+
+        if keys.escape {
+            self.active = false;
+            self.selected_menu = None;
+            self.close_submenu();
+            return None;
+        }
+
+        let action = match self.open_submenu {
+            Some(menu) if !self.selection.is_empty() => self.navigate_submenu(keys, model, menu),
+            _ => self.navigate_menus(keys, model),
+        };
+
+        // One-frame guard; side menus stay open until Left or Escape
+        self.just_opened = false;
+
+        action
+    }
+
+    /// Handle the keys while an entry of the open submenu, or of one of its
+    /// side menus, is highlighted.
+    fn navigate_submenu(
+        &mut self,
+        keys: NavKeys,
+        model: &MenuNavModel,
+        menu: usize,
+    ) -> Option<NavAction> {
+        let depth = self.selection.len() - 1;
+        let current = self.selection[depth];
+        let entries = model.entries_at(menu, &self.selection[..depth])?;
+        let entry = &entries[current];
+        let has_side_menu = !entry.children.is_empty();
+
+        if keys.enter {
+            if self.just_opened || !entry.enabled {
+                return None;
+            }
+            // Entries with a side menu open it, unless they are split; a side
+            // menu without any entry to land on stays closed
+            if has_side_menu && !entry.split {
+                if let Some(first) = first_navigable(&entry.children) {
+                    self.selection.push(first);
+                }
+                return None;
+            }
+            let path = std::mem::take(&mut self.selection);
+            self.open_submenu = None;
+            return Some(NavAction::Submenu {
+                submenu: menu,
+                path,
+            });
+        }
+
+        if keys.up || keys.down {
+            if let Some(target) = step(entries, current, keys.down) {
+                self.selection[depth] = target;
+            }
+            return None;
+        }
+
+        // In multi-column menus, Left/Right move to the neighboring column
+        // first; Right still opens the side menu of the highlighted entry
+        if keys.left {
+            if let Some(target) = adjacent_column(entries, current, false) {
+                self.selection[depth] = target;
+            } else if depth > 0 {
+                // Back out of the innermost side menu
+                self.selection.pop();
+            } else {
+                self.walk_menus(model, false);
+            }
+        } else if keys.right {
+            if let Some(first) = first_navigable(&entry.children) {
+                self.selection.push(first);
+            } else if let Some(target) = adjacent_column(entries, current, true) {
+                self.selection[depth] = target;
+            } else {
+                self.walk_menus(model, true);
+            }
+        }
+        None
+    }
+
+    /// Handle the keys while no submenu entry is highlighted.
+    fn navigate_menus(&mut self, keys: NavKeys, model: &MenuNavModel) -> Option<NavAction> {
+        if keys.left {
+            self.walk_menus(model, false);
+        }
+        if keys.right {
+            self.walk_menus(model, true);
+        }
+
+        if !keys.enter {
+            return None;
+        }
+        let index = self.selected_menu?;
+        if index < model.simple_menus {
+            return Some(NavAction::Menu(index));
+        }
+        let menu = index - model.simple_menus;
+        if let Some(first) = model.submenus.get(menu).and_then(|e| first_navigable(e)) {
+            self.open_submenu = Some(menu);
+            self.selection = vec![first];
+        }
+        None
+    }
+
+    /// Highlight the next (or previous) top-level menu, closing the open submenu.
+    fn walk_menus(&mut self, model: &MenuNavModel, forward: bool) {
+        let Some(current) = self.selected_menu else {
+            return;
+        };
+        let target = if forward {
+            (current + 1..model.top_level_count()).find(|&i| model.is_visible(i))
+        } else {
+            (0..current).rev().find(|&i| model.is_visible(i))
+        };
+        if let Some(target) = target {
+            self.selected_menu = Some(target);
+            self.close_submenu();
+        }
+    }
+
+    fn close_submenu(&mut self) {
+        self.open_submenu = None;
+        self.selection.clear();
+    }
+}
+
+/// Index of the first entry Up/Down can land on, if any.
+fn first_navigable(entries: &[NavEntry]) -> Option<usize> {
+    entries.iter().position(|entry| !entry.skip)
+}
+
+/// Next (or previous) entry after `current` that isn't skipped, within its column.
+fn step(entries: &[NavEntry], current: usize, forward: bool) -> Option<usize> {
+    let column = entries.get(current).map_or(0, |entry| entry.cell.0);
+    let usable = |i: &usize| !entries[*i].skip && entries[*i].cell.0 == column;
+    if forward {
+        (current + 1..entries.len()).find(usable)
+    } else {
+        (0..current.min(entries.len())).rev().find(usable)
+    }
+}
+
+/// Entry of the next (or previous) column closest to the row of `current`.
+fn adjacent_column(entries: &[NavEntry], current: usize, forward: bool) -> Option<usize> {
+    let (column, row) = entries.get(current)?.cell;
+    let target = if forward {
+        column + 1
+    } else {
+        column.checked_sub(1)?
+    };
+    (0..entries.len())
+        .filter(|&i| !entries[i].skip && entries[i].cell.0 == target)
+        .min_by_key(|&i| entries[i].cell.1.abs_diff(row))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACTIVATE: NavKeys = NavKeys {
+        activate: true,
+        escape: false,
+        left: false,
+        right: false,
+        up: false,
+        down: false,
+        enter: false,
+    };
+    const NONE: NavKeys = NavKeys {
+        activate: false,
+        ..ACTIVATE
+    };
+    const LEFT: NavKeys = NavKeys { left: true, ..NONE };
+    const RIGHT: NavKeys = NavKeys {
+        right: true,
+        ..NONE
+    };
+    const DOWN: NavKeys = NavKeys { down: true, ..NONE };
+    const ENTER: NavKeys = NavKeys {
+        enter: true,
+        ..NONE
+    };
+    const ESCAPE: NavKeys = NavKeys {
+        escape: true,
+        ..NONE
+    };
+
+    fn entry() -> NavEntry {
+        NavEntry {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Menus: 0 "Home" without a submenu, 1 "File" with two entries, the
+    /// second one with a side menu of two entries
+    fn model() -> MenuNavModel {
+        MenuNavModel {
+            simple_menus: 1,
+            hidden_menus: Vec::new(),
+            submenus: vec![vec![
+                entry(),
+                NavEntry {
+                    children: vec![entry(), entry()],
+                    ..entry()
+                },
+            ]],
+        }
+    }
+
+    /// A navigator that went through `keys`, one frame each.
+    fn navigated(model: &MenuNavModel, keys: &[NavKeys]) -> MenuNavigator {
+        let mut navigator = MenuNavigator::default();
+        for &keys in keys {
+            navigator.update(keys, model);
+        }
+        navigator
+    }
+
+    #[test]
+    fn activation_selects_the_first_menu() {
+        let model = model();
+        let mut navigator = MenuNavigator::default();
+        assert_eq!(navigator.update(RIGHT, &model), None);
+        assert!(!navigator.active);
+        navigator.update(ACTIVATE, &model);
+        assert!(navigator.active);
+        assert_eq!(navigator.selected_menu, Some(0));
+        assert_eq!(navigator.update(ENTER, &model), Some(NavAction::Menu(0)));
+    }
+
+    #[test]
+    fn left_and_right_stop_at_the_ends() {
+        let model = model();
+        let navigator = navigated(&model, &[ACTIVATE, LEFT]);
+        assert_eq!(navigator.selected_menu, Some(0));
+        let navigator = navigated(&model, &[ACTIVATE, RIGHT, RIGHT, RIGHT]);
+        assert_eq!(navigator.selected_menu, Some(1));
+    }
+
+    #[test]
+    fn hidden_menus_are_passed_over() {
+        let model = MenuNavModel {
+            simple_menus: 3,
+            hidden_menus: vec![0, 1],
+            ..model()
+        };
+        let navigator = navigated(&model, &[ACTIVATE]);
+        assert_eq!(navigator.selected_menu, Some(2));
+        let navigator = navigated(&model, &[ACTIVATE, RIGHT, LEFT]);
+        assert_eq!(navigator.selected_menu, Some(2));
+    }
+
+    #[test]
+    fn enter_opens_a_submenu_and_activates_its_entries() {
+        let model = model();
+        let mut navigator = navigated(&model, &[ACTIVATE, RIGHT]);
+        assert_eq!(navigator.update(ENTER, &model), None);
+        assert_eq!(navigator.open_submenu, Some(0));
+        assert_eq!(navigator.selection, [0]);
+        navigator.update(DOWN, &model);
+        navigator.update(DOWN, &model);
+        assert_eq!(navigator.selection, [1]);
+        navigator.update(RIGHT, &model);
+        navigator.update(DOWN, &model);
+        assert_eq!(navigator.selection, [1, 1]);
+        navigator.update(LEFT, &model);
+        assert_eq!(navigator.selection, [1]);
+        navigator.update(RIGHT, &model);
+        assert_eq!(
+            navigator.update(ENTER, &model),
+            Some(NavAction::Submenu {
+                submenu: 0,
+                path: vec![1, 0],
+            })
+        );
+        assert_eq!(navigator.open_submenu, None);
+    }
+
+    #[test]
+    fn menus_without_a_reachable_entry_stay_closed() {
+        let model = MenuNavModel {
+            simple_menus: 0,
+            hidden_menus: Vec::new(),
+            submenus: vec![vec![NavEntry {
+                skip: true,
+                ..entry()
+            }]],
+        };
+        let mut navigator = navigated(&model, &[ACTIVATE, ENTER]);
+        assert_eq!(navigator.open_submenu, None);
+        assert_eq!(navigator.update(ENTER, &model), None);
+        assert!(navigator.selection.is_empty());
+    }
+
+    #[test]
+    fn escape_resets_the_navigator() {
+        let model = model();
+        let navigator = navigated(&model, &[ACTIVATE, RIGHT, ENTER, ESCAPE]);
+        assert_eq!(navigator, MenuNavigator::default());
+    }
+
+    #[test]
+    fn an_empty_model_selects_nothing() {
+        let model = MenuNavModel::default();
+        let mut navigator = navigated(&model, &[ACTIVATE]);
+        for keys in [RIGHT, LEFT, DOWN, ENTER] {
+            assert_eq!(navigator.update(keys, &model), None);
+        }
+        assert!(navigator.active);
+        assert_eq!(navigator.selected_menu, None);
+        assert_eq!(navigator.open_submenu, None);
+    }
+}
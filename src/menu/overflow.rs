@@ -0,0 +1,176 @@
+use egui::{Align2, Area, Color32, Context, FontId, Order, Pos2, Rect, Sense, Ui, Vec2};
+
+#[cfg(feature = "accesskit")]
+use crate::menu::accessibility::{MenuNode, describe_menu_item};
+use crate::menu::items::{MenuItem, SubMenuItem};
+use crate::{MenuActivation, TitleBar, TitleBarEdge};
+
+/// Label of the button holding the menus that don't fit in the bar.
+pub(crate) const OVERFLOW_LABEL: &str = "»";
+
+impl TitleBar {
+    /// Draw the "»" button at `rect` and open or close its menu on click.
+    pub(crate) fn render_overflow_button(&mut self, ui: &mut Ui, rect: Rect, font: &FontId) {
+        let response = ui.interact(rect, self.id.with("menu_overflow"), Sense::click());
+
+        #[cfg(feature = "accesskit")]
+        describe_menu_item(
+            ui.ctx(),
+            response.id,
+            MenuNode {
+                label: "More menus",
+                enabled: true,
+                checked: None,
+                expanded: Some(self.overflow_menu_open),
+                shortcut: None,
+            },
+        );
+
+        // Keyboard navigation walks the collapsed menus on this button
+        let is_keyboard_selected = self.keyboard_navigation_active
+            && self
+                .selected_menu_index
+                .is_some_and(|index| self.overflow_menus.contains(&index));
+        self.paint_menu_label_highlight(ui, &response, rect, is_keyboard_selected);
+        let text_color = if is_keyboard_selected {
+            Color32::WHITE
+        } else {
+            self.menu_text_color
+        };
+        ui.painter().text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            OVERFLOW_LABEL,
+            font.clone(),
+            text_color,
+        );
+
+        // Like the other menus, hovering the button opens its menu in hover
+        // activation, or switches to it while another menu is open
+        let hover_activation = self.menu_activation == MenuActivation::Hover;
+        if response.hovered() {
+            self.pointer_over_menu_label = true;
+        }
+        let switch_here = response.hovered()
+            && !self.overflow_menu_open
+            && (hover_activation || self.open_submenu.is_some());
+        if response.clicked() || switch_here {
+            self.overflow_menu_open = switch_here || hover_activation || !self.overflow_menu_open;
+            if self.overflow_menu_open {
+                self.open_submenu = None;
+                self.generate_overflow_entries();
+            }
+        }
+    }
+
+    /// Run the generators of the collapsed dynamic menus, once per opening.
+    fn generate_overflow_entries(&mut self) {
+        let simple_menus = self.menu_items.len();
+        for &index in &self.overflow_menus {
+            if let Some(menu) = index
+                .checked_sub(simple_menus)
+                .and_then(|index| self.menu_items_with_submenus.get_mut(index))
+                && let Some(generator) = &menu.dynamic_subitems
+            {
+                menu.subitems = generator();
+            }
+        }
+    }
+
+    /// The "»" menu: one entry per collapsed menu, with its entries as a side menu.
+    fn overflow_menu(&self) -> MenuItem {
+        let simple_menus = self.menu_items.len();
+        self.overflow_menus
+            .iter()
+            .filter_map(|&index| match index.checked_sub(simple_menus) {
+                None => {
+                    let mut entry = SubMenuItem::new(&self.menu_items[index].0);
+                    entry.enabled = self.is_menu_item_enabled(index);
+                    Some(entry)
+                }
+                Some(index) => {
+                    let menu = self.menu_items_with_submenus.get(index)?;
+                    let mut entry = SubMenuItem::new(&menu.label);
+                    entry.enabled = menu.enabled;
+                    entry.children = menu.subitems.clone();
+                    Some(entry)
+                }
+            })
+            .fold(MenuItem::new(OVERFLOW_LABEL), MenuItem::add_subitem)
+    }
+
+    /// Draw the "»" menu while it is open and run the entry chosen in it
+    ///
+    /// Its entries are copies without callbacks, so the callback, message and
+    /// state callback of the chosen entry are taken from the collapsed menu.
+    pub(crate) fn render_overflow_menu(&mut self, ctx: &Context) {
+        if !self.overflow_menu_open {
+            return;
+        }
+        let Some(start) = self.overflow_position else {
+            return;
+        };
+        let bar_rect = self.bar_rect_or_default(ctx);
+        let open_upward = self.position == TitleBarEdge::Bottom;
+        let position = Pos2::new(
+            start,
+            if open_upward {
+                bar_rect.min.y
+            } else {
+                bar_rect.max.y
+            },
+        );
+
+        let menu = self.overflow_menu();
+        let id = self.id.with("menu_overflow");
+        let popup = self.popup_menu(&menu, id);
+        let text_widths = &mut self.text_widths;
+        let response = Area::new(id.with("overlay"))
+            .fixed_pos(Pos2::ZERO)
+            .default_size(Vec2::ZERO)
+            .order(Order::Foreground)
+            .show(ctx, |ui| popup.show_in(ui, position, text_widths))
+            .inner;
+
+        if let Some(path) = response.activated {
+            self.overflow_menu_open = false;
+            self.activate_overflow_entry(&path);
+        } else if response.clicked_elsewhere
+            && ctx
+                .input(|i| i.pointer.interact_pos())
+                .is_some_and(|pos| !bar_rect.contains(pos))
+        {
+            self.overflow_menu_open = false;
+        }
+        if self.hovered_away(ctx, &response.rects) {
+            self.overflow_menu_open = false;
+        }
+    }
+
+    /// Run the entry at `path` in the "»" menu on the menu it was copied from.
+    fn activate_overflow_entry(&mut self, path: &[usize]) {
+        let Some((&first, rest)) = path.split_first() else {
+            return;
+        };
+        let Some(&index) = self.overflow_menus.get(first) else {
+            return;
+        };
+        let Some(menu) = index.checked_sub(self.menu_items.len()) else {
+            if let Some(callback) = &self.menu_items[index].1 {
+                callback();
+            }
+            self.send_simple_menu_action(index);
+            return;
+        };
+        if let Some(menu) = self.menu_items_with_submenus.get(menu)
+            && let Some(item) = SubMenuItem::at_path(&menu.subitems, rest)
+        {
+            if let Some(callback) = &item.callback {
+                callback();
+            }
+            self.send_menu_message(item);
+            self.send_menu_action(menu, rest);
+            self.queue_state_callback(item);
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::Duration;
+
+use egui::{Area, Color32, Context, Id, Order, Pos2, Rect, Shadow, Ui, Vec2};
+
+use crate::menu::api::SubmenuStyle;
+use crate::menu::items::{LabelResolverFn, MenuItem, SubMenuItem};
+use crate::menu::text_cache::TextWidthCache;
+use crate::titlebar::main::{COMPACT_SCALE, DEFAULT_HOVER_ANIMATION, DEFAULT_SUBMENU_SHADOW};
+use crate::{ChevronStyle, TitleBar, TitleBarEdge, TitleBarTheme};
+
+/// A cascading menu that can be shown anywhere, e.g. as a context menu or dropdown
+///
+/// This is the menu the title bar opens under its labels: the subitems of a
+/// `MenuItem` with their shortcuts, check marks, separators, custom rows and
+/// side menus. It draws above the rest of the UI at the given position and
+/// runs the callback of the entry the user picks.
+///
+/// Nothing is kept between frames besides hover and scroll state, so the
+/// caller decides when the menu is open and shows it every frame until it
+/// should close.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use egui::{Context, Pos2};
+/// # use egui_desktop::{MenuItem, PopupMenu, SubMenuItem};
+/// # fn cut() {}
+/// # fn paste() {}
+/// # struct App { context_menu_at: Option<Pos2> }
+/// # impl App {
+/// # fn update(&mut self, ctx: &Context) {
+/// let menu = MenuItem::new("Edit")
+///     .add_subitem(SubMenuItem::new("Cut").with_callback(Box::new(|| cut())))
+///     .add_subitem(SubMenuItem::new("Paste").with_callback(Box::new(|| paste())));
+///
+/// if let Some(position) = self.context_menu_at {
+///     let response = PopupMenu::new("editor_context_menu", &menu).show(ctx, position);
+///     if response.activated.is_some() || response.clicked_elsewhere {
+///         self.context_menu_at = None;
+///     }
+/// }
+/// # }
+/// # }
+/// ```
+pub struct PopupMenu<'a> {
+    menu: &'a MenuItem,
+    id: Id,
+    style: Option<PopupStyle>,
+    ui_scale: f32,
+    compact: bool,
+    open_upward: bool,
+    rtl: bool,
+    max_visible_items: Option<usize>,
+    max_width: Option<f32>,
+    hover_delays: (Duration, Duration),
+    hover_animation: Duration,
+    label_resolver: Option<Arc<LabelResolverFn>>,
+    keyboard: Option<Vec<usize>>,
+}
+
+/// What happened in a [`PopupMenu`] this frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PopupMenuResponse {
+    /// Entry activated this frame: its index among the subitems, followed by
+    /// its index in each side menu on the way down to it.
+    pub activated: Option<Vec<usize>>,
+    /// Screen areas of the menu and of every side menu shown this frame.
+    pub rects: Vec<Rect>,
+    /// Whether the primary button was clicked outside all of `rects`, the
+    /// usual cue to close the menu.
+    pub clicked_elsewhere: bool,
+}
+
+/// Colors, text size, separators, arrows, shadow and shape of a popup menu.
+#[derive(Debug, Clone, Copy)]
+struct PopupStyle {
+    text_size: f32,
+    background_color: Color32,
+    text_color: Color32,
+    hover_color: Color32,
+    shortcut_color: Color32,
+    border_color: Color32,
+    separator_color: Color32,
+    separator_thickness: f32,
+    keyboard_selection_color: Color32,
+    chevron_color: Color32,
+    chevron_size: Option<f32>,
+    chevron_style: ChevronStyle,
+    shadow: Shadow,
+    corner_radius: u8,
+    min_width: f32,
+}
+
+impl PopupStyle {
+    fn from_theme(theme: &TitleBarTheme) -> Self {
+        Self {
+            text_size: theme.menu_text_size,
+            background_color: theme.submenu_background_color,
+            text_color: theme.submenu_text_color,
+            hover_color: theme.submenu_hover_color,
+            shortcut_color: theme.submenu_shortcut_color,
+            border_color: theme.submenu_border_color,
+            separator_color: theme.submenu_border_color,
+            separator_thickness: 1.0,
+            keyboard_selection_color: theme.submenu_keyboard_selection_color,
+            chevron_color: theme.submenu_text_color,
+            chevron_size: None,
+            chevron_style: ChevronStyle::default(),
+            shadow: DEFAULT_SUBMENU_SHADOW,
+            corner_radius: 4,
+            min_width: 120.0,
+        }
+    }
+}
+
+impl<'a> PopupMenu<'a> {
+    /// Create a popup menu listing the subitems of `menu`
+    ///
+    /// The menu's own label is not shown. Colors follow the light or dark
+    /// title bar theme, matching egui's current visuals, until
+    /// `with_theme` picks one.
+    ///
+    /// # Arguments
+    /// * `id_salt` - Any hashable value unique to this menu
+    /// * `menu` - The menu whose subitems are listed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::{MenuItem, PopupMenu};
+    /// # let file_menu = MenuItem::new("File");
+    /// PopupMenu::new("file_dropdown", &file_menu)
+    /// # ;
+    /// ```
+    pub fn new(id_salt: impl Hash, menu: &'a MenuItem) -> Self {
+        Self {
+            menu,
+            id: Id::new(id_salt),
+            style: None,
+            ui_scale: 1.0,
+            compact: false,
+            open_upward: false,
+            rtl: false,
+            max_visible_items: None,
+            max_width: None,
+            hover_delays: (Duration::ZERO, Duration::ZERO),
+            hover_animation: DEFAULT_HOVER_ANIMATION,
+            label_resolver: None,
+            keyboard: None,
+        }
+    }
+
+    /// Take the submenu colors and menu text size from `theme`.
+    pub fn with_theme(mut self, theme: &TitleBarTheme) -> Self {
+        self.style = Some(PopupStyle::from_theme(theme));
+        self
+    }
+
+    /// Set the multiplier applied to every menu metric (default `1.0`).
+    pub fn with_ui_scale(mut self, scale: f32) -> Self {
+        self.ui_scale = scale;
+        self
+    }
+
+    /// Draw shorter rows, like the menus of a compact title bar (default `false`).
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Open upward, with `position` as the bottom corner of the menu (default `false`).
+    pub fn with_open_upward(mut self, open_upward: bool) -> Self {
+        self.open_upward = open_upward;
+        self
+    }
+
+    /// Hang the menu and its side menus to the left of `position` (default `false`).
+    pub fn with_rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Cap the menu at this many rows, scrolling the rest.
+    pub fn with_max_visible_items(mut self, rows: usize) -> Self {
+        self.max_visible_items = Some(rows);
+        self
+    }
+
+    /// Cap the menu width in points, cutting long labels short.
+    pub fn with_max_width(mut self, width: f32) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Wait before opening and closing side menus on hover (both default to zero).
+    pub fn with_hover_delays(mut self, open: Duration, close: Duration) -> Self {
+        self.hover_delays = (open, close);
+        self
+    }
+
+    /// Fade hover highlights in and out over `duration`; zero snaps them (default 100 ms).
+    pub fn with_hover_animation(mut self, duration: Duration) -> Self {
+        self.hover_animation = duration;
+        self
+    }
+
+    /// Treat labels as keys and show the text `resolver` returns for them.
+    pub fn with_label_resolver(mut self, resolver: Arc<LabelResolverFn>) -> Self {
+        self.label_resolver = Some(resolver);
+        self
+    }
+
+    /// Show the menu with its top-left corner at `position`
+    ///
+    /// The top-right corner in right-to-left menus, and the bottom corner
+    /// when opening upward. The menu is moved sideways to stay on screen.
+    ///
+    /// # Arguments
+    /// * `ctx` - The egui context
+    /// * `position` - Where the menu is anchored, in screen points
+    pub fn show(self, ctx: &Context, position: Pos2) -> PopupMenuResponse {
+        let mut text_widths = TextWidthCache::default();
+        Area::new(self.id.with("overlay"))
+            .fixed_pos(Pos2::ZERO)
+            .default_size(Vec2::ZERO)
+            .order(Order::Foreground)
+            .show(ctx, |ui| self.show_in(ui, position, &mut text_widths))
+            .inner
+    }
+
+    /// Draw the menu into `ui`, which should cover the screen from its origin.
+    pub(crate) fn show_in(
+        &self,
+        ui: &mut Ui,
+        position: Pos2,
+        text_widths: &mut TextWidthCache,
+    ) -> PopupMenuResponse {
+        let style = self.style.unwrap_or_else(|| {
+            PopupStyle::from_theme(&if ui.visuals().dark_mode {
+                TitleBarTheme::dark()
+            } else {
+                TitleBarTheme::light()
+            })
+        });
+        // Separators scale with the rows they sit between
+        let row_scale = self.ui_scale * if self.compact { COMPACT_SCALE } else { 1.0 };
+        let submenu_style = SubmenuStyle {
+            text_size: style.text_size * self.ui_scale,
+            item_height: 24.0 * row_scale,
+            separator_height: style.separator_thickness * row_scale,
+            background_color: style.background_color,
+            text_color: style.text_color,
+            hover_color: style.hover_color,
+            shortcut_color: style.shortcut_color,
+            border_color: style.border_color,
+            separator_color: style.separator_color,
+            keyboard_selection_color: style.keyboard_selection_color,
+            chevron_color: style.chevron_color,
+            chevron_size: style.chevron_size.unwrap_or(style.text_size * 0.6) * self.ui_scale,
+            chevron_style: style.chevron_style,
+            shadow: style.shadow,
+            corner_radius: style.corner_radius,
+            min_width: style.min_width,
+            ui_scale: self.ui_scale,
+            open_upward: self.open_upward,
+            hover_delays: self.hover_delays,
+            hover_animation: self.hover_animation,
+            rtl: self.rtl,
+            max_visible_items: self.max_visible_items,
+            max_width: self.max_width,
+            label_resolver: self.label_resolver.as_deref(),
+        };
+        let mut response = TitleBar::render_submenu_overlay_static(
+            ui,
+            self.menu,
+            self.id,
+            position,
+            &submenu_style,
+            self.keyboard.as_deref(),
+            text_widths,
+        );
+        // Side menus are drawn from copies without callbacks, so run the
+        // callback of the chosen entry on the menu itself
+        if let Some(path) = &response.activated
+            && let Some(item) = SubMenuItem::at_path(&self.menu.subitems, path)
+            && let Some(callback) = &item.callback
+        {
+            callback();
+        }
+        response.clicked_elsewhere = ui.input(|i| {
+            i.pointer.primary_clicked()
+                && i.pointer
+                    .interact_pos()
+                    .is_some_and(|pos| !response.rects.iter().any(|rect| rect.contains(pos)))
+        });
+        response
+    }
+
+    /// Highlight the entries of the title bar's keyboard selection
+    ///
+    /// `path` holds the highlighted entry of the menu, followed by the
+    /// highlighted entry of each side menu opened from it.
+    pub(crate) fn with_keyboard(mut self, path: Vec<usize>) -> Self {
+        self.keyboard = Some(path);
+        self
+    }
+}
+
+impl TitleBar {
+    /// Popup showing `menu` the way the bar's own menus look
+    ///
+    /// `id` is used as is, so hover and scroll state live under the bar's
+    /// widget ids.
+    pub(crate) fn popup_menu<'a>(&self, menu: &'a MenuItem, id: Id) -> PopupMenu<'a> {
+        PopupMenu {
+            menu,
+            id,
+            style: Some(PopupStyle {
+                text_size: self.menu_text_size,
+                background_color: self.submenu_background_color,
+                text_color: self.submenu_text_color,
+                hover_color: self.submenu_hover_color,
+                shortcut_color: self.submenu_shortcut_color,
+                border_color: self.submenu_border_color,
+                separator_color: self
+                    .submenu_separator_color
+                    .unwrap_or(self.submenu_border_color),
+                separator_thickness: self.submenu_separator_thickness,
+                keyboard_selection_color: self.submenu_keyboard_selection_color,
+                chevron_color: self
+                    .submenu_chevron_color
+                    .unwrap_or(self.submenu_text_color),
+                chevron_size: self.submenu_chevron_size,
+                chevron_style: self.submenu_chevron_style,
+                shadow: self.submenu_shadow,
+                corner_radius: self.submenu_corner_radius,
+                min_width: self.submenu_min_width,
+            }),
+            ui_scale: self.ui_scale,
+            compact: self.compact,
+            open_upward: self.position == TitleBarEdge::Bottom,
+            rtl: self.rtl,
+            max_visible_items: self.submenu_max_visible_items,
+            max_width: self.submenu_max_width,
+            hover_delays: (self.submenu_open_delay, self.submenu_close_delay),
+            hover_animation: self.hover_animation_time(),
+            label_resolver: self.label_resolver.clone(),
+            keyboard: None,
+        }
+    }
+}
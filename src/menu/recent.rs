@@ -0,0 +1,126 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::menu::items::{SubItemsGenerator, SubMenuItem};
+
+/// Callback opening a path chosen in a recent-files menu.
+pub type OpenRecentFn = dyn Fn(&Path) + Send + Sync;
+
+/// Most recently used paths, newest first, for an "Open Recent" menu
+///
+/// Pushing a path that is already listed moves it to the top instead of
+/// listing it twice, and the oldest paths drop off past the capacity. With
+/// the `serde` feature the list can be saved and restored with the app.
+///
+/// # Examples
+///
+/// ```rust
+/// # use egui_desktop::{MenuItem, RecentMenu};
+/// # use std::path::Path;
+/// # use std::sync::{Arc, Mutex};
+/// # fn open_file(_path: &Path) {}
+/// let recent = Arc::new(Mutex::new(RecentMenu::new(10)));
+/// let file_menu = MenuItem::new("File").with_dynamic_subitems(RecentMenu::generator(
+///     recent.clone(),
+///     Arc::new(|path| open_file(path)),
+/// ));
+///
+/// // After opening a file
+/// recent.lock().unwrap().push("/home/me/notes.txt");
+/// # let _ = file_menu;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecentMenu {
+    paths: Vec<PathBuf>,
+    capacity: usize,
+}
+
+impl Default for RecentMenu {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl RecentMenu {
+    /// Create an empty list keeping at most `capacity` paths.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            paths: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Put `path` at the top of the list, dropping its older entry and the
+    /// paths past the capacity.
+    pub fn push(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        self.paths.retain(|existing| *existing != path);
+        self.paths.insert(0, path);
+        self.paths.truncate(self.capacity);
+    }
+
+    /// Remove `path` from the list, e.g. after it failed to open.
+    pub fn remove(&mut self, path: &Path) {
+        self.paths.retain(|existing| existing != path);
+    }
+
+    /// Forget every path.
+    pub fn clear(&mut self) {
+        self.paths.clear();
+    }
+
+    /// Listed paths, newest first.
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Whether no path is listed.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Most paths kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change the most paths kept, dropping the oldest ones past it.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.paths.truncate(capacity);
+    }
+
+    /// Menu entries for the listed paths, newest first
+    ///
+    /// Each entry shows the file name, with the full path as tooltip, and
+    /// calls `open` with its path. An empty list gives a single disabled
+    /// "No Recent Files" entry.
+    pub fn subitems(&self, open: Arc<OpenRecentFn>) -> Vec<SubMenuItem> {
+        if self.paths.is_empty() {
+            return vec![SubMenuItem::new("No Recent Files").disabled()];
+        }
+        self.paths
+            .iter()
+            .map(|path| {
+                let label = path
+                    .file_name()
+                    .map_or_else(|| path.to_string_lossy(), |name| name.to_string_lossy());
+                let open = open.clone();
+                let callback_path = path.clone();
+                SubMenuItem::new(&label)
+                    .with_id(path.to_string_lossy())
+                    .with_tooltip(path.display().to_string())
+                    .with_callback(Box::new(move || open(&callback_path)))
+            })
+            .collect()
+    }
+
+    /// Generator for `MenuItem::with_dynamic_subitems()` listing the paths of a shared list
+    ///
+    /// The entries are rebuilt from `recent` each time the menu opens, so
+    /// paths pushed meanwhile show up without touching the title bar.
+    pub fn generator(recent: Arc<Mutex<Self>>, open: Arc<OpenRecentFn>) -> SubItemsGenerator {
+        Box::new(move || recent.lock().unwrap().subitems(open.clone()))
+    }
+}
@@ -1,5 +1,7 @@
 use egui::{Key, Modifiers};
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Mutex;
 
 // Global state to track shortcut states across frames
@@ -24,16 +26,74 @@ pub struct KeyboardShortcut {
 ///
 /// Returned by [`KeyboardShortcut::from_string`] when the provided string
 /// contains an unknown key, unsupported modifier, or has the wrong format.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ShortcutParseError {
     /// Unknown or unsupported key token, e.g. "foobar".
     InvalidKey(String),
     /// Unknown or unsupported modifier token, e.g. "hyper".
     InvalidModifier(String),
-    /// General formatting issue (e.g. empty string).
+    /// General formatting issue (e.g. empty string or no key after the modifiers).
     InvalidFormat(String),
 }
 
+impl fmt::Display for ShortcutParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidKey(key) => write!(f, "unknown key \"{key}\" in shortcut"),
+            Self::InvalidModifier(modifier) => write!(
+                f,
+                "unknown modifier \"{modifier}\" in shortcut (expected ctrl, alt, shift or cmd)"
+            ),
+            Self::InvalidFormat(shortcut) => {
+                write!(
+                    f,
+                    "invalid shortcut \"{shortcut}\": expected e.g. \"Ctrl+Shift+P\""
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShortcutParseError {}
+
+/// Modifier set by a macOS modifier glyph.
+fn modifier_glyph(c: char) -> Option<fn(&mut Modifiers)> {
+    match c {
+        '⌘' => Some(|m| m.command = true),
+        '⌥' => Some(|m| m.alt = true),
+        '⇧' => Some(|m| m.shift = true),
+        '⌃' => Some(|m| m.ctrl = true),
+        _ => None,
+    }
+}
+
+/// Map a key token to an `egui::Key`, ignoring case.
+fn parse_key(token: &str) -> Option<Key> {
+    let alias = match token.to_lowercase().as_str() {
+        "esc" | "⎋" => Some(Key::Escape),
+        "return" | "⏎" | "↩" => Some(Key::Enter),
+        "del" | "⌦" => Some(Key::Delete),
+        "⌫" => Some(Key::Backspace),
+        "⇥" => Some(Key::Tab),
+        "ins" => Some(Key::Insert),
+        "pgup" => Some(Key::PageUp),
+        "pgdown" | "pgdn" => Some(Key::PageDown),
+        "↑" => Some(Key::ArrowUp),
+        "↓" => Some(Key::ArrowDown),
+        "←" => Some(Key::ArrowLeft),
+        "→" => Some(Key::ArrowRight),
+        _ => None,
+    };
+    alias
+        .or_else(|| {
+            Key::ALL
+                .iter()
+                .copied()
+                .find(|key| key.name().eq_ignore_ascii_case(token))
+        })
+        .or_else(|| Key::from_name(token))
+}
+
 impl KeyboardShortcut {
     /// Create a shortcut with a primary key and no modifiers.
     pub fn new(key: Key) -> Self {
@@ -43,138 +103,97 @@ impl KeyboardShortcut {
         }
     }
 
-    /// Create a shortcut from a simple string like "t", "ctrl+t", "ctrl+shift+t", etc.
+    /// Create a shortcut from a string like "t", "ctrl+t", "Ctrl-Shift-T" or "⌘⇧T"
+    ///
+    /// Tokens can be separated by `+`, `-` or spaces. Modifier names are
+    /// case-insensitive and the macOS glyphs ⌘ ⌥ ⇧ ⌃ are accepted with or
+    /// without a separator. The key is matched case-insensitively against
+    /// `egui::Key` names ("F3", "PageUp", "Minus", "Plus", …), common aliases
+    /// ("esc", "del", "pgup") and single characters ("-", "=", "/"). Strings produced
+    /// by [`KeyboardShortcut::display_string`] parse back to the same shortcut.
+    /// The same parser backs `str::parse::<KeyboardShortcut>()`.
     ///
     /// # Examples
     /// ```
     /// KeyboardShortcut::from_string("t").unwrap()
     /// KeyboardShortcut::from_string("ctrl+t").unwrap()
-    /// KeyboardShortcut::from_string("ctrl+shift+t").unwrap()
-    /// KeyboardShortcut::from_string("alt+f4").unwrap()
+    /// KeyboardShortcut::from_string("Ctrl+Shift+P").unwrap()
+    /// KeyboardShortcut::from_string("alt-f4").unwrap()
+    /// KeyboardShortcut::from_string("⌘⇧P").unwrap()
+    /// let shortcut: KeyboardShortcut = "ctrl+=".parse()?;
     /// ```
     pub fn from_string(shortcut: &str) -> Result<Self, ShortcutParseError> {
-        let parts: Vec<&str> = shortcut.split('+').collect();
+        const SEPARATORS: [char; 3] = ['+', '-', ' '];
 
-        if parts.is_empty() {
+        let mut modifiers = Modifiers::default();
+        let mut rest = shortcut.trim();
+        if rest.is_empty() {
             return Err(ShortcutParseError::InvalidFormat(shortcut.to_string()));
         }
 
-        let mut modifiers = Modifiers::default();
-        let key_str = parts.last().unwrap().to_lowercase();
-
-        // Parse modifiers
-        for part in &parts[..parts.len() - 1] {
-            let modifier = part.to_lowercase();
-            match modifier.as_str() {
-                "ctrl" | "control" => modifiers.ctrl = true,
-                "alt" => modifiers.alt = true,
-                "shift" => modifiers.shift = true,
-                "cmd" | "meta" | "super" => modifiers.command = true,
-                _ => return Err(ShortcutParseError::InvalidModifier(part.to_string())),
+        // Peel modifiers off the front until only the key is left
+        let key_token = loop {
+            let mut chars = rest.chars();
+            let first = chars.next().unwrap();
+            if let Some(apply) = modifier_glyph(first) {
+                apply(&mut modifiers);
+                rest = chars.as_str().trim_start_matches(SEPARATORS);
+                if rest.is_empty() {
+                    return Err(ShortcutParseError::InvalidFormat(shortcut.to_string()));
+                }
+                continue;
             }
-        }
 
-        // Parse key
-        let key = match key_str.as_str() {
-            // Letters
-            "a" => Key::A,
-            "b" => Key::B,
-            "c" => Key::C,
-            "d" => Key::D,
-            "e" => Key::E,
-            "f" => Key::F,
-            "g" => Key::G,
-            "h" => Key::H,
-            "i" => Key::I,
-            "j" => Key::J,
-            "k" => Key::K,
-            "l" => Key::L,
-            "m" => Key::M,
-            "n" => Key::N,
-            "o" => Key::O,
-            "p" => Key::P,
-            "q" => Key::Q,
-            "r" => Key::R,
-            "s" => Key::S,
-            "t" => Key::T,
-            "u" => Key::U,
-            "v" => Key::V,
-            "w" => Key::W,
-            "x" => Key::X,
-            "y" => Key::Y,
-            "z" => Key::Z,
-
-            // Numbers
-            "0" => Key::Num0,
-            "1" => Key::Num1,
-            "2" => Key::Num2,
-            "3" => Key::Num3,
-            "4" => Key::Num4,
-            "5" => Key::Num5,
-            "6" => Key::Num6,
-            "7" => Key::Num7,
-            "8" => Key::Num8,
-            "9" => Key::Num9,
-
-            // Function keys
-            "f1" => Key::F1,
-            "f2" => Key::F2,
-            "f3" => Key::F3,
-            "f4" => Key::F4,
-            "f5" => Key::F5,
-            "f6" => Key::F6,
-            "f7" => Key::F7,
-            "f8" => Key::F8,
-            "f9" => Key::F9,
-            "f10" => Key::F10,
-            "f11" => Key::F11,
-            "f12" => Key::F12,
-
-            // Special keys
-            "enter" | "return" => Key::Enter,
-            "space" => Key::Space,
-            "tab" => Key::Tab,
-            "escape" | "esc" => Key::Escape,
-            "backspace" => Key::Backspace,
-            "delete" | "del" => Key::Delete,
-            "home" => Key::Home,
-            "end" => Key::End,
-            "pageup" | "pgup" => Key::PageUp,
-            "pagedown" | "pgdown" => Key::PageDown,
-            "up" => Key::ArrowUp,
-            "down" => Key::ArrowDown,
-            "left" => Key::ArrowLeft,
-            "right" => Key::ArrowRight,
-
-            // Punctuation
-            "-" | "minus" => Key::Minus,
-            "=" | "plus" => Key::Equals,
-            "[" => Key::OpenBracket,
-            "]" => Key::CloseBracket,
-            ";" => Key::Semicolon,
-            "'" => Key::Quote,
-            "`" => Key::Backtick,
-            "\\" => Key::Backslash,
-            "," => Key::Comma,
-            "." => Key::Period,
-            "/" => Key::Slash,
-
-            _ => return Err(ShortcutParseError::InvalidKey(key_str)),
+            // Skip the first char so a lone separator ("ctrl+-") is the key itself
+            let Some((index, _)) = rest
+                .char_indices()
+                .skip(1)
+                .find(|(_, c)| SEPARATORS.contains(c))
+            else {
+                break rest;
+            };
+            let token = &rest[..index];
+            let after = &rest[index..];
+            let trimmed = after.trim_start_matches(SEPARATORS);
+            rest = if trimmed.is_empty() {
+                // Trailing separators: the last one is the key ("ctrl++")
+                let last = after.trim_end_matches(' ');
+                if last.is_empty() {
+                    return Err(ShortcutParseError::InvalidFormat(shortcut.to_string()));
+                }
+                &last[last.len() - 1..]
+            } else {
+                trimmed
+            };
+
+            match token.to_lowercase().as_str() {
+                "ctrl" | "control" | "ctl" => modifiers.ctrl = true,
+                "alt" | "option" | "opt" => modifiers.alt = true,
+                "shift" => modifiers.shift = true,
+                "cmd" | "command" | "meta" | "super" | "win" => modifiers.command = true,
+                _ => return Err(ShortcutParseError::InvalidModifier(token.to_string())),
+            }
         };
 
+        let key = parse_key(key_token)
+            .ok_or_else(|| ShortcutParseError::InvalidKey(key_token.to_string()))?;
         Ok(Self { key, modifiers })
     }
 
     /// Create a shortcut from a string, panicking on invalid input.
-    /// Use this when you know the shortcut string is valid.
+    ///
+    /// Prefer [`KeyboardShortcut::from_string`] or `str::parse`, which return
+    /// the [`ShortcutParseError`] instead of panicking.
     ///
     /// # Examples
     /// ```
     /// KeyboardShortcut::parse("ctrl+t")
     /// KeyboardShortcut::parse("alt+f4")
     /// ```
+    #[deprecated(note = "panics on invalid input; use `from_string` or `str::parse` instead")]
     pub fn parse(shortcut: &str) -> Self {
-        Self::from_string(shortcut).expect(&format!("Invalid shortcut: {}", shortcut))
+        Self::from_string(shortcut)
+            .unwrap_or_else(|error| panic!("Invalid shortcut {shortcut:?}: {error}"))
     }
 
     /// Check if this shortcut matches the current input
@@ -185,8 +204,10 @@ impl KeyboardShortcut {
     /// Check if this shortcut was just pressed
     pub fn just_pressed(&self, ctx: &egui::Context) -> bool {
         // Create a unique key for this shortcut
+        // Viewports get their own state, so windows don't see each other's presses
         let shortcut_key = format!(
-            "{:?}_{}_{}_{}_{}",
+            "{:?}_{:?}_{}_{}_{}_{}",
+            ctx.viewport_id(),
             self.key,
             self.modifiers.ctrl,
             self.modifiers.alt,
@@ -223,6 +244,18 @@ impl KeyboardShortcut {
         current_frame_pressed && !was_pressed
     }
 
+    /// Remove the press of this shortcut's key from the current frame's input
+    ///
+    /// Widgets and `key_pressed()` checks running later in the frame no
+    /// longer see it. The press is matched with the modifiers held, so a
+    /// Ctrl shortcut fired with Cmd is removed too.
+    pub fn consume(&self, ctx: &egui::Context) {
+        ctx.input_mut(|i| {
+            let modifiers = i.modifiers;
+            i.consume_key(modifiers, self.key)
+        });
+    }
+
     /// Human-readable representation like "Ctrl+Shift+P".
     pub fn display_string(&self) -> String {
         let mut result = String::new();
@@ -244,3 +277,109 @@ impl KeyboardShortcut {
         result
     }
 }
+
+impl FromStr for KeyboardShortcut {
+    type Err = ShortcutParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_string(s)
+    }
+}
+
+/// Shortcuts are stored as their display string, like "Ctrl+Shift+P".
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeyboardShortcut {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.display_string())
+    }
+}
+
+/// Any string accepted by [`KeyboardShortcut::from_string`] can be loaded.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeyboardShortcut {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let shortcut = String::deserialize(deserializer)?;
+        Self::from_string(&shortcut).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(shortcut: &str) -> (Key, Modifiers) {
+        let shortcut = KeyboardShortcut::from_string(shortcut).unwrap();
+        (shortcut.key, shortcut.modifiers)
+    }
+
+    #[test]
+    fn separators_can_be_the_key() {
+        assert_eq!(parsed("ctrl+-"), (Key::Minus, Modifiers::CTRL));
+        assert_eq!(parsed("ctrl++"), (Key::Plus, Modifiers::CTRL));
+        assert_eq!(parsed("ctrl-+"), (Key::Plus, Modifiers::CTRL));
+        assert_eq!(parsed("-"), (Key::Minus, Modifiers::NONE));
+        assert_eq!(parsed("+"), (Key::Plus, Modifiers::NONE));
+    }
+
+    #[test]
+    fn modifier_glyphs_need_no_separator() {
+        assert_eq!(
+            parsed("⌘⇧P"),
+            (Key::P, Modifiers::COMMAND | Modifiers::SHIFT)
+        );
+        assert_eq!(
+            parsed("⌥ ⌃ F4"),
+            (Key::F4, Modifiers::ALT | Modifiers::CTRL)
+        );
+        assert!(KeyboardShortcut::from_string("⌘").is_err());
+    }
+
+    #[test]
+    fn modifiers_and_keys_accept_aliases() {
+        assert_eq!(parsed("Control Shift P"), parsed("ctrl+shift+p"));
+        assert_eq!(parsed("opt-esc"), (Key::Escape, Modifiers::ALT));
+        assert_eq!(parsed("win+pgdn"), (Key::PageDown, Modifiers::COMMAND));
+        assert_eq!(parsed("del"), (Key::Delete, Modifiers::NONE));
+        assert_eq!(parsed("pageup"), (Key::PageUp, Modifiers::NONE));
+    }
+
+    #[test]
+    fn plus_and_equals_are_different_keys() {
+        assert_eq!(parsed("ctrl+plus"), (Key::Plus, Modifiers::CTRL));
+        assert_eq!(parsed("ctrl+="), (Key::Equals, Modifiers::CTRL));
+        assert_eq!(parsed("ctrl+equals"), (Key::Equals, Modifiers::CTRL));
+    }
+
+    #[test]
+    fn invalid_shortcuts_are_reported() {
+        assert_eq!(
+            KeyboardShortcut::from_string("  ").unwrap_err(),
+            ShortcutParseError::InvalidFormat("  ".to_string())
+        );
+        assert_eq!(
+            KeyboardShortcut::from_string("hyper+k").unwrap_err(),
+            ShortcutParseError::InvalidModifier("hyper".to_string())
+        );
+        assert_eq!(
+            KeyboardShortcut::from_string("ctrl+foobar").unwrap_err(),
+            ShortcutParseError::InvalidKey("foobar".to_string())
+        );
+    }
+
+    #[test]
+    fn display_strings_parse_back() {
+        let modifiers = [
+            Modifiers::NONE,
+            Modifiers::CTRL,
+            Modifiers::ALT | Modifiers::SHIFT,
+            Modifiers::CTRL | Modifiers::ALT | Modifiers::SHIFT | Modifiers::COMMAND,
+        ];
+        for &key in Key::ALL {
+            for modifiers in modifiers {
+                let shortcut = KeyboardShortcut { key, modifiers };
+                let display = shortcut.display_string();
+                assert_eq!(parsed(&display), (key, modifiers), "{display}");
+            }
+        }
+    }
+}
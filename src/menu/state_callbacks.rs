@@ -0,0 +1,83 @@
+use std::sync::{Arc, Mutex};
+
+use crate::menu::items::QueueStateCallbackFn;
+
+/// Callback given mutable access to the app state, as queued on `StateCallbacks`.
+type StateCallbackFn<S> = dyn Fn(&mut S) + Send + Sync;
+
+/// Typed queue of the state callbacks of activated menu items
+///
+/// Items attach a callback with `SubMenuItem::with_state_callback()`, which
+/// takes this handle, and `TitleBar::show_with()` runs the queued callbacks
+/// with the state it is given, so the callbacks and the state must both be
+/// an `S` before the code compiles. Clones share the same queue.
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_desktop::{MenuItem, StateCallbacks, SubMenuItem, TitleBar, TitleBarOptions};
+///
+/// struct Document {
+///     text: String,
+/// }
+///
+/// let callbacks = StateCallbacks::new();
+/// let title_bar = TitleBar::new(TitleBarOptions::new()).add_menu_with_submenu(
+///     MenuItem::new("Edit").add_subitem(SubMenuItem::new("Clear").with_state_callback(
+///         &callbacks,
+///         Box::new(|document: &mut Document| document.text.clear()),
+///     )),
+/// );
+///
+/// // In update(), with the bar, the handle and the state in separate fields
+/// // self.title_bar.show_with(ctx, &self.callbacks, &mut self.document);
+/// # let _ = title_bar;
+/// ```
+pub struct StateCallbacks<S> {
+    queue: Arc<Mutex<Vec<Arc<StateCallbackFn<S>>>>>,
+}
+
+impl<S> Clone for StateCallbacks<S> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+        }
+    }
+}
+
+impl<S> Default for StateCallbacks<S> {
+    fn default() -> Self {
+        Self {
+            queue: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+impl<S: 'static> StateCallbacks<S> {
+    /// Create an empty queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run the queued callbacks with `state`, oldest first, and empty the queue
+    ///
+    /// `TitleBar::show_with()` calls this after drawing the bar.
+    ///
+    /// # Arguments
+    /// * `state` - App state given to the callbacks
+    pub fn run(&self, state: &mut S) {
+        let queued = std::mem::take(&mut *self.queue.lock().unwrap());
+        for callback in queued {
+            callback(state);
+        }
+    }
+
+    /// Callback queuing `callback` to run on the next `run()`.
+    pub(crate) fn queue_of(&self, callback: Box<StateCallbackFn<S>>) -> Arc<QueueStateCallbackFn> {
+        let queue = self.queue.clone();
+        let callback: Arc<StateCallbackFn<S>> = Arc::from(callback);
+        Arc::new(move || {
+            queue.lock().unwrap().push(callback.clone());
+        })
+    }
+}
@@ -0,0 +1,151 @@
+use egui::{
+    Align2, Area, Context, CornerRadius, CursorIcon, FontId, Order, Pos2, Rect, Sense, Stroke,
+    StrokeKind, Vec2,
+};
+
+use crate::TitleBar;
+use crate::menu::items::{SubMenuItem, resolve_label};
+
+impl TitleBar {
+    /// Whether the menu at `index` is torn off into a floating palette.
+    pub fn is_menu_torn_off(&self, index: usize) -> bool {
+        self.torn_off_menus.contains_key(&index)
+    }
+
+    /// Close the floating palette of the menu at `index`, if it is torn off.
+    pub fn close_torn_off_menu(&mut self, index: usize) {
+        self.torn_off_menus.remove(&index);
+    }
+
+    /// Height of the strip above a torn-off menu, in points.
+    fn torn_off_strip_height(&self) -> f32 {
+        self.scaled(18.0)
+    }
+
+    /// Where the pointer holds a palette being torn off, from its top-left corner.
+    pub(crate) fn torn_off_grab_offset(&self) -> Vec2 {
+        Vec2::new(self.scaled(24.0), self.torn_off_strip_height() / 2.0)
+    }
+
+    /// Draw the torn-off menus as floating palettes
+    ///
+    /// Each palette is the menu under a strip that moves it around and holds
+    /// its close button. Palettes stay open when an item is chosen and never
+    /// close on clicks elsewhere; hidden menus are skipped until shown again.
+    pub(crate) fn render_torn_off_menus(&mut self, ctx: &Context) {
+        // Forget palettes of menus that were removed
+        let menu_count = self.menu_items_with_submenus.len();
+        self.torn_off_menus.retain(|&index, _| index < menu_count);
+
+        let strip_height = self.torn_off_strip_height();
+        let padding = self.scaled(8.0);
+        let font = FontId::proportional(self.menu_text_size * self.ui_scale * 0.85);
+        let (background, border, text_color, hover_color) = (
+            self.submenu_background_color,
+            self.submenu_border_color,
+            self.submenu_text_color,
+            self.submenu_hover_color,
+        );
+
+        let mut moves = Vec::new();
+        let mut closed = Vec::new();
+        let mut activated = Vec::new();
+        for (&index, &position) in &self.torn_off_menus {
+            let menu = &self.menu_items_with_submenus[index];
+            if !menu.visible {
+                continue;
+            }
+            let id = menu.widget_id(self.id, index).with("torn_off");
+            let popup = self.popup_menu(menu, id).with_open_upward(false);
+            let text_widths = &mut self.text_widths;
+
+            Area::new(id.with("palette"))
+                .fixed_pos(Pos2::ZERO)
+                .default_size(Vec2::ZERO)
+                .order(Order::Middle)
+                .show(ctx, |ui| {
+                    let response =
+                        popup.show_in(ui, position + Vec2::new(0.0, strip_height), text_widths);
+                    if let Some(path) = response.activated {
+                        activated.push((index, path));
+                    }
+
+                    // The strip sits on top of the menu, wherever it was kept on screen
+                    let menu_rect = response.rects[0];
+                    let strip = Rect::from_min_size(
+                        menu_rect.min - Vec2::new(0.0, strip_height),
+                        Vec2::new(menu_rect.width(), strip_height),
+                    );
+                    let close_rect = Rect::from_min_size(
+                        Pos2::new(strip.max.x - strip_height, strip.min.y),
+                        Vec2::splat(strip_height),
+                    );
+                    let drag = ui.interact(strip, id.with("strip"), Sense::click_and_drag());
+                    let close = ui.interact(close_rect, id.with("close"), Sense::click());
+
+                    let radius = CornerRadius {
+                        nw: 4,
+                        ne: 4,
+                        sw: 0,
+                        se: 0,
+                    };
+                    ui.painter().rect_filled(strip, radius, background);
+                    ui.painter().rect_stroke(
+                        strip,
+                        radius,
+                        Stroke::new(1.0, border),
+                        StrokeKind::Outside,
+                    );
+                    ui.painter().text(
+                        Pos2::new(strip.min.x + padding, strip.center().y),
+                        Align2::LEFT_CENTER,
+                        resolve_label(self.label_resolver.as_deref(), &menu.label),
+                        font.clone(),
+                        text_color.gamma_multiply(0.7),
+                    );
+
+                    if close.hovered() {
+                        ui.painter().rect_filled(
+                            close_rect.shrink(2.0),
+                            CornerRadius::same(2),
+                            hover_color,
+                        );
+                    }
+                    let cross = close_rect.shrink(strip_height * 0.35);
+                    let stroke = Stroke::new(1.2, text_color);
+                    ui.painter()
+                        .line_segment([cross.left_top(), cross.right_bottom()], stroke);
+                    ui.painter()
+                        .line_segment([cross.right_top(), cross.left_bottom()], stroke);
+
+                    if drag.dragged() {
+                        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+                        moves.push((index, drag.drag_delta()));
+                    } else if drag.hovered() {
+                        ui.ctx().set_cursor_icon(CursorIcon::Grab);
+                    }
+                    if close.clicked() {
+                        closed.push(index);
+                    }
+                });
+        }
+
+        for (index, delta) in moves {
+            if let Some(position) = self.torn_off_menus.get_mut(&index) {
+                *position += delta;
+            }
+        }
+        for index in closed {
+            self.torn_off_menus.remove(&index);
+        }
+        // Callbacks already ran; chosen items stay in the open palette
+        for (index, path) in activated {
+            let menu = &self.menu_items_with_submenus[index];
+            if let Some(item) = SubMenuItem::at_path(&menu.subitems, &path) {
+                self.send_menu_message(item);
+                self.send_menu_action(menu, &path);
+                self.queue_state_callback(item);
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use egui::{Color32, FontFamily, FontId, Ui};
+use std::collections::HashMap;
+
+/// Upper bound on cached labels before the cache starts over.
+///
+/// Keeps apps that generate labels on the fly (counters, file names) from
+/// growing the cache without limit.
+const MAX_ENTRIES: usize = 1024;
+
+/// Measured widths of menu labels, keyed by font and text.
+///
+/// Menus are laid out every frame; measuring a label means building a galley,
+/// so the widths are kept until the menu text size or the display scale
+/// changes.
+#[derive(Default)]
+pub(crate) struct TextWidthCache {
+    /// Widths per font (size bits and family) and label.
+    widths: HashMap<(u32, FontFamily), HashMap<String, f32>>,
+    /// `pixels_per_point` the widths were measured at.
+    pixels_per_point: f32,
+    /// Number of cached labels across all sizes.
+    len: usize,
+}
+
+impl TextWidthCache {
+    /// Width of `text` in points when drawn with `font`, measuring it on a miss.
+    pub(crate) fn width(&mut self, ui: &Ui, text: &str, font: &FontId) -> f32 {
+        let pixels_per_point = ui.ctx().pixels_per_point();
+        if pixels_per_point != self.pixels_per_point || self.len >= MAX_ENTRIES {
+            self.clear();
+            self.pixels_per_point = pixels_per_point;
+        }
+
+        let key = (font.size.to_bits(), font.family.clone());
+        if let Some(width) = self.widths.get(&key).and_then(|labels| labels.get(text)) {
+            return *width;
+        }
+
+        let width = ui.fonts_mut(|f| {
+            f.layout_no_wrap(text.to_owned(), font.clone(), Color32::PLACEHOLDER)
+                .size()
+                .x
+        });
+        self.widths
+            .entry(key)
+            .or_default()
+            .insert(text.to_owned(), width);
+        self.len += 1;
+        width
+    }
+
+    /// Forget every measured width.
+    pub(crate) fn clear(&mut self) {
+        self.widths.clear();
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{CentralPanel, Context, RawInput};
+
+    /// Run `check` with a `Ui` of a fresh frame.
+    fn with_ui(check: impl FnMut(&mut Ui)) {
+        let ctx = Context::default();
+        let mut check = Some(check);
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                if let Some(mut check) = check.take() {
+                    check(ui);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn repeated_lookups_are_not_laid_out_again() {
+        with_ui(|ui| {
+            let mut cache = TextWidthCache::default();
+            let font = FontId::proportional(14.0);
+
+            let width = cache.width(ui, "File", &font);
+            assert!(width > 0.0);
+            assert_eq!(cache.len, 1);
+            assert_eq!(cache.width(ui, "File", &font), width);
+            assert_eq!(cache.len, 1);
+
+            // Another size or family is measured anew
+            let larger = cache.width(ui, "File", &FontId::proportional(20.0));
+            assert!(larger > width);
+            assert_eq!(cache.len, 2);
+            cache.width(ui, "File", &FontId::monospace(14.0));
+            assert_eq!(cache.len, 3);
+            cache.width(ui, "Edit", &font);
+            assert_eq!(cache.len, 4);
+        });
+    }
+
+    #[test]
+    fn display_scale_changes_start_over() {
+        with_ui(|ui| {
+            let mut cache = TextWidthCache::default();
+            let font = FontId::proportional(14.0);
+            cache.width(ui, "File", &font);
+            cache.width(ui, "Edit", &font);
+            assert_eq!(cache.len, 2);
+
+            cache.pixels_per_point = 2.0 * ui.ctx().pixels_per_point();
+            cache.width(ui, "File", &font);
+            assert_eq!(cache.len, 1);
+        });
+    }
+}
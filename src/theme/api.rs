@@ -1,7 +1,9 @@
 use egui::{Color32, Context};
 
-use crate::theme::{detect_system_dark_mode, ThemeError, ThemeMode, ThemeProvider, TitleBarTheme};
 use crate::TitleBar;
+use crate::theme::{
+    ColorScheme, ThemeError, ThemeMode, ThemeProvider, TitleBarTheme, detect_system_dark_mode,
+};
 
 impl TitleBar {
     /// Attach a ThemeProvider to this TitleBar
@@ -193,6 +195,155 @@ impl TitleBar {
         self
     }
 
+    /// Apply a complete color scheme in one call
+    ///
+    /// Sets every title bar and menu color from the scheme. Individual `with_*_color`
+    /// builders chained afterwards still override single colors.
+    ///
+    /// # Arguments
+    /// * `scheme` - The colors to apply
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::Color32;
+    /// # use egui_desktop::ColorScheme;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar
+    ///     .with_color_scheme(ColorScheme::dark())
+    ///     .with_close_hover_color(Color32::from_rgb(200, 40, 40))
+    /// # ;
+    /// ```
+    pub fn with_color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.apply_color_scheme(scheme);
+        self
+    }
+
+    /// Apply a complete color scheme to an existing title bar
+    ///
+    /// Same as [`with_color_scheme`](Self::with_color_scheme), for use at runtime.
+    pub fn apply_color_scheme(&mut self, scheme: ColorScheme) {
+        self.background_color = scheme.background_color;
+        self.hover_color = scheme.hover_color;
+        self.close_hover_color = scheme.close_hover_color;
+        self.close_icon_color = scheme.close_icon_color;
+        self.maximize_icon_color = scheme.maximize_icon_color;
+        self.restore_icon_color = scheme.restore_icon_color;
+        self.minimize_icon_color = scheme.minimize_icon_color;
+        self.title_color = scheme.title_color;
+        self.menu_text_color = scheme.menu_text_color;
+        self.menu_hover_color = scheme.menu_hover_color;
+        self.keyboard_selection_color = scheme.keyboard_selection_color;
+        self.menu_badge_color = scheme.menu_badge_color;
+        self.menu_badge_text_color = scheme.menu_badge_text_color;
+        // Submenu colors
+        self.submenu_background_color = scheme.submenu_background_color;
+        self.submenu_text_color = scheme.submenu_text_color;
+        self.submenu_hover_color = scheme.submenu_hover_color;
+        self.submenu_disabled_color = scheme.submenu_disabled_color;
+        self.submenu_shortcut_color = scheme.submenu_shortcut_color;
+        self.submenu_border_color = scheme.submenu_border_color;
+        self.submenu_keyboard_selection_color = scheme.submenu_keyboard_selection_color;
+    }
+
+    /// Get the colors currently used by the title bar as a `ColorScheme`
+    pub fn color_scheme(&self) -> ColorScheme {
+        ColorScheme {
+            background_color: self.background_color,
+            hover_color: self.hover_color,
+            close_hover_color: self.close_hover_color,
+            close_icon_color: self.close_icon_color,
+            maximize_icon_color: self.maximize_icon_color,
+            restore_icon_color: self.restore_icon_color,
+            minimize_icon_color: self.minimize_icon_color,
+            title_color: self.title_color,
+            menu_text_color: self.menu_text_color,
+            menu_hover_color: self.menu_hover_color,
+            keyboard_selection_color: self.keyboard_selection_color,
+            menu_badge_color: self.menu_badge_color,
+            menu_badge_text_color: self.menu_badge_text_color,
+            submenu_background_color: self.submenu_background_color,
+            submenu_text_color: self.submenu_text_color,
+            submenu_hover_color: self.submenu_hover_color,
+            submenu_disabled_color: self.submenu_disabled_color,
+            submenu_shortcut_color: self.submenu_shortcut_color,
+            submenu_border_color: self.submenu_border_color,
+            submenu_keyboard_selection_color: self.submenu_keyboard_selection_color,
+        }
+    }
+
+    /// Follow the system light/dark theme automatically
+    ///
+    /// When enabled, `show()` reads `ctx.style().visuals.dark_mode` every frame
+    /// (egui tracks the OS preference by default) and switches between the light
+    /// and dark color schemes. Colors are only reassigned when the mode actually
+    /// changes, so this is cheap. Any manual color overrides are replaced on the
+    /// next switch; customize the schemes with `with_light_color_scheme` and
+    /// `with_dark_color_scheme` instead.
+    ///
+    /// On Windows the same signal also drives the DWM immersive dark mode attribute
+    /// (`DWMWA_USE_IMMERSIVE_DARK_MODE`), which tints the native border and shadow;
+    /// see `apply_dark_titlebar_to_viewport`.
+    ///
+    /// # Arguments
+    /// * `follow` - Whether to follow the system theme
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_follow_system_theme(true)
+    /// # ;
+    /// ```
+    pub fn with_follow_system_theme(mut self, follow: bool) -> Self {
+        self.follow_system_theme = follow;
+        self.applied_dark_mode = None;
+        self
+    }
+
+    /// Set the colors used in light mode when following the system theme
+    ///
+    /// # Arguments
+    /// * `scheme` - The light mode colors
+    pub fn with_light_color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.light_color_scheme = scheme;
+        self.applied_dark_mode = None;
+        self
+    }
+
+    /// Set the colors used in dark mode when following the system theme
+    ///
+    /// # Arguments
+    /// * `scheme` - The dark mode colors
+    pub fn with_dark_color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.dark_color_scheme = scheme;
+        self.applied_dark_mode = None;
+        self
+    }
+
+    /// Swap color schemes if following the system theme and the mode changed.
+    pub(crate) fn update_followed_theme(&mut self, ctx: &Context) {
+        if !self.follow_system_theme {
+            return;
+        }
+
+        let dark_mode = ctx.style().visuals.dark_mode;
+        if self.applied_dark_mode == Some(dark_mode) {
+            return;
+        }
+
+        let scheme = if dark_mode {
+            self.dark_color_scheme
+        } else {
+            self.light_color_scheme
+        };
+        self.apply_color_scheme(scheme);
+        crate::utils::apply_dark_titlebar_to_viewport(ctx, dark_mode);
+        self.applied_dark_mode = Some(dark_mode);
+    }
+
     /// Create a custom light theme with specific overrides
     ///
     /// This is a convenience method that creates a custom light theme with only
@@ -349,6 +500,8 @@ impl TitleBar {
         self.menu_text_size = theme.menu_text_size;
         self.menu_hover_color = theme.menu_hover_color;
         self.keyboard_selection_color = theme.keyboard_selection_color;
+        self.menu_badge_color = theme.menu_badge_color;
+        self.menu_badge_text_color = theme.menu_badge_text_color;
         // Submenu colors
         self.submenu_background_color = theme.submenu_background_color;
         self.submenu_text_color = theme.submenu_text_color;
@@ -373,6 +526,8 @@ impl TitleBar {
         self.menu_text_size = theme.menu_text_size;
         self.menu_hover_color = theme.menu_hover_color;
         self.keyboard_selection_color = theme.keyboard_selection_color;
+        self.menu_badge_color = theme.menu_badge_color;
+        self.menu_badge_text_color = theme.menu_badge_text_color;
         // Submenu colors
         self.submenu_background_color = theme.submenu_background_color;
         self.submenu_text_color = theme.submenu_text_color;
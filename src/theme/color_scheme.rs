@@ -0,0 +1,112 @@
+use egui::Color32;
+
+use crate::theme::TitleBarTheme;
+
+/// Every color used by the title bar and its menus, bundled together.
+///
+/// Apply one with [`TitleBar::with_color_scheme`](crate::TitleBar::with_color_scheme)
+/// instead of calling each `with_*_color` builder; the individual builders can
+/// still be chained afterwards to override single colors.
+///
+/// # Examples
+///
+/// ```rust
+/// # use egui_desktop::TitleBar;
+/// # use egui::Color32;
+/// # use egui_desktop::ColorScheme;
+/// # let title_bar = TitleBar::default();
+/// let scheme = ColorScheme {
+///     background_color: Color32::from_rgb(20, 24, 32),
+///     ..ColorScheme::dark()
+/// };
+/// title_bar.with_color_scheme(scheme)
+/// # ;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorScheme {
+    /// Window/title bar background color.
+    pub background_color: Color32,
+    /// Hover background color for window controls.
+    pub hover_color: Color32,
+    /// Hover color for the close button.
+    pub close_hover_color: Color32,
+    /// Close icon color.
+    pub close_icon_color: Color32,
+    /// Maximize icon color.
+    pub maximize_icon_color: Color32,
+    /// Restore icon color.
+    pub restore_icon_color: Color32,
+    /// Minimize icon color.
+    pub minimize_icon_color: Color32,
+    /// Title text color.
+    pub title_color: Color32,
+    /// Menu text color.
+    pub menu_text_color: Color32,
+    /// Menu hover background color.
+    pub menu_hover_color: Color32,
+    /// Keyboard selection highlight color for menus.
+    pub keyboard_selection_color: Color32,
+    /// Background color of menu count badges.
+    pub menu_badge_color: Color32,
+    /// Text color of menu count badges.
+    pub menu_badge_text_color: Color32,
+    /// Submenu background color.
+    pub submenu_background_color: Color32,
+    /// Submenu text color.
+    pub submenu_text_color: Color32,
+    /// Submenu hover background color.
+    pub submenu_hover_color: Color32,
+    /// Color for disabled submenu items.
+    pub submenu_disabled_color: Color32,
+    /// Color for keyboard shortcuts in submenus.
+    pub submenu_shortcut_color: Color32,
+    /// Submenu border color.
+    pub submenu_border_color: Color32,
+    /// Keyboard selection highlight color for submenus.
+    pub submenu_keyboard_selection_color: Color32,
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl ColorScheme {
+    /// Colors of the built-in light theme.
+    pub fn light() -> Self {
+        Self::from(&TitleBarTheme::light())
+    }
+
+    /// Colors of the built-in dark theme.
+    pub fn dark() -> Self {
+        Self::from(&TitleBarTheme::dark())
+    }
+}
+
+impl From<&TitleBarTheme> for ColorScheme {
+    fn from(theme: &TitleBarTheme) -> Self {
+        Self {
+            background_color: theme.background_color,
+            hover_color: theme.hover_color,
+            close_hover_color: theme.close_hover_color,
+            close_icon_color: theme.close_icon_color,
+            maximize_icon_color: theme.maximize_icon_color,
+            restore_icon_color: theme.restore_icon_color,
+            minimize_icon_color: theme.minimize_icon_color,
+            title_color: theme.title_color,
+            menu_text_color: theme.menu_text_color,
+            menu_hover_color: theme.menu_hover_color,
+            keyboard_selection_color: theme.keyboard_selection_color,
+            menu_badge_color: theme.menu_badge_color,
+            menu_badge_text_color: theme.menu_badge_text_color,
+            submenu_background_color: theme.submenu_background_color,
+            submenu_text_color: theme.submenu_text_color,
+            submenu_hover_color: theme.submenu_hover_color,
+            submenu_disabled_color: theme.submenu_disabled_color,
+            submenu_shortcut_color: theme.submenu_shortcut_color,
+            submenu_border_color: theme.submenu_border_color,
+            submenu_keyboard_selection_color: theme.submenu_keyboard_selection_color,
+        }
+    }
+}
@@ -2,9 +2,14 @@ use egui::{Color32, Visuals};
 
 /// Public API helpers for working with themes.
 pub mod api;
+/// Bundled title bar and menu colors with light/dark presets.
+pub mod color_scheme;
+
+pub use color_scheme::ColorScheme;
 
 /// Theme mode selection for the title bar and related UI.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ThemeMode {
     /// Light appearance.
     Light,
@@ -40,6 +45,10 @@ pub struct TitleBarTheme {
     pub menu_hover_color: Color32,
     /// Highlight color used for keyboard selection in menus.
     pub keyboard_selection_color: Color32,
+    /// Background color of menu count badges.
+    pub menu_badge_color: Color32,
+    /// Text color of menu count badges.
+    pub menu_badge_text_color: Color32,
     // Submenu customization
     /// Submenu background color.
     pub submenu_background_color: Color32,
@@ -98,6 +107,8 @@ impl TitleBarTheme {
             menu_text_size: 12.0,
             menu_hover_color: Color32::from_rgb(230, 230, 230),
             keyboard_selection_color: Color32::from_rgb(0, 120, 215),
+            menu_badge_color: Color32::from_rgb(232, 17, 35),
+            menu_badge_text_color: Color32::WHITE,
             submenu_background_color: Color32::WHITE,
             submenu_text_color: Color32::from_rgb(50, 50, 50),
             submenu_text_size: 11.0,
@@ -124,6 +135,8 @@ impl TitleBarTheme {
             menu_text_size: 12.0,
             menu_hover_color: Color32::from_rgb(60, 60, 60),
             keyboard_selection_color: Color32::from_rgb(30, 144, 255),
+            menu_badge_color: Color32::from_rgb(255, 99, 97),
+            menu_badge_text_color: Color32::from_rgb(30, 30, 30),
             submenu_background_color: Color32::from_rgb(40, 40, 40),
             submenu_text_color: Color32::from_rgb(200, 200, 200),
             submenu_text_size: 11.0,
@@ -170,6 +183,8 @@ impl TitleBarTheme {
             menu_hover_color: menu_hover_color.unwrap_or(default.menu_hover_color),
             keyboard_selection_color: keyboard_selection_color
                 .unwrap_or(default.keyboard_selection_color),
+            menu_badge_color: default.menu_badge_color,
+            menu_badge_text_color: default.menu_badge_text_color,
             submenu_background_color: submenu_background_color
                 .unwrap_or(default.submenu_background_color),
             submenu_text_color: submenu_text_color.unwrap_or(default.submenu_text_color),
@@ -219,6 +234,8 @@ impl TitleBarTheme {
             menu_hover_color: menu_hover_color.unwrap_or(default.menu_hover_color),
             keyboard_selection_color: keyboard_selection_color
                 .unwrap_or(default.keyboard_selection_color),
+            menu_badge_color: default.menu_badge_color,
+            menu_badge_text_color: default.menu_badge_text_color,
             submenu_background_color: submenu_background_color
                 .unwrap_or(default.submenu_background_color),
             submenu_text_color: submenu_text_color.unwrap_or(default.submenu_text_color),
@@ -1,12 +1,16 @@
 use std::borrow::Cow;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
-use egui::load::Bytes;
+use egui::load::{Bytes, SizeHint, TexturePoll};
 use egui::{
-    Color32, CornerRadius, CursorIcon, Id, Image, ImageSource, Pos2, Rect, Sense, Ui, Vec2,
+    Color32, Context, CornerRadius, CursorIcon, Image, ImageSource, Pos2, Rect, Sense, Stroke,
+    TextureOptions, Ui, Vec2,
 };
 
 use crate::titlebar::CustomIconButton;
-use crate::{CustomIcon, TitleBar, TitleBarOptions};
+use crate::titlebar::control_buttons::{CloseRequestFn, ControlButtonLayout, MinimizeFn};
+use crate::{CustomIcon, ThemeMode, TitleBar, TitleBarEdge, TitleBarOptions, TitleBarStyle};
 
 impl TitleBar {
     /// Convenience constructor for a title bar with a title
@@ -66,6 +70,34 @@ impl TitleBar {
         }
     }
 
+    /// Hide the title while the window is narrower than `width` points
+    ///
+    /// By default (`0.0`) the title is only hidden by the platform settings;
+    /// at intermediate widths it is cut with "…" to keep clear of the menus
+    /// and controls. Both the macOS and the Windows/Linux bar apply this.
+    ///
+    /// # Arguments
+    /// * `width` - Window width in points below which the title is hidden
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// // Keep the title until the window is 400 points wide
+    /// title_bar.with_title_hide_below_width(400.0)
+    /// # ;
+    /// ```
+    pub fn with_title_hide_below_width(mut self, width: f32) -> Self {
+        self.title_hide_below_width = width;
+        self
+    }
+
+    /// Whether the title is drawn in a bar `bar_width` points wide.
+    pub(crate) fn title_visible_at(&self, bar_width: f32) -> bool {
+        self.should_show_title() && bar_width >= self.title_hide_below_width
+    }
+
     /// Set the background color of the title bar
     ///
     /// # Arguments
@@ -81,6 +113,28 @@ impl TitleBar {
         self
     }
 
+    /// Set the background color of the title bar while the window is unfocused
+    ///
+    /// Native title bars dim when another window is active. Without this the
+    /// bar keeps its regular background color.
+    ///
+    /// # Arguments
+    /// * `color` - The unfocused background color as a Color32
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::Color32;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_unfocused_background_color(Color32::from_rgb(250, 250, 250))
+    /// # ;
+    /// ```
+    pub fn with_unfocused_background_color(mut self, color: Color32) -> Self {
+        self.unfocused_background_color = Some(color);
+        self
+    }
+
     /// Set the hover color for control buttons
     ///
     /// This color is used when hovering over maximize, restore, and minimize buttons.
@@ -149,6 +203,25 @@ impl TitleBar {
         self
     }
 
+    /// Set the color of the title text while the window is unfocused
+    ///
+    /// # Arguments
+    /// * `color` - The unfocused title text color as a Color32
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::Color32;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_unfocused_title_color(Color32::from_rgb(150, 150, 150))
+    /// # ;
+    /// ```
+    pub fn with_unfocused_title_color(mut self, color: Color32) -> Self {
+        self.unfocused_title_color = Some(color);
+        self
+    }
+
     /// Set the font size of the title text
     ///
     /// # Arguments
@@ -164,6 +237,348 @@ impl TitleBar {
         self
     }
 
+    /// Scale the bar and its menus independently of the system DPI
+    ///
+    /// Every height, padding, icon and font size used by the title bar and its
+    /// menus is multiplied by this factor. Values below `1.0` give a denser bar,
+    /// values above a looser one. HiDPI displays need no adjustment: sizes are in
+    /// points and egui already applies `pixels_per_point`.
+    ///
+    /// # Arguments
+    /// * `scale` - The multiplier, `1.0` being the default density
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_ui_scale(0.9)
+    /// # ;
+    /// ```
+    pub fn with_ui_scale(mut self, scale: f32) -> Self {
+        self.ui_scale = scale.max(0.1);
+        self
+    }
+
+    /// Draw the bar the macOS or the Windows/Linux way on every platform
+    ///
+    /// By default (`TitleBarStyle::Auto`) macOS gets the traffic lights and
+    /// other platforms the control buttons on the right. Forcing a style keeps
+    /// the branding consistent across platforms or makes screenshots
+    /// reproducible. Only the visuals change: the buttons send the same
+    /// viewport commands either way.
+    ///
+    /// # Arguments
+    /// * `style` - The look of the bar
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::TitleBarStyle;
+    /// # let title_bar = TitleBar::default();
+    /// // Traffic lights on Windows and Linux too
+    /// title_bar.with_style(TitleBarStyle::MacOs)
+    /// # ;
+    /// ```
+    pub fn with_style(mut self, style: TitleBarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Draw a denser bar for information-dense tools
+    ///
+    /// Shrinks the bar height, the padding around menu labels, the rows of
+    /// submenus and the window control buttons by the same factor. Text
+    /// sizes are left alone; use `with_ui_scale` to shrink everything.
+    /// A height set with `with_title_bar_height` is kept as is.
+    ///
+    /// # Arguments
+    /// * `compact` - Whether the bar is drawn compact (default `false`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_compact(true)
+    /// # ;
+    /// ```
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Set the height of the bar instead of the platform default
+    ///
+    /// The height is in points and scaled by `ui_scale`. It wins over the
+    /// height chosen by `with_compact`, which still tightens the rest.
+    ///
+    /// # Arguments
+    /// * `height` - Height of the bar in points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_title_bar_height(40.0)
+    /// # ;
+    /// ```
+    pub fn with_title_bar_height(mut self, height: f32) -> Self {
+        self.bar_height = Some(height.max(1.0));
+        self
+    }
+
+    /// Choose where the window control buttons are drawn
+    ///
+    /// Some Linux themes put the controls on the left or in the middle of
+    /// the bar. Menus, tabs and the title make room for the buttons; a
+    /// centered macOS title moves next to centered buttons.
+    ///
+    /// # Arguments
+    /// * `layout` - Side of the bar, or `Platform` for the native placement
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::ControlButtonLayout;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_control_button_layout(ControlButtonLayout::Center)
+    /// # ;
+    /// ```
+    pub fn with_control_button_layout(mut self, layout: ControlButtonLayout) -> Self {
+        self.control_button_layout = layout;
+        self
+    }
+
+    /// Replace what the minimize button does
+    ///
+    /// By default the button minimizes the window to the taskbar or dock.
+    /// With a callback set, the button runs it instead, e.g. to hide the
+    /// window and show a tray icon.
+    ///
+    /// # Arguments
+    /// * `callback` - Called with the viewport's context when minimize is clicked
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::ViewportCommand;
+    /// # let title_bar = TitleBar::default();
+    /// # fn show_tray_icon() {}
+    /// title_bar.with_minimize_callback(Box::new(|ctx| {
+    ///     ctx.send_viewport_cmd(ViewportCommand::Visible(false));
+    ///     show_tray_icon();
+    /// }))
+    /// # ;
+    /// ```
+    pub fn with_minimize_callback(mut self, callback: Box<MinimizeFn>) -> Self {
+        self.on_minimize = Some(callback);
+        self
+    }
+
+    /// Ask before the close button closes the window
+    ///
+    /// The hook runs when the close button or the red traffic light is
+    /// clicked; the window only closes if it returns `true`. Use
+    /// `confirm_close()` to apply the same hook to closes coming from the
+    /// window manager.
+    ///
+    /// # Arguments
+    /// * `hook` - Returns whether the window may close
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// # use std::sync::{Arc, Mutex};
+    /// # struct Document;
+    /// # impl Document { fn is_dirty(&self) -> bool { false } }
+    /// # struct App { document: Arc<Mutex<Document>> }
+    /// # impl App {
+    /// # fn title_bar(&self, title_bar: TitleBar) -> TitleBar {
+    /// let document = self.document.clone();
+    /// title_bar.with_on_close_requested(Box::new(move || !document.lock().unwrap().is_dirty()))
+    /// # }
+    /// # }
+    /// ```
+    pub fn with_on_close_requested(mut self, hook: Box<CloseRequestFn>) -> Self {
+        self.on_close_requested = Some(hook);
+        self
+    }
+
+    /// Whether a close request may go ahead
+    ///
+    /// Runs the hook set with `with_on_close_requested()`, or returns `true`
+    /// without one. A close sent by the close button was already confirmed,
+    /// so the hook is not asked twice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use egui::{Context, ViewportCommand};
+    /// # use egui_desktop::TitleBar;
+    /// # fn update(ctx: &Context, title_bar: &mut TitleBar) {
+    /// if ctx.input(|i| i.viewport().close_requested()) && !title_bar.confirm_close() {
+    ///     ctx.send_viewport_cmd(ViewportCommand::CancelClose);
+    /// }
+    /// # }
+    /// ```
+    pub fn confirm_close(&mut self) -> bool {
+        if std::mem::take(&mut self.close_confirmed) {
+            return true;
+        }
+        self.on_close_requested.as_ref().is_none_or(|hook| hook())
+    }
+
+    /// Dock the bar to the top or bottom edge of the window
+    ///
+    /// A bottom bar opens its submenus upward, for tool-strip style apps.
+    ///
+    /// # Arguments
+    /// * `edge` - The window edge to draw the bar along
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::TitleBarEdge;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_title_bar_position(TitleBarEdge::Bottom)
+    /// # ;
+    /// ```
+    pub fn with_title_bar_position(mut self, edge: TitleBarEdge) -> Self {
+        self.position = edge;
+        self
+    }
+
+    /// Get the title text, if any.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Get the bar background color.
+    pub fn background_color(&self) -> Color32 {
+        self.background_color
+    }
+
+    /// Get the hover color for window controls.
+    pub fn hover_color(&self) -> Color32 {
+        self.hover_color
+    }
+
+    /// Get the hover color for the close button.
+    pub fn close_hover_color(&self) -> Color32 {
+        self.close_hover_color
+    }
+
+    /// Get the title text color.
+    pub fn title_color(&self) -> Color32 {
+        self.title_color
+    }
+
+    /// Whether the window has focus; assumed focused when the backend doesn't say.
+    pub(crate) fn is_window_focused(ctx: &Context) -> bool {
+        ctx.input(|i| i.viewport().focused.unwrap_or(true))
+    }
+
+    /// Bar background color for the current window focus.
+    pub(crate) fn current_background_color(&self, ctx: &Context) -> Color32 {
+        match self.unfocused_background_color {
+            Some(color) if !Self::is_window_focused(ctx) => color,
+            _ => self.background_color,
+        }
+    }
+
+    /// Title text color for the current window focus.
+    pub(crate) fn current_title_color(&self, ctx: &Context) -> Color32 {
+        match self.unfocused_title_color {
+            Some(color) if !Self::is_window_focused(ctx) => color,
+            _ => self.title_color,
+        }
+    }
+
+    /// Get the title font size in points.
+    pub fn title_font_size(&self) -> f32 {
+        self.title_font_size
+    }
+
+    /// Get the UI scale multiplier.
+    pub fn ui_scale(&self) -> f32 {
+        self.ui_scale
+    }
+
+    /// Get the spacing between custom icons in points.
+    pub fn icon_spacing(&self) -> f32 {
+        self.icon_spacing
+    }
+
+    /// Get the theme mode the bar was configured with.
+    pub fn theme_mode(&self) -> ThemeMode {
+        self.theme_mode
+    }
+
+    /// Get the visibility of the close, maximize and minimize buttons.
+    pub fn control_buttons_visibility(&self) -> (bool, bool, bool) {
+        (
+            self.show_close_button,
+            self.show_maximize_button,
+            self.show_minimize_button,
+        )
+    }
+
+    /// Get where the window control buttons are drawn.
+    pub fn control_button_layout(&self) -> ControlButtonLayout {
+        self.control_button_layout
+    }
+
+    /// Get the height of the bar in points
+    ///
+    /// Returns the height drawn in the last frame, or the default of the bar's
+    /// style (scaled by `ui_scale` and shrunk in compact mode) before the first frame. The toolbar row is not
+    /// included; see `total_height()`.
+    pub fn title_bar_height(&self) -> f32 {
+        self.bar_rect
+            .map(|rect| rect.height())
+            .unwrap_or_else(|| self.target_bar_height())
+    }
+
+    /// Get the height of the bar plus its toolbar row, if any, in points.
+    pub fn total_height(&self) -> f32 {
+        let toolbar_height = match (&self.toolbar, self.toolbar_rect) {
+            (None, _) => 0.0,
+            (Some(_), Some(rect)) => rect.height(),
+            (Some(_), None) => self.scaled(self.toolbar_height),
+        };
+        self.title_bar_height() + toolbar_height
+    }
+
+    /// Get the window edge the bar is docked to.
+    pub fn title_bar_position(&self) -> TitleBarEdge {
+        self.position
+    }
+
+    /// Screen rect of the bar, estimated from the docked edge before the
+    /// first frame.
+    pub(crate) fn bar_rect_or_default(&self, ctx: &Context) -> Rect {
+        self.bar_rect.unwrap_or_else(|| {
+            let content_rect = ctx.content_rect();
+            let height = self.title_bar_height();
+            let min_y = match self.position {
+                TitleBarEdge::Top => content_rect.min.y,
+                TitleBarEdge::Bottom => content_rect.max.y - height,
+            };
+            Rect::from_min_size(
+                Pos2::new(content_rect.min.x, min_y),
+                Vec2::new(content_rect.width(), height),
+            )
+        })
+    }
+
     /// Add a custom icon to the title bar
     ///
     /// The framework automatically positions the icon based on the platform:
@@ -208,6 +623,9 @@ impl TitleBar {
             icon_color: None,
             callback,
             shortcut,
+            toggle: None,
+            active_color: None,
+            separator_after: false,
         });
         // Keep animation states aligned
         if let CustomIcon::Animated(_) = self.custom_icons.last().unwrap().icon {
@@ -216,6 +634,86 @@ impl TitleBar {
         self
     }
 
+    /// Add a custom icon that toggles between on and off
+    ///
+    /// Each click, or press of its shortcut, flips `state` and then runs the
+    /// callback, which can read the new value. While on, the icon is drawn on
+    /// a highlighted background.
+    ///
+    /// # Arguments
+    /// * `icon` - The custom icon (image or drawing function)
+    /// * `state` - Shared on/off state, also readable and settable by the app
+    /// * `callback` - Optional callback run after the state flips
+    /// * `tooltip` - Optional tooltip text to show on hover
+    /// * `shortcut` - Optional keyboard shortcut that also flips the state
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let muted = Arc::new(AtomicBool::new(false));
+    /// title_bar.add_toggle_icon(
+    ///     CustomIcon::Image(ImageSource::from_bytes("mute.svg", include_bytes!("mute.svg"))),
+    ///     muted.clone(),
+    ///     None,
+    ///     Some("Mute".to_string()),
+    ///     None,
+    /// );
+    /// ```
+    pub fn add_toggle_icon(
+        mut self,
+        icon: CustomIcon,
+        state: Arc<AtomicBool>,
+        callback: Option<Box<dyn Fn() + Send + Sync>>,
+        tooltip: Option<String>,
+        shortcut: Option<crate::KeyboardShortcut>,
+    ) -> Self {
+        self = self.add_icon(icon, callback, tooltip, shortcut);
+        if let Some(button) = self.custom_icons.last_mut() {
+            button.toggle = Some(state);
+        }
+        self
+    }
+
+    /// Separate the custom icons added so far from the ones added next
+    ///
+    /// Draws a thin vertical line between the two groups, for example to keep
+    /// connectivity icons apart from account icons. Icons are placed from the
+    /// right edge in the order they are added, so the line sits to the left
+    /// of the last icon added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::CustomIcon;
+    /// # let title_bar = TitleBar::default();
+    /// # let wifi_icon = CustomIcon::Drawn(Box::new(|_, _, _| {}));
+    /// # let account_icon = CustomIcon::Drawn(Box::new(|_, _, _| {}));
+    /// title_bar
+    ///     .add_icon(wifi_icon, None, Some("Wi-Fi".to_string()), None)
+    ///     .add_icon_separator()
+    ///     .add_icon(account_icon, None, Some("Account".to_string()), None)
+    /// # ;
+    /// ```
+    pub fn add_icon_separator(mut self) -> Self {
+        if let Some(button) = self.custom_icons.last_mut() {
+            button.separator_after = true;
+        }
+        self
+    }
+
+    /// Set the space on each side of custom icon separators in points (default 6).
+    pub fn with_icon_separator_spacing(mut self, spacing: f32) -> Self {
+        self.icon_separator_spacing = spacing;
+        self
+    }
+
+    /// Set the color of the separators between custom icons.
+    pub fn with_icon_separator_color(mut self, color: Color32) -> Self {
+        self.icon_separator_color = Some(color);
+        self
+    }
+
     /// Add an animated icon with tooltip and optional callback/shortcut
     pub fn add_animated_icon(
         mut self,
@@ -262,12 +760,12 @@ impl TitleBar {
     /// Call this in your app's update loop to handle icon shortcuts
     pub fn handle_icon_shortcuts(&self, ctx: &egui::Context) {
         for icon_button in &self.custom_icons {
-            if let Some(shortcut) = &icon_button.shortcut {
-                if shortcut.just_pressed(ctx) {
-                    if let Some(callback) = &icon_button.callback {
-                        callback();
-                    }
-                }
+            if let Some(shortcut) = &icon_button.shortcut
+                && self.shortcut_allowed(ctx, shortcut)
+                && shortcut.just_pressed(ctx)
+            {
+                icon_button.activate();
+                self.consume_shortcut(ctx, shortcut);
             }
         }
     }
@@ -280,6 +778,22 @@ impl TitleBar {
         }
     }
 
+    /// Update the hover background of a custom icon at a given index
+    /// Pass None to use the bar's hover color
+    pub fn set_custom_icon_hover_color(&mut self, index: usize, color: Option<Color32>) {
+        if let Some(button) = self.custom_icons.get_mut(index) {
+            button.hover_color = color;
+        }
+    }
+
+    /// Update the background of a toggle icon at a given index while it is on
+    /// Pass None to use a faint keyboard selection color
+    pub fn set_custom_icon_active_color(&mut self, index: usize, color: Option<Color32>) {
+        if let Some(button) = self.custom_icons.get_mut(index) {
+            button.active_color = color;
+        }
+    }
+
     /// Create a custom app icon from image bytes (supports SVG, PNG, JPEG, etc.)
     ///
     /// This function automatically detects the format and creates the appropriate ImageSource.
@@ -300,6 +814,65 @@ impl TitleBar {
             bytes: Bytes::Static(bytes),
         };
         self.app_icon = Some(icon);
+        self.app_icon_texture = None;
+        self
+    }
+
+    /// Use an embedded image as the app icon
+    ///
+    /// Like `with_app_icon()`, without having to name the image. SVG, PNG,
+    /// JPEG and the other formats enabled in `egui_extras` are recognized
+    /// from the bytes. The icon is shown next to the title on Windows/Linux.
+    ///
+    /// # Arguments
+    /// * `bytes` - The image data as bytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// title_bar.with_icon_bytes(include_bytes!("../assets/icon.png"))
+    /// ```
+    pub fn with_icon_bytes(mut self, bytes: &'static [u8]) -> Self {
+        // The SVG loader only picks up URIs ending in `.svg`; raster formats
+        // are guessed from their contents
+        let head = &bytes[..bytes.len().min(1024)];
+        let extension = if head.windows(4).any(|window| window == b"<svg") {
+            ".svg"
+        } else {
+            ""
+        };
+        let uri = format!(
+            "bytes://egui_desktop_app_icon_{:x}{extension}",
+            bytes.as_ptr() as usize
+        );
+        self.app_icon = Some(ImageSource::Bytes {
+            uri: Cow::Owned(uri),
+            bytes: Bytes::Static(bytes),
+        });
+        self.app_icon_texture = None;
+        self
+    }
+
+    /// Use any egui image source as the app icon
+    ///
+    /// Accepts `egui::include_image!`, a `file://` path, a URL or an already
+    /// loaded texture.
+    ///
+    /// # Arguments
+    /// * `image` - Where the app icon is loaded from
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::ImageSource;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_icon_image(ImageSource::Uri("file://assets/icon.png".into()))
+    /// # ;
+    /// ```
+    pub fn with_icon_image(mut self, image: ImageSource<'static>) -> Self {
+        self.app_icon = Some(image);
+        self.app_icon_texture = None;
         self
     }
 
@@ -312,18 +885,17 @@ impl TitleBar {
             return;
         }
 
-        let icon_size = 16.0;
-        let spacing = self.icon_spacing;
-        let icon_height = 28.0; // Standard icon height
+        let icon_size = self.scaled(16.0);
+        let icon_height = self.scaled(28.0); // Standard icon height
 
         // Calculate total width needed for all icons + extra spacing
-        let extra_spacing = 16.0; // Extra space between custom icons and window controls
-        let total_width =
-            self.custom_icons.len() as f32 * (icon_size + spacing) - spacing + extra_spacing;
+        let extra_spacing = self.scaled(16.0); // Extra space between custom icons and window controls
+        let total_width = self.custom_icons_width();
 
         // Allocate space for the entire icon bar
         let (icon_bar_rect, _) =
             ui.allocate_exact_size(Vec2::new(total_width, icon_height), Sense::click());
+        self.exclude_from_drag(icon_bar_rect);
 
         let mut current_x = icon_bar_rect.max.x - extra_spacing;
 
@@ -337,11 +909,14 @@ impl TitleBar {
 
         for index in 0..self.custom_icons.len() {
             let icon_button = &self.custom_icons[index];
-            let icon_id = Id::new(format!("custom_icon_{}", index));
+            let icon_id = self.id.with(("custom_icon", index));
 
             // Create individual icon rect (positioned from right to left)
             let icon_rect = Rect::from_min_size(
-                Pos2::new(current_x - icon_size, icon_bar_rect.min.y + 6.0),
+                Pos2::new(
+                    current_x - icon_size,
+                    icon_bar_rect.center().y - icon_size / 2.0,
+                ),
                 Vec2::new(icon_size, icon_size),
             );
 
@@ -358,11 +933,26 @@ impl TitleBar {
                 response = response.on_hover_text(tooltip_text);
             }
 
+            // Toggle icons that are on sit on a highlighted background
+            if icon_button.is_active() {
+                let active_color = icon_button
+                    .active_color
+                    .unwrap_or(self.submenu_keyboard_selection_color.gamma_multiply(0.4));
+                ui.painter().rect_filled(
+                    icon_rect.expand(self.scaled(2.0)),
+                    CornerRadius::same(2),
+                    active_color,
+                );
+            }
+
             // Handle hover effect (render background first)
             if response.hovered() {
                 let hover_color = icon_button.hover_color.unwrap_or(self.hover_color);
-                ui.painter()
-                    .rect_filled(icon_rect.expand(2.0), CornerRadius::same(2), hover_color);
+                ui.painter().rect_filled(
+                    icon_rect.expand(self.scaled(2.0)),
+                    CornerRadius::same(2),
+                    hover_color,
+                );
                 ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
             }
 
@@ -439,15 +1029,26 @@ impl TitleBar {
 
             // Handle click
             if response.clicked() {
-                if let Some(ref callback) = icon_button.callback {
-                    callback();
-                }
+                icon_button.activate();
                 // Ensure the next frame runs so animations start immediately
                 ui.ctx().request_repaint();
             }
 
             // Move to next icon position (from right to left)
-            current_x -= icon_size + spacing;
+            current_x -= icon_size + self.custom_icon_gap(icon_button);
+            if icon_button.separator_after && index + 1 < self.custom_icons.len() {
+                let x = current_x + self.scaled(self.icon_separator_spacing);
+                let half_height = icon_size / 2.0;
+                let color = self
+                    .icon_separator_color
+                    .unwrap_or(self.menu_text_color.gamma_multiply(0.3));
+                ui.painter().vline(
+                    x,
+                    (icon_bar_rect.center().y - half_height)
+                        ..=(icon_bar_rect.center().y + half_height),
+                    Stroke::new(1.0, color),
+                );
+            }
         }
     }
 
@@ -465,4 +1066,39 @@ impl TitleBar {
             .clone()
             .unwrap_or_else(|| self.get_default_app_icon())
     }
+
+    /// App icon as a texture rasterized for `size` points
+    ///
+    /// The icon is loaded once per image and size and the texture is reused
+    /// on later frames. While it is still loading, the plain image source is
+    /// returned so `Image` can show its placeholder.
+    pub(crate) fn app_icon_image(&mut self, ctx: &Context, size: f32) -> ImageSource<'static> {
+        let source = self.get_app_icon();
+        let Some(uri) = source.uri().map(str::to_owned) else {
+            return source;
+        };
+        let pixels = (size * ctx.pixels_per_point()).round() as u32;
+        if let Some((cached_uri, cached_pixels, texture)) = &self.app_icon_texture
+            && *cached_uri == uri
+            && *cached_pixels == pixels
+        {
+            return ImageSource::Texture(*texture);
+        }
+
+        let size_hint = SizeHint::Size {
+            width: pixels,
+            height: pixels,
+            maintain_aspect_ratio: true,
+        };
+        match source
+            .clone()
+            .load(ctx, TextureOptions::default(), size_hint)
+        {
+            Ok(TexturePoll::Ready { texture }) => {
+                self.app_icon_texture = Some((uri, pixels, texture));
+                ImageSource::Texture(texture)
+            }
+            _ => source,
+        }
+    }
 }
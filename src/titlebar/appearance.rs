@@ -0,0 +1,154 @@
+use egui::{Color32, Shadow};
+
+use crate::theme::ColorScheme;
+use crate::{ChevronStyle, TitleBar, TitleBarEdge, TitleBarStyle};
+
+/// Colors and sizes of a title bar, without its content or callbacks
+///
+/// Take the appearance of a configured bar with [`TitleBar::appearance`] and
+/// apply it to the bars of other viewports with [`TitleBar::with_appearance`],
+/// so they all look alike without repeating every builder call. Menus, icons,
+/// titles and callbacks are not part of the appearance.
+///
+/// # Examples
+///
+/// ```rust
+/// # use egui_desktop::{ColorScheme, TitleBar, TitleBarOptions};
+/// let base = TitleBar::default()
+///     .with_color_scheme(ColorScheme::dark())
+///     .with_compact(true)
+///     .appearance();
+///
+/// let main_bar = TitleBar::new(TitleBarOptions::new().with_title("Editor")).with_appearance(base);
+/// let tool_bar = TitleBar::new(TitleBarOptions::new().with_title("Inspector")).with_appearance(base);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TitleBarAppearance {
+    /// Every bar and menu color.
+    pub colors: ColorScheme,
+    /// Background color while the window is unfocused (`None` keeps the background).
+    pub unfocused_background_color: Option<Color32>,
+    /// Title text color while the window is unfocused (`None` keeps the title color).
+    pub unfocused_title_color: Option<Color32>,
+    /// Title font size in points.
+    pub title_font_size: f32,
+    /// Menu text size in points.
+    pub menu_text_size: f32,
+    /// Submenu text size in points.
+    pub submenu_text_size: f32,
+    /// Color of submenu separators (`None` uses the submenu border color).
+    pub submenu_separator_color: Option<Color32>,
+    /// Thickness of submenu separators in points.
+    pub submenu_separator_thickness: f32,
+    /// Color of side-menu arrows (`None` uses the submenu text color).
+    pub submenu_chevron_color: Option<Color32>,
+    /// Height of side-menu arrows in points (`None` follows the text size).
+    pub submenu_chevron_size: Option<f32>,
+    /// Shape of side-menu arrows.
+    pub submenu_chevron_style: ChevronStyle,
+    /// Drop shadow under submenus.
+    pub submenu_shadow: Shadow,
+    /// Corner radius of submenus in points.
+    pub submenu_corner_radius: u8,
+    /// Minimum width of submenus in points.
+    pub submenu_min_width: f32,
+    /// Spacing between custom icons in points.
+    pub icon_spacing: f32,
+    /// Space on each side of a separator between custom icons, in points.
+    pub icon_separator_spacing: f32,
+    /// Color of the separators between custom icons (`None` uses a faint menu text color).
+    pub icon_separator_color: Option<Color32>,
+    /// Multiplier applied to every bar and menu metric.
+    pub ui_scale: f32,
+    /// Whether the bar, its menus and window controls are drawn denser.
+    pub compact: bool,
+    /// Height of the bar in points, before UI scaling (`None` uses the platform default).
+    pub bar_height: Option<f32>,
+    /// Window edge the bar is docked to.
+    pub position: TitleBarEdge,
+    /// Platform look of the bar.
+    pub style: TitleBarStyle,
+}
+
+impl Default for TitleBarAppearance {
+    fn default() -> Self {
+        TitleBar::default().appearance()
+    }
+}
+
+impl TitleBar {
+    /// Get the colors and sizes currently used by the title bar.
+    pub fn appearance(&self) -> TitleBarAppearance {
+        TitleBarAppearance {
+            colors: self.color_scheme(),
+            unfocused_background_color: self.unfocused_background_color,
+            unfocused_title_color: self.unfocused_title_color,
+            title_font_size: self.title_font_size,
+            menu_text_size: self.menu_text_size,
+            submenu_text_size: self.submenu_text_size,
+            submenu_separator_color: self.submenu_separator_color,
+            submenu_separator_thickness: self.submenu_separator_thickness,
+            submenu_chevron_color: self.submenu_chevron_color,
+            submenu_chevron_size: self.submenu_chevron_size,
+            submenu_chevron_style: self.submenu_chevron_style,
+            submenu_shadow: self.submenu_shadow,
+            submenu_corner_radius: self.submenu_corner_radius,
+            submenu_min_width: self.submenu_min_width,
+            icon_spacing: self.icon_spacing,
+            icon_separator_spacing: self.icon_separator_spacing,
+            icon_separator_color: self.icon_separator_color,
+            ui_scale: self.ui_scale,
+            compact: self.compact,
+            bar_height: self.bar_height,
+            position: self.position,
+            style: self.style,
+        }
+    }
+
+    /// Apply the colors and sizes of another title bar
+    ///
+    /// Individual builders chained afterwards still override single values.
+    ///
+    /// # Arguments
+    /// * `appearance` - Colors and sizes, usually from another bar's `appearance()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let main_bar = TitleBar::default();
+    /// let secondary = TitleBar::default().with_appearance(main_bar.appearance());
+    /// ```
+    pub fn with_appearance(mut self, appearance: TitleBarAppearance) -> Self {
+        self.apply_appearance(appearance);
+        self
+    }
+
+    /// Apply the colors and sizes of another title bar at runtime
+    ///
+    /// Same as [`with_appearance`](Self::with_appearance), for use on an existing bar.
+    pub fn apply_appearance(&mut self, appearance: TitleBarAppearance) {
+        self.apply_color_scheme(appearance.colors);
+        self.unfocused_background_color = appearance.unfocused_background_color;
+        self.unfocused_title_color = appearance.unfocused_title_color;
+        self.title_font_size = appearance.title_font_size;
+        self.menu_text_size = appearance.menu_text_size;
+        self.submenu_text_size = appearance.submenu_text_size;
+        self.submenu_separator_color = appearance.submenu_separator_color;
+        self.submenu_separator_thickness = appearance.submenu_separator_thickness;
+        self.submenu_chevron_color = appearance.submenu_chevron_color;
+        self.submenu_chevron_size = appearance.submenu_chevron_size;
+        self.submenu_chevron_style = appearance.submenu_chevron_style;
+        self.submenu_shadow = appearance.submenu_shadow;
+        self.submenu_corner_radius = appearance.submenu_corner_radius;
+        self.submenu_min_width = appearance.submenu_min_width;
+        self.icon_spacing = appearance.icon_spacing;
+        self.icon_separator_spacing = appearance.icon_separator_spacing;
+        self.icon_separator_color = appearance.icon_separator_color;
+        self.ui_scale = appearance.ui_scale;
+        self.compact = appearance.compact;
+        self.bar_height = appearance.bar_height;
+        self.position = appearance.position;
+        self.style = appearance.style;
+    }
+}
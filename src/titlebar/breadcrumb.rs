@@ -0,0 +1,198 @@
+use egui::{Align2, CursorIcon, FontId, Pos2, Rect, Sense, Stroke, Ui, Vec2};
+
+use crate::TitleBar;
+
+/// Separator drawn between breadcrumb segments.
+const SEPARATOR: &str = "›";
+/// Stand-in for segments collapsed on the left.
+const ELLIPSIS: &str = "…";
+
+/// A breadcrumb entry after fitting the path into the available width.
+struct Crumb {
+    /// Segment reported when this entry is clicked.
+    index: usize,
+    /// Whether this entry stands for collapsed segments.
+    collapsed: bool,
+    /// Text width in points.
+    width: f32,
+}
+
+impl TitleBar {
+    /// Show a clickable breadcrumb path instead of the title
+    ///
+    /// Segments are separated with "›". When the bar is too narrow the
+    /// leading segments collapse into "…", always keeping the last segment
+    /// visible. It follows the same per-platform visibility as the title.
+    ///
+    /// # Arguments
+    /// * `segments` - Path segments from the root to the current item
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_breadcrumb(vec!["Home".into(), "Projects".into(), "file.rs".into()])
+    /// # ;
+    /// ```
+    pub fn with_breadcrumb(mut self, segments: Vec<String>) -> Self {
+        self.breadcrumb = segments;
+        self
+    }
+
+    /// Replace the breadcrumb segments at runtime.
+    pub fn set_breadcrumb(&mut self, segments: Vec<String>) {
+        self.breadcrumb = segments;
+        self.breadcrumb_clicked = None;
+    }
+
+    /// Take the index of the breadcrumb segment clicked since the last call
+    ///
+    /// Clicking the "…" of collapsed segments reports the last hidden segment.
+    pub fn take_breadcrumb_click(&mut self) -> Option<usize> {
+        self.breadcrumb_clicked.take()
+    }
+
+    /// Whether a breadcrumb replaces the title.
+    pub(crate) fn has_breadcrumb(&self) -> bool {
+        !self.breadcrumb.is_empty()
+    }
+
+    /// Width the breadcrumb needs when limited to `max_width`.
+    pub(crate) fn breadcrumb_width(&mut self, ui: &Ui, font: &FontId, max_width: f32) -> f32 {
+        let (crumbs, separator_width) = self.layout_breadcrumb(ui, font, max_width);
+        Self::crumbs_width(&crumbs, separator_width)
+    }
+
+    /// Draw the breadcrumb with its left edge vertically centered on `left_center`.
+    pub(crate) fn render_breadcrumb(
+        &mut self,
+        ui: &mut Ui,
+        left_center: Pos2,
+        font: &FontId,
+        max_width: f32,
+    ) {
+        let (crumbs, separator_width) = self.layout_breadcrumb(ui, font, max_width);
+        let height = font.size * 1.4;
+        let last = self.breadcrumb.len() - 1;
+
+        let mut x = left_center.x;
+        for (position, crumb) in crumbs.iter().enumerate() {
+            if position > 0 {
+                ui.painter().text(
+                    Pos2::new(x + separator_width / 2.0, left_center.y),
+                    Align2::CENTER_CENTER,
+                    SEPARATOR,
+                    font.clone(),
+                    self.menu_text_color.gamma_multiply(0.6),
+                );
+                x += separator_width;
+            }
+
+            let rect = Rect::from_min_size(
+                Pos2::new(x, left_center.y - height / 2.0),
+                Vec2::new(crumb.width, height),
+            );
+            x += crumb.width;
+            self.exclude_from_drag(rect);
+
+            let response = ui.interact(
+                rect,
+                self.id.with(("breadcrumb", position, crumb.index)),
+                Sense::click(),
+            );
+            let color = if crumb.index == last && !crumb.collapsed {
+                self.title_color
+            } else {
+                self.menu_text_color
+            };
+            let text = if crumb.collapsed {
+                ELLIPSIS
+            } else {
+                self.breadcrumb[crumb.index].as_str()
+            };
+            ui.painter().text(
+                Pos2::new(rect.min.x, left_center.y),
+                Align2::LEFT_CENTER,
+                text,
+                font.clone(),
+                color,
+            );
+
+            if response.hovered() {
+                ui.painter().line_segment(
+                    [
+                        Pos2::new(rect.min.x, rect.max.y - 1.0),
+                        Pos2::new(rect.max.x, rect.max.y - 1.0),
+                    ],
+                    Stroke::new(1.0, color),
+                );
+                ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+            }
+            if response.clicked() {
+                self.breadcrumb_clicked = Some(crumb.index);
+            }
+        }
+    }
+
+    /// Fit the segments into `max_width`, collapsing from the left.
+    ///
+    /// Returns the entries to draw and the width of one separator.
+    fn layout_breadcrumb(&mut self, ui: &Ui, font: &FontId, max_width: f32) -> (Vec<Crumb>, f32) {
+        let separator_width = self.text_widths.width(ui, &format!(" {SEPARATOR} "), font);
+        let widths: Vec<f32> = self
+            .breadcrumb
+            .iter()
+            .map(|segment| self.text_widths.width(ui, segment, font))
+            .collect();
+        let Some(&last_width) = widths.last() else {
+            return (Vec::new(), separator_width);
+        };
+
+        let segment = |index: usize| Crumb {
+            index,
+            collapsed: false,
+            width: widths[index],
+        };
+
+        let full_width = widths.iter().sum::<f32>() + separator_width * (widths.len() - 1) as f32;
+        if full_width <= max_width {
+            return ((0..widths.len()).map(segment).collect(), separator_width);
+        }
+
+        // Keep the last segment no matter what, then add segments from the
+        // right while they fit next to a leading "…"
+        let ellipsis_width = self.text_widths.width(ui, ELLIPSIS, font);
+        let mut first = widths.len() - 1;
+        let mut used = last_width;
+        while first > 0 {
+            let candidate = used + separator_width + widths[first - 1];
+            let ellipsis = if first > 1 {
+                separator_width + ellipsis_width
+            } else {
+                0.0
+            };
+            if candidate + ellipsis > max_width {
+                break;
+            }
+            used = candidate;
+            first -= 1;
+        }
+
+        let mut crumbs = Vec::with_capacity(widths.len() - first + 1);
+        if first > 0 {
+            crumbs.push(Crumb {
+                index: first - 1,
+                collapsed: true,
+                width: ellipsis_width,
+            });
+        }
+        crumbs.extend((first..widths.len()).map(segment));
+        (crumbs, separator_width)
+    }
+
+    fn crumbs_width(crumbs: &[Crumb], separator_width: f32) -> f32 {
+        crumbs.iter().map(|crumb| crumb.width).sum::<f32>()
+            + separator_width * crumbs.len().saturating_sub(1) as f32
+    }
+}
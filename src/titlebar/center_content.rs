@@ -0,0 +1,90 @@
+use egui::{Align, Layout, Pos2, Rect, Sense, Ui, UiBuilder};
+
+use crate::TitleBar;
+
+/// Callback that fills the middle of the bar with widgets each frame.
+pub type CenterContentFn = dyn FnMut(&mut Ui) + Send + Sync;
+
+impl TitleBar {
+    /// Put custom widgets in the middle of the bar
+    ///
+    /// The widgets are laid out left to right and centered on the bar where
+    /// the menus and the right-hand cluster leave room, otherwise pushed
+    /// aside and clipped to the space left. Pressing on them never drags the
+    /// window. On macOS the content takes the place of the centered title.
+    ///
+    /// # Arguments
+    /// * `content` - Called every frame to add the widgets
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_center_content(Box::new(|ui| {
+    ///     ui.label("Branch:");
+    ///     if ui.button("main").clicked() {
+    ///         println!("Switch branch");
+    ///     }
+    /// }))
+    /// # ;
+    /// ```
+    pub fn with_center_content(mut self, content: Box<CenterContentFn>) -> Self {
+        self.center_content = Some(content);
+        self
+    }
+
+    /// Whether custom center content is set.
+    pub fn has_center_content(&self) -> bool {
+        self.center_content.is_some()
+    }
+
+    /// Draw the center content between `left` and `right`, centered on the bar if it fits.
+    pub(crate) fn render_center_content(
+        &mut self,
+        ui: &mut Ui,
+        title_bar_rect: Rect,
+        left: f32,
+        right: f32,
+    ) {
+        let Some(content) = &mut self.center_content else {
+            return;
+        };
+        let region = Rect::from_min_max(
+            Pos2::new(left, title_bar_rect.min.y),
+            Pos2::new(right, title_bar_rect.max.y),
+        );
+        if region.width() <= 0.0 {
+            return;
+        }
+
+        // Widgets are centered using the width they took last frame
+        let width = self.center_content_width.min(region.width());
+        let start =
+            (title_bar_rect.center().x - width / 2.0).clamp(region.min.x, region.max.x - width);
+        let content_rect = Rect::from_min_max(Pos2::new(start, region.min.y), region.max);
+
+        // Claim presses on the content so they don't reach the bar's drag
+        let id = self.id.with("center_content");
+        ui.interact(
+            Rect::from_min_max(content_rect.min, Pos2::new(start + width, region.max.y)),
+            id,
+            Sense::click_and_drag(),
+        );
+
+        let mut content_ui = ui.new_child(
+            UiBuilder::new()
+                .id_salt(id)
+                .max_rect(content_rect)
+                .layout(Layout::left_to_right(Align::Center)),
+        );
+        content_ui.set_clip_rect(region.intersect(ui.clip_rect()));
+        content(&mut content_ui);
+
+        let used = content_ui.min_rect().width();
+        if used != self.center_content_width {
+            self.center_content_width = used;
+            ui.ctx().request_repaint();
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use egui::Color32;
+use serde::{Deserialize, Serialize};
+
+use crate::theme::ThemeMode;
+use crate::titlebar::control_buttons::ControlButtonLayout;
+use crate::{TitleBar, TitleBarEdge, TitleBarOptions, TitleBarStyle};
+
+/// Serializable title bar appearance and layout.
+///
+/// Covers everything that can be expressed as plain data: colors, sizes,
+/// title visibility and which window controls are shown. Menus and custom
+/// icons carry callbacks, so add them afterwards with the usual builders.
+///
+/// Every field is optional; anything left out falls back to the theme
+/// selected by `theme_mode`. Colors use egui's serde format, an
+/// `[r, g, b, a]` array.
+///
+/// # Examples
+///
+/// ```toml
+/// title = "My App"
+/// theme_mode = "Dark"
+/// background_color = [30, 30, 30, 255]
+/// menu_text_size = 13.0
+/// show_maximize_button = false
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TitleBarConfig {
+    /// Optional title text.
+    pub title: Option<String>,
+    /// Theme the unspecified colors are taken from.
+    pub theme_mode: Option<ThemeMode>,
+    /// Show title text on macOS.
+    pub show_title_on_macos: Option<bool>,
+    /// Show title text on Windows.
+    pub show_title_on_windows: Option<bool>,
+    /// Show title text on Linux.
+    pub show_title_on_linux: Option<bool>,
+    /// Background color of the bar.
+    pub background_color: Option<Color32>,
+    /// Hover color for window controls.
+    pub hover_color: Option<Color32>,
+    /// Close button hover color.
+    pub close_hover_color: Option<Color32>,
+    /// Close icon color.
+    pub close_icon_color: Option<Color32>,
+    /// Maximize icon color.
+    pub maximize_icon_color: Option<Color32>,
+    /// Restore icon color.
+    pub restore_icon_color: Option<Color32>,
+    /// Minimize icon color.
+    pub minimize_icon_color: Option<Color32>,
+    /// Title text color.
+    pub title_color: Option<Color32>,
+    /// Title font size in points.
+    pub title_font_size: Option<f32>,
+    /// Menu text color.
+    pub menu_text_color: Option<Color32>,
+    /// Menu text size in points.
+    pub menu_text_size: Option<f32>,
+    /// Menu hover background color.
+    pub menu_hover_color: Option<Color32>,
+    /// Keyboard selection highlight color for menus.
+    pub keyboard_selection_color: Option<Color32>,
+    /// Submenu background color.
+    pub submenu_background_color: Option<Color32>,
+    /// Submenu text color.
+    pub submenu_text_color: Option<Color32>,
+    /// Submenu text size in points.
+    pub submenu_text_size: Option<f32>,
+    /// Submenu hover background color.
+    pub submenu_hover_color: Option<Color32>,
+    /// Submenu disabled item color.
+    pub submenu_disabled_color: Option<Color32>,
+    /// Submenu shortcut text color.
+    pub submenu_shortcut_color: Option<Color32>,
+    /// Submenu border color.
+    pub submenu_border_color: Option<Color32>,
+    /// Submenu keyboard selection highlight color.
+    pub submenu_keyboard_selection_color: Option<Color32>,
+    /// Show the close button.
+    pub show_close_button: Option<bool>,
+    /// Show the maximize button.
+    pub show_maximize_button: Option<bool>,
+    /// Show the minimize button.
+    pub show_minimize_button: Option<bool>,
+    /// Where the window control buttons are drawn.
+    pub control_button_layout: Option<ControlButtonLayout>,
+    /// Window edge the bar is docked to.
+    pub position: Option<TitleBarEdge>,
+    /// Platform look of the bar.
+    pub style: Option<TitleBarStyle>,
+    /// Spacing between custom icons in points.
+    pub icon_spacing: Option<f32>,
+    /// Multiplier for bar and menu metrics.
+    pub ui_scale: Option<f32>,
+    /// Draw a denser bar.
+    pub compact: Option<bool>,
+    /// Switch between light and dark colors with the system theme.
+    pub follow_system_theme: Option<bool>,
+}
+
+impl From<&TitleBarConfig> for TitleBarOptions {
+    fn from(config: &TitleBarConfig) -> Self {
+        let defaults = TitleBarOptions::default();
+        Self {
+            title: config.title.clone().filter(|title| !title.is_empty()),
+            theme_mode: config.theme_mode.unwrap_or(defaults.theme_mode),
+            show_title_on_macos: config
+                .show_title_on_macos
+                .unwrap_or(defaults.show_title_on_macos),
+            show_title_on_windows: config
+                .show_title_on_windows
+                .unwrap_or(defaults.show_title_on_windows),
+            show_title_on_linux: config
+                .show_title_on_linux
+                .unwrap_or(defaults.show_title_on_linux),
+            background_color: config.background_color,
+            hover_color: config.hover_color,
+            close_hover_color: config.close_hover_color,
+            close_icon_color: config.close_icon_color,
+            maximize_icon_color: config.maximize_icon_color,
+            restore_icon_color: config.restore_icon_color,
+            minimize_icon_color: config.minimize_icon_color,
+            title_color: config.title_color,
+            title_font_size: config.title_font_size,
+            menu_text_color: config.menu_text_color,
+            menu_text_size: config.menu_text_size,
+            menu_hover_color: config.menu_hover_color,
+            keyboard_selection_color: config.keyboard_selection_color,
+            show_close_button: config.show_close_button,
+            show_maximize_button: config.show_maximize_button,
+            show_minimize_button: config.show_minimize_button,
+            icon_spacing: config.icon_spacing,
+            ui_scale: config.ui_scale,
+            ..defaults
+        }
+    }
+}
+
+impl TitleBar {
+    /// Create a title bar from a deserialized configuration
+    ///
+    /// Menus, custom icons and the app icon are not part of the config; add
+    /// them with the regular builders afterwards.
+    ///
+    /// # Arguments
+    /// * `config` - Appearance and layout, typically loaded from TOML or JSON
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use egui_desktop::{TitleBar, TitleBarConfig};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config: TitleBarConfig = toml::from_str(&std::fs::read_to_string("titlebar.toml")?)?;
+    /// let title_bar = TitleBar::from_config(config)
+    ///     .add_menu_item("File", Some(Box::new(|| println!("File clicked"))));
+    /// # let _ = title_bar;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_config(config: TitleBarConfig) -> Self {
+        let mut title_bar = TitleBar::new(TitleBarOptions::from(&config));
+
+        let submenu_colors = [
+            (
+                config.submenu_background_color,
+                &mut title_bar.submenu_background_color,
+            ),
+            (config.submenu_text_color, &mut title_bar.submenu_text_color),
+            (
+                config.submenu_hover_color,
+                &mut title_bar.submenu_hover_color,
+            ),
+            (
+                config.submenu_disabled_color,
+                &mut title_bar.submenu_disabled_color,
+            ),
+            (
+                config.submenu_shortcut_color,
+                &mut title_bar.submenu_shortcut_color,
+            ),
+            (
+                config.submenu_border_color,
+                &mut title_bar.submenu_border_color,
+            ),
+            (
+                config.submenu_keyboard_selection_color,
+                &mut title_bar.submenu_keyboard_selection_color,
+            ),
+        ];
+        for (color, field) in submenu_colors {
+            if let Some(color) = color {
+                *field = color;
+            }
+        }
+        if let Some(size) = config.submenu_text_size {
+            title_bar.submenu_text_size = size;
+        }
+        if let Some(layout) = config.control_button_layout {
+            title_bar.control_button_layout = layout;
+        }
+        if let Some(edge) = config.position {
+            title_bar.position = edge;
+        }
+        if let Some(style) = config.style {
+            title_bar.style = style;
+        }
+        if let Some(compact) = config.compact {
+            title_bar.compact = compact;
+        }
+
+        // The configured colors become the scheme for the configured mode, so
+        // following the system theme doesn't discard them
+        if config.follow_system_theme.unwrap_or(false) {
+            let scheme = title_bar.color_scheme();
+            title_bar = match config.theme_mode {
+                Some(ThemeMode::Dark) => title_bar.with_dark_color_scheme(scheme),
+                Some(ThemeMode::Light) => title_bar.with_light_color_scheme(scheme),
+                _ => title_bar,
+            }
+            .with_follow_system_theme(true);
+        }
+
+        title_bar
+    }
+}
@@ -1,9 +1,32 @@
 use egui::{
-    Color32, CursorIcon, Painter, Pos2, Rect, Response, Sense, Stroke, StrokeKind, Ui, Vec2,
+    Color32, Context, CursorIcon, Painter, Pos2, Rect, Response, Sense, Stroke, StrokeKind, Ui,
+    Vec2,
 };
 
 use crate::TitleBar;
 
+/// Action run by the minimize button in place of minimizing the window.
+pub type MinimizeFn = dyn Fn(&Context) + Send + Sync;
+
+/// Hook deciding whether a close request goes ahead (`true`) or is canceled.
+pub type CloseRequestFn = dyn Fn() -> bool + Send + Sync;
+
+/// Where the window control buttons sit in the title bar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ControlButtonLayout {
+    /// The platform's usual side: left for the macOS traffic lights, right
+    /// for the Windows/Linux buttons.
+    #[default]
+    Platform,
+    /// Pinned to the left edge.
+    Left,
+    /// Pinned to the right edge.
+    Right,
+    /// Centered in the bar. A centered macOS title moves next to the buttons.
+    Center,
+}
+
 /// Window control icon types used by the title bar.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum WindowControlIcon {
@@ -126,12 +149,99 @@ impl TitleBar {
         );
     }
 
+    /// Draw the glyph shown inside a traffic light on hover
+    ///
+    /// Draws an ×, − or + with line segments so the result does not depend on
+    /// the available fonts.
+    ///
+    /// # Arguments
+    /// * `painter` - The egui painter to draw with
+    /// * `center` - The center of the traffic light circle
+    /// * `size` - The diameter of the traffic light circle
+    /// * `symbol` - The button whose glyph should be drawn
+    fn draw_traffic_light_symbol(
+        &self,
+        painter: &Painter,
+        center: Pos2,
+        size: f32,
+        symbol: WindowControlIcon,
+    ) {
+        let half = size * 0.22;
+        let stroke = Stroke::new(1.2, Color32::from_black_alpha(150));
+
+        match symbol {
+            WindowControlIcon::Close => {
+                painter.line_segment(
+                    [
+                        center + Vec2::new(-half, -half),
+                        center + Vec2::new(half, half),
+                    ],
+                    stroke,
+                );
+                painter.line_segment(
+                    [
+                        center + Vec2::new(half, -half),
+                        center + Vec2::new(-half, half),
+                    ],
+                    stroke,
+                );
+            }
+            WindowControlIcon::Minimize => {
+                painter.line_segment(
+                    [
+                        center + Vec2::new(-half, 0.0),
+                        center + Vec2::new(half, 0.0),
+                    ],
+                    stroke,
+                );
+            }
+            WindowControlIcon::Maximize | WindowControlIcon::Restore => {
+                painter.line_segment(
+                    [
+                        center + Vec2::new(-half, 0.0),
+                        center + Vec2::new(half, 0.0),
+                    ],
+                    stroke,
+                );
+                painter.line_segment(
+                    [
+                        center + Vec2::new(0.0, -half),
+                        center + Vec2::new(0.0, half),
+                    ],
+                    stroke,
+                );
+            }
+        }
+    }
+
     /// Render a macOS-style traffic light button.
     pub fn render_traffic_light(&self, ui: &mut Ui, color: Color32, size: f32) -> egui::Response {
+        self.render_traffic_light_with_symbol(ui, color, size, None)
+    }
+
+    /// Render a macOS-style traffic light button, optionally drawing its symbol.
+    ///
+    /// Native traffic lights reveal an ×, − or + glyph inside each circle while
+    /// the pointer is over the cluster. Pass `Some(icon)` to draw the glyph that
+    /// matches the button semantics (`Restore` uses the same + as `Maximize`).
+    ///
+    /// # Arguments
+    /// * `ui` - The egui UI context
+    /// * `color` - The fill color of the circle
+    /// * `size` - The diameter of the circle
+    /// * `symbol` - The glyph to draw inside the circle, if any
+    pub fn render_traffic_light_with_symbol(
+        &self,
+        ui: &mut Ui,
+        color: Color32,
+        size: f32,
+        symbol: Option<WindowControlIcon>,
+    ) -> egui::Response {
         let button_size = Vec2::new(size, size);
         let (button_id, button_rect) = ui.allocate_space(button_size);
 
-        let y_center = 14.0;
+        // Center vertically in the bar (the panel clips to its own rect)
+        let y_center = ui.clip_rect().center().y;
         let centered_pos = Pos2::new(button_rect.center().x, y_center);
 
         ui.painter().circle_filled(centered_pos, size / 2.0, color);
@@ -141,6 +251,10 @@ impl TitleBar {
             Stroke::new(0.5, Color32::from_rgba_premultiplied(0, 0, 0, 30)),
         );
 
+        if let Some(symbol) = symbol {
+            self.draw_traffic_light_symbol(ui.painter(), centered_pos, size, symbol);
+        }
+
         let centered_rect = Rect::from_center_size(centered_pos, button_size);
         let response = ui.interact(centered_rect, button_id, Sense::click());
 
@@ -173,18 +287,44 @@ impl TitleBar {
         icon_color: Color32,
         icon_size: f32,
     ) -> Response {
-        let desired_size = Vec2::new(46.0, 32.0);
+        self.render_window_control_button_hovered(
+            ui,
+            icon_type,
+            hover_color,
+            icon_color,
+            icon_size,
+            false,
+        )
+    }
+
+    /// Like `render_window_control_button_with_drawn_icon()`, drawn hovered
+    /// when `force_hover` is set even though egui sees no pointer.
+    pub(crate) fn render_window_control_button_hovered(
+        &self,
+        ui: &mut Ui,
+        icon_type: WindowControlIcon,
+        hover_color: Color32,
+        icon_color: Color32,
+        icon_size: f32,
+        force_hover: bool,
+    ) -> Response {
+        let desired_size = Vec2::new(self.compacted(46.0), self.target_bar_height());
         let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let hovered = response.hovered() || force_hover;
+        let hover = Self::hover_fade(ui.ctx(), response.id, hovered, self.hover_animation_time());
 
-        if response.hovered() {
-            ui.painter().rect_filled(rect, 2.0, hover_color);
+        if hover > 0.0 {
+            ui.painter()
+                .rect_filled(rect, 2.0, hover_color.gamma_multiply(hover));
+        }
+        if hovered {
             ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
         }
 
         let icon_rect = Rect::from_center_size(rect.center(), Vec2::new(icon_size, icon_size));
 
-        let final_icon_color = if response.hovered() && hover_color == self.close_hover_color {
-            Color32::WHITE
+        let final_icon_color = if hover_color == self.close_hover_color {
+            icon_color.lerp_to_gamma(Color32::WHITE, hover)
         } else {
             icon_color
         };
@@ -0,0 +1,82 @@
+use egui::{Pos2, Rect, Response, Sense, Ui};
+
+use crate::TitleBar;
+
+impl TitleBar {
+    /// Restrict window dragging to a handle inside the bar
+    ///
+    /// When set, only presses inside `handle` move the window; the rest of
+    /// the bar no longer drags, which helps when it is packed with widgets.
+    /// Double-clicking anywhere on the bar still maximizes. A dotted grip is
+    /// painted in the handle unless disabled with `with_drag_handle_grip()`.
+    ///
+    /// # Arguments
+    /// * `handle` - Rect relative to the top-left corner of the bar, or `None`
+    ///   to make the whole bar draggable again
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui::{Rect, pos2, vec2};
+    /// # let title_bar = TitleBar::default();
+    /// // A 24pt grip strip right after the app icon
+    /// title_bar.with_drag_handle(Some(Rect::from_min_size(pos2(36.0, 0.0), vec2(24.0, 32.0))))
+    /// # ;
+    /// ```
+    pub fn with_drag_handle(mut self, handle: Option<Rect>) -> Self {
+        self.drag_handle = handle;
+        self
+    }
+
+    /// Set whether a grip is painted in the drag handle (default `true`).
+    pub fn with_drag_handle_grip(mut self, show: bool) -> Self {
+        self.show_drag_handle_grip = show;
+        self
+    }
+
+    /// Sense for the whole bar: only clicks when a drag handle takes over dragging.
+    pub(crate) fn bar_sense(&self) -> Sense {
+        if self.drag_handle.is_some() {
+            Sense::click()
+        } else {
+            Sense::click_and_drag()
+        }
+    }
+
+    /// Interact with and paint the drag handle, if one is configured.
+    pub(crate) fn render_drag_handle(&self, ui: &mut Ui, title_bar_rect: Rect) -> Option<Response> {
+        let handle = self
+            .drag_handle?
+            .translate(title_bar_rect.min.to_vec2())
+            .intersect(title_bar_rect);
+        let response = ui.interact(handle, self.id.with("drag_handle"), Sense::click_and_drag());
+
+        if self.show_drag_handle_grip {
+            // Two columns of dots centered in the handle
+            let dot_radius = self.scaled(1.2);
+            let spacing = self.scaled(4.0);
+            let rows = ((handle.height() - spacing) / spacing).clamp(0.0, 4.0) as usize;
+            let color = if response.hovered() {
+                self.title_color
+            } else {
+                self.menu_text_color.gamma_multiply(0.5)
+            };
+            let top = handle.center().y - rows.saturating_sub(1) as f32 * spacing / 2.0;
+            for row in 0..rows {
+                for column in [-0.5, 0.5] {
+                    ui.painter().circle_filled(
+                        Pos2::new(
+                            handle.center().x + column * spacing,
+                            top + row as f32 * spacing,
+                        ),
+                        dot_radius,
+                        color,
+                    );
+                }
+            }
+        }
+
+        Some(response)
+    }
+}
@@ -0,0 +1,122 @@
+use egui::{Area, Context, Key, Order, Pos2, Rect, Vec2};
+
+use crate::{MenuItem, SubMenuItem, TitleBar, TitleBarEdge};
+
+impl TitleBar {
+    /// Run a callback when the app icon is clicked
+    ///
+    /// Only the Windows/Linux bar draws an app icon. The callback also runs
+    /// when an icon menu is set, before the menu opens.
+    ///
+    /// # Arguments
+    /// * `callback` - Called when the app icon is clicked
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_on_icon_click(Box::new(|| println!("Icon clicked")))
+    /// # ;
+    /// ```
+    pub fn with_on_icon_click(mut self, callback: Box<dyn Fn() + Send + Sync>) -> Self {
+        self.on_icon_click = Some(callback);
+        self
+    }
+
+    /// Open a menu under the app icon when it is clicked
+    ///
+    /// This is the classic Windows system menu. The menu is drawn like the
+    /// menus of the bar; its label is not shown.
+    ///
+    /// # Arguments
+    /// * `menu` - The menu whose entries are shown under the icon
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::{MenuItem, SubMenuItem};
+    /// # let title_bar = TitleBar::default();
+    /// # fn show_about() {}
+    /// # fn quit() {}
+    /// title_bar.with_icon_menu(
+    ///     MenuItem::new("App")
+    ///         .add_subitem(SubMenuItem::new("About").with_callback(Box::new(|| show_about())))
+    ///         .add_subitem(SubMenuItem::new("Quit").with_callback(Box::new(|| quit()))),
+    /// )
+    /// # ;
+    /// ```
+    pub fn with_icon_menu(mut self, menu: MenuItem) -> Self {
+        self.icon_menu = Some(menu);
+        self
+    }
+
+    /// Whether the app icon menu is open.
+    pub fn is_icon_menu_open(&self) -> bool {
+        self.icon_menu_anchor.is_some()
+    }
+
+    /// React to a click on the app icon: run the callback and toggle the icon menu.
+    pub(crate) fn handle_icon_click(&mut self, icon_rect: Rect) {
+        if let Some(callback) = &self.on_icon_click {
+            callback();
+        }
+        if self.icon_menu.is_none() {
+            return;
+        }
+        if self.icon_menu_anchor.is_some() {
+            self.icon_menu_anchor = None;
+        } else {
+            self.close_all_menus();
+            self.icon_menu_anchor = Some(icon_rect);
+        }
+    }
+
+    /// Draw the app icon menu under the icon, if it is open.
+    pub(crate) fn render_icon_menu(&mut self, ctx: &Context) {
+        let (Some(icon_rect), Some(menu)) = (self.icon_menu_anchor, &self.icon_menu) else {
+            return;
+        };
+
+        let bar_rect = self.bar_rect_or_default(ctx);
+        let open_upward = self.position == TitleBarEdge::Bottom;
+        let position = Pos2::new(
+            icon_rect.min.x,
+            if open_upward {
+                bar_rect.min.y
+            } else {
+                bar_rect.max.y
+            },
+        );
+        let menu_id = self.id.with("icon_menu");
+        let popup = self.popup_menu(menu, menu_id);
+        let text_widths = &mut self.text_widths;
+
+        let response = Area::new(menu_id.with("overlay"))
+            .fixed_pos(Pos2::ZERO)
+            .default_size(Vec2::ZERO)
+            .order(Order::Foreground)
+            .show(ctx, |ui| popup.show_in(ui, position, text_widths))
+            .inner;
+
+        if let Some(path) = &response.activated
+            && let Some(menu) = &self.icon_menu
+            && let Some(item) = SubMenuItem::at_path(&menu.subitems, path)
+        {
+            self.send_menu_message(item);
+            self.send_menu_action(menu, path);
+            self.queue_state_callback(item);
+        }
+
+        // Close on a chosen item, Escape, or a click away from the menu and icon
+        let clicked_away = response.clicked_elsewhere
+            && ctx
+                .input(|i| i.pointer.interact_pos())
+                .is_some_and(|pos| !icon_rect.contains(pos));
+        if response.activated.is_some() || clicked_away || ctx.input(|i| i.key_pressed(Key::Escape))
+        {
+            self.icon_menu_anchor = None;
+        }
+    }
+}
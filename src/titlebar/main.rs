@@ -1,8 +1,41 @@
-use egui::{Color32, Id, ImageSource, Painter};
+use egui::load::SizedTexture;
+use egui::{Color32, Context, Id, ImageSource, Painter, Pos2, Shadow};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
 
 use crate::TitleBarOptions;
-use crate::menu::items::MenuItem;
-use crate::theme::{ThemeMode, ThemeProvider, TitleBarTheme, detect_system_dark_mode};
+#[cfg(feature = "global-shortcuts")]
+use crate::menu::global_shortcuts::GlobalShortcuts;
+use crate::menu::items::{LabelResolverFn, MenuAction, MenuItem};
+#[cfg(target_os = "macos")]
+use crate::menu::native_macos::NativeMenuState;
+use crate::menu::text_cache::TextWidthCache;
+use crate::theme::{ColorScheme, ThemeMode, ThemeProvider, TitleBarTheme, detect_system_dark_mode};
+use crate::titlebar::center_content::CenterContentFn;
+use crate::titlebar::control_buttons::ControlButtonLayout;
+use crate::titlebar::control_buttons::{CloseRequestFn, MinimizeFn};
+use crate::titlebar::search::TitleBarSearch;
+use crate::titlebar::snap::SnapDrag;
+use crate::titlebar::system_menu::{OpenSystemMenu, SystemMenu, WindowKeyMode};
+use crate::titlebar::tabs::TitleBarTabs;
+use crate::titlebar::toolbar::ToolbarFn;
+
+/// How long hover highlights fade in and out unless configured otherwise.
+pub(crate) const DEFAULT_HOVER_ANIMATION: Duration = Duration::from_millis(100);
+
+/// Factor applied to the bar height, menu padding and control sizes in compact mode.
+pub(crate) const COMPACT_SCALE: f32 = 0.8;
+
+/// Soft drop shadow under submenus unless configured otherwise.
+pub(crate) const DEFAULT_SUBMENU_SHADOW: Shadow = Shadow {
+    offset: [0, 4],
+    blur: 12,
+    spread: 0,
+    color: Color32::from_black_alpha(50),
+};
 
 /// Custom icon for the title bar
 pub enum CustomIcon {
@@ -28,6 +61,53 @@ pub enum CustomIcon {
     ),
 }
 
+/// Window edge the title bar is docked to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TitleBarEdge {
+    /// Along the top of the window; submenus open downward.
+    #[default]
+    Top,
+    /// Along the bottom of the window; submenus open upward.
+    Bottom,
+}
+
+/// Platform look of the title bar and its window controls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TitleBarStyle {
+    /// The macOS bar on macOS and the Windows/Linux bar elsewhere.
+    #[default]
+    Auto,
+    /// The macOS bar with traffic lights, on every platform.
+    MacOs,
+    /// The Windows/Linux bar with control buttons, on every platform.
+    Generic,
+}
+
+/// Shape of the arrow marking submenu entries with a side menu.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChevronStyle {
+    /// Two strokes meeting at the tip.
+    #[default]
+    Stroke,
+    /// A filled triangle.
+    Triangle,
+}
+
+/// How the pointer opens the menus in the bar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MenuActivation {
+    /// A click opens a menu; once one is open, hovering another switches to it.
+    #[default]
+    Click,
+    /// Hovering a menu opens it, and it closes shortly after the pointer
+    /// leaves both the menu and the labels.
+    Hover,
+}
+
 /// Configuration for a custom icon button (internal use only).
 pub struct CustomIconButton {
     /// Icon kind to render.
@@ -42,16 +122,46 @@ pub struct CustomIconButton {
     pub callback: Option<Box<dyn Fn() + Send + Sync>>,
     /// Optional keyboard shortcut for this icon.
     pub shortcut: Option<crate::KeyboardShortcut>,
+    /// On/off state for toggle icons, flipped on each click.
+    pub toggle: Option<Arc<AtomicBool>>,
+    /// Override background color while a toggle icon is on.
+    pub active_color: Option<Color32>,
+    /// Whether a separator follows this icon, toward the left of the bar.
+    pub separator_after: bool,
+}
+
+impl CustomIconButton {
+    /// Whether this is a toggle icon that is currently on.
+    pub fn is_active(&self) -> bool {
+        self.toggle
+            .as_ref()
+            .is_some_and(|state| state.load(Ordering::Relaxed))
+    }
+
+    /// Flip the toggle state, if any, then run the callback.
+    pub(crate) fn activate(&self) {
+        if let Some(state) = &self.toggle {
+            state.fetch_xor(true, Ordering::Relaxed);
+        }
+        if let Some(callback) = &self.callback {
+            callback();
+        }
+    }
 }
 
 /// Title bar state and configuration.
 pub struct TitleBar {
     /// Optional title text.
     pub title: Option<String>,
-    /// Unique egui id for interactions.
+    /// Unique egui id for interactions, derived each frame from `id_salt`
+    /// and the viewport the bar is shown in.
     pub id: Id,
+    /// Base of `id`, set with `with_id()` to tell bars in one window apart.
+    pub(crate) id_salt: Id,
     /// Background color of the bar.
     pub background_color: Color32,
+    /// Background color while the window is unfocused (`None` keeps `background_color`).
+    pub unfocused_background_color: Option<Color32>,
     /// Hover color for window controls.
     pub hover_color: Color32,
     /// Hover color for the close button.
@@ -66,10 +176,16 @@ pub struct TitleBar {
     pub minimize_icon_color: Color32,
     /// Simple menu items (label, optional callback).
     pub menu_items: Vec<(String, Option<Box<dyn Fn() + Send + Sync>>)>,
+    /// Indices of the simple menu items that are disabled.
+    pub disabled_menu_items: Vec<usize>,
     /// Menus with submenus.
     pub menu_items_with_submenus: Vec<MenuItem>,
     /// Index of currently open submenu.
     pub open_submenu: Option<usize>,
+    /// Top-left corners of the menus torn off into floating palettes, by menu index.
+    pub torn_off_menus: BTreeMap<usize, Pos2>,
+    /// Open submenu whose dynamic entries were last generated.
+    pub generated_submenu: Option<usize>,
     /// Time when submenu was opened.
     pub submenu_open_time: Option<f64>,
     /// Guard to prevent immediate close after open in same frame.
@@ -78,14 +194,41 @@ pub struct TitleBar {
     pub last_click_time: f64,
     /// Monotonic id of last click used to open submenu.
     pub last_click_id: usize,
-    /// Cached x positions for submenu alignment.
+    /// Clicks that opened a submenu so far, numbering the next one.
+    pub(crate) submenu_click_counter: usize,
+    /// Cached x positions for submenu alignment (right edges when `rtl` is set).
     pub menu_positions: Vec<f32>,
+    /// Whether the menus go to the macOS menu bar instead of the title bar.
+    pub native_macos_menubar: bool,
+    /// Menus last mirrored into the macOS menu bar.
+    #[cfg(target_os = "macos")]
+    pub(crate) native_menu: NativeMenuState,
+    /// Whether menus open on click or on hover.
+    pub menu_activation: MenuActivation,
+    /// Whether the pointer is over a menu label this frame.
+    pub(crate) pointer_over_menu_label: bool,
+    /// When the pointer left a menu opened by hovering, in egui time.
+    pub(crate) menu_hover_left_at: Option<f64>,
+    /// Whether menus that don't fit in the bar collapse into a "»" menu.
+    pub menu_overflow: bool,
+    /// Top-level indices of the menus collapsed into the "»" menu this frame.
+    pub overflow_menus: Vec<usize>,
+    /// Whether the "»" menu is open.
+    pub overflow_menu_open: bool,
+    /// Start of the "»" button (its right edge when `rtl` is set).
+    pub(crate) overflow_position: Option<f32>,
+    /// Room the bar leaves to the menus, set right before they are laid out.
+    pub(crate) menu_room: Option<f32>,
     /// Custom icon buttons shown on the right.
     pub custom_icons: Vec<CustomIconButton>,
     /// Optional app icon displayed next to the title (Windows/Linux).
     pub app_icon: Option<ImageSource<'static>>,
+    /// App icon texture with the uri and pixel size it was loaded for.
+    pub(crate) app_icon_texture: Option<(String, u32, SizedTexture)>,
     /// Title text color.
     pub title_color: Color32,
+    /// Title text color while the window is unfocused (`None` keeps `title_color`).
+    pub unfocused_title_color: Option<Color32>,
     /// Title font size in points.
     pub title_font_size: f32,
     /// Selected theme mode for rendering.
@@ -96,23 +239,22 @@ pub struct TitleBar {
     pub show_title_on_windows: bool,
     /// Whether to display title on Linux.
     pub show_title_on_linux: bool,
+    /// Window width in points below which the title is hidden.
+    pub title_hide_below_width: f32,
     // Keyboard navigation state
     /// Whether keyboard navigation is active.
     pub keyboard_navigation_active: bool,
     /// Currently selected top-level menu index.
     pub selected_menu_index: Option<usize>,
-    /// Currently selected submenu item index (deprecated; use `submenu_selections`).
-    pub selected_submenu_index: Option<usize>,
     /// Time of last keyboard navigation.
     pub last_keyboard_nav_time: f64,
-    /// When set, force-open child submenu for this subitem index.
-    pub force_open_child_subitem: Option<usize>,
-    /// Currently selected child submenu item (deprecated; use `child_submenu_selections`).
-    pub selected_child_submenu_index: Option<usize>,
-    /// Map submenu index to selected item index.
-    pub submenu_selections: std::collections::HashMap<usize, usize>,
-    /// Map submenu index to selected child index.
-    pub child_submenu_selections: std::collections::HashMap<usize, usize>,
+    /// Letters typed into the open menu to jump to an entry.
+    pub(crate) typeahead_prefix: String,
+    /// Time of the last typeahead keystroke.
+    pub(crate) last_typeahead_time: f64,
+    /// Highlighted entry of the open submenu, followed by the highlighted
+    /// entry of each side menu opened from the keyboard.
+    pub submenu_selection: Vec<usize>,
     /// Menu text color.
     pub menu_text_color: Color32,
     /// Menu text size in points.
@@ -121,6 +263,35 @@ pub struct TitleBar {
     pub menu_hover_color: Color32,
     /// Keyboard selection highlight color.
     pub keyboard_selection_color: Color32,
+    /// Background color of menu count badges.
+    pub menu_badge_color: Color32,
+    /// Text color of menu count badges.
+    pub menu_badge_text_color: Color32,
+    /// Hover time before a side menu opens.
+    pub submenu_open_delay: Duration,
+    /// Time a side menu stays open after the pointer leaves.
+    pub submenu_close_delay: Duration,
+    /// Length of the fade when a menu opens; `None` opens it instantly.
+    pub submenu_animation: Option<Duration>,
+    /// Whether opening menus also slide into place.
+    pub submenu_animation_slide: bool,
+    /// How long hover highlights of menus and window controls fade in and out.
+    pub hover_animation: Duration,
+    /// Whether hover highlights fade and opening menus are animated.
+    pub animations: bool,
+    /// Whether menus are laid out right to left.
+    pub rtl: bool,
+    /// Whether Ctrl/Alt/Cmd shortcuts fire while a text field has focus.
+    pub shortcuts_while_editing: bool,
+    /// Whether key presses that fire a shortcut are hidden from the rest of the UI.
+    pub consume_shortcuts: bool,
+    /// Rows a menu shows before it scrolls; `None` shows every row.
+    pub submenu_max_visible_items: Option<usize>,
+    /// Widest a menu grows before labels are cut; `None` fits the longest label.
+    pub submenu_max_width: Option<f32>,
+    /// OS-level shortcuts registered by this bar, released when it is dropped.
+    #[cfg(feature = "global-shortcuts")]
+    pub(crate) global_shortcuts: GlobalShortcuts,
     // Submenu colors
     /// Submenu background color.
     pub submenu_background_color: Color32,
@@ -138,6 +309,22 @@ pub struct TitleBar {
     pub submenu_border_color: Color32,
     /// Submenu keyboard selection highlight color.
     pub submenu_keyboard_selection_color: Color32,
+    /// Color of submenu separators (`None` uses the submenu border color).
+    pub submenu_separator_color: Option<Color32>,
+    /// Thickness of submenu separators in points, before UI scaling.
+    pub submenu_separator_thickness: f32,
+    /// Color of side-menu arrows (`None` uses the submenu text color).
+    pub submenu_chevron_color: Option<Color32>,
+    /// Height of side-menu arrows in points, before UI scaling (`None` follows the text size).
+    pub submenu_chevron_size: Option<f32>,
+    /// Shape of side-menu arrows.
+    pub submenu_chevron_style: ChevronStyle,
+    /// Drop shadow painted under submenus (`Shadow::NONE` for none).
+    pub submenu_shadow: Shadow,
+    /// Corner radius of submenus in points.
+    pub submenu_corner_radius: u8,
+    /// Minimum width of submenus in points, before UI scaling.
+    pub submenu_min_width: f32,
     // Optional external theme provider
     /// Optional external theme provider.
     pub theme_provider: Option<Box<dyn ThemeProvider + Send + Sync>>,
@@ -150,11 +337,110 @@ pub struct TitleBar {
     pub show_maximize_button: bool,
     /// Whether to show the minimize button.
     pub show_minimize_button: bool,
+    /// Action run by the minimize button instead of minimizing the window.
+    pub on_minimize: Option<Box<MinimizeFn>>,
+    /// Hook asked before the close button closes the window.
+    pub on_close_requested: Option<Box<CloseRequestFn>>,
+    /// Set when the close button already confirmed the close it sent.
+    pub(crate) close_confirmed: bool,
+    /// Callback run when the app icon is clicked.
+    pub on_icon_click: Option<Box<dyn Fn() + Send + Sync>>,
+    /// Menu opened by clicking the app icon.
+    pub icon_menu: Option<MenuItem>,
+    /// Screen rect of the app icon while its menu is open.
+    pub icon_menu_anchor: Option<egui::Rect>,
+    /// Window menu opened with Alt+Space or a right-click on the bar.
+    pub system_menu: Option<SystemMenu>,
+    /// The system menu while it is open.
+    pub(crate) system_menu_open: Option<OpenSystemMenu>,
+    /// Move or resize started from the system menu, driven by the arrow keys.
+    pub(crate) window_key_mode: Option<WindowKeyMode>,
     // Per custom icon animation states (kept aligned with custom_icons)
     /// Per-icon animation state list aligned with `custom_icons`.
     pub icon_animation_states: Vec<IconAnimationState>,
     /// Spacing between custom icons in pixels.
     pub icon_spacing: f32,
+    /// Space on each side of a separator between custom icons, in points.
+    pub icon_separator_spacing: f32,
+    /// Color of the separators between custom icons (`None` uses a faint menu text color).
+    pub icon_separator_color: Option<Color32>,
+    /// Multiplier applied to every bar and menu metric (`1.0` is the default density).
+    pub ui_scale: f32,
+    /// Whether the bar, its menus and window controls are drawn denser.
+    pub compact: bool,
+    /// Height of the bar in points, before UI scaling (`None` uses the platform default).
+    pub bar_height: Option<f32>,
+    /// Screen rect of the bar from the last rendered frame, used to anchor menus.
+    pub bar_rect: Option<egui::Rect>,
+    // Automatic light/dark switching
+    /// Whether `show()` switches color schemes to follow the light/dark mode.
+    pub follow_system_theme: bool,
+    /// Colors used while following the theme and the mode is light.
+    pub light_color_scheme: ColorScheme,
+    /// Colors used while following the theme and the mode is dark.
+    pub dark_color_scheme: ColorScheme,
+    /// Dark mode state the current colors were applied for, if any.
+    pub applied_dark_mode: Option<bool>,
+    /// Optional tab strip shown between the menus and the window controls.
+    pub tabs: Option<TitleBarTabs>,
+    /// Breadcrumb segments shown instead of the title; empty for none.
+    pub breadcrumb: Vec<String>,
+    /// Breadcrumb segment clicked since the last `take_breadcrumb_click()`.
+    pub breadcrumb_clicked: Option<usize>,
+    /// Optional search field shown before the custom icons.
+    pub search: Option<TitleBarSearch>,
+    /// Where the window control buttons are drawn.
+    pub control_button_layout: ControlButtonLayout,
+    /// Window edge the bar is docked to.
+    pub position: TitleBarEdge,
+    /// Platform look of the bar, independent of the OS it runs on.
+    pub style: TitleBarStyle,
+    /// Optional toolbar row drawn next to the bar.
+    pub toolbar: Option<Box<ToolbarFn>>,
+    /// Height of the toolbar row in points, before UI scaling.
+    pub toolbar_height: f32,
+    /// Screen rect of the toolbar row from the last rendered frame.
+    pub toolbar_rect: Option<egui::Rect>,
+    /// Completed fraction of a long operation, drawn as a bar along the bar's inner edge.
+    pub progress: Option<f32>,
+    /// Whether a spinner is shown while `progress` is `None`.
+    pub progress_indeterminate: bool,
+    /// Color of the progress bar and spinner (`None` uses the keyboard selection color).
+    pub progress_color: Option<Color32>,
+    /// Whether the maximize button is reported to Windows for the snap layouts flyout.
+    #[cfg(feature = "snap-layouts")]
+    pub(crate) snap_layouts: bool,
+    /// Optional status message shown next to the window controls.
+    pub status_text: Option<String>,
+    /// Color of the status message (`None` uses a faded menu text color).
+    pub status_text_color: Option<Color32>,
+    /// Optional widgets drawn in the middle of the bar.
+    pub center_content: Option<Box<CenterContentFn>>,
+    /// Width the center content took last frame, used to center it.
+    pub(crate) center_content_width: f32,
+    /// Whether the bar moves the window itself and previews edge snapping.
+    pub snap_assist: bool,
+    /// Snap-assisted drag in progress.
+    pub(crate) snap_drag: Option<SnapDrag>,
+    /// Only area of the bar that drags the window, relative to the bar.
+    pub drag_handle: Option<egui::Rect>,
+    /// Whether a grip is painted in the drag handle.
+    pub show_drag_handle_grip: bool,
+    /// Turns menu labels, used as keys, into the text shown.
+    pub(crate) label_resolver: Option<Arc<LabelResolverFn>>,
+    /// Channel receiving a `MenuAction` for every activated menu entry.
+    pub(crate) action_sender: Option<Sender<MenuAction>>,
+    /// Clickable areas of the bar this frame, where a press never drags the window.
+    pub(crate) no_drag_rects: Vec<egui::Rect>,
+    /// Measured menu label widths, reused across frames.
+    pub(crate) text_widths: TextWidthCache,
+}
+
+impl Default for TitleBar {
+    /// A light title bar without title, menus or icons.
+    fn default() -> Self {
+        Self::new(TitleBarOptions::default())
+    }
 }
 
 impl TitleBar {
@@ -193,7 +479,9 @@ impl TitleBar {
         let title_bar = Self {
             title: options.title,
             id: Id::new("title_bar"),
+            id_salt: Id::new("title_bar"),
             background_color: options.background_color.unwrap_or(theme.background_color),
+            unfocused_background_color: None,
             hover_color: options.hover_color.unwrap_or(theme.hover_color),
             close_hover_color: options.close_hover_color.unwrap_or(theme.close_hover_color),
             close_icon_color: options.close_icon_color.unwrap_or(theme.close_icon_color),
@@ -207,36 +495,67 @@ impl TitleBar {
                 .minimize_icon_color
                 .unwrap_or(theme.minimize_icon_color),
             menu_items: Vec::new(),
+            disabled_menu_items: Vec::new(),
             menu_items_with_submenus: Vec::new(),
+            torn_off_menus: BTreeMap::new(),
             open_submenu: None,
+            generated_submenu: None,
             submenu_open_time: None,
             submenu_just_opened_frame: false,
             last_click_time: 0.0,
             last_click_id: 0,
+            submenu_click_counter: 0,
             menu_positions: Vec::new(),
+            native_macos_menubar: false,
+            #[cfg(target_os = "macos")]
+            native_menu: NativeMenuState::default(),
+            menu_activation: MenuActivation::default(),
+            pointer_over_menu_label: false,
+            menu_hover_left_at: None,
+            menu_overflow: false,
+            overflow_menus: Vec::new(),
+            overflow_menu_open: false,
+            overflow_position: None,
+            menu_room: None,
             custom_icons: Vec::new(),
             app_icon: options.app_icon,
+            app_icon_texture: None,
             // Initialize keyboard navigation state
             keyboard_navigation_active: false,
             selected_menu_index: None,
-            selected_submenu_index: None,
             last_keyboard_nav_time: 0.0,
-            force_open_child_subitem: None,
-            selected_child_submenu_index: None,
-            submenu_selections: std::collections::HashMap::new(),
-            child_submenu_selections: std::collections::HashMap::new(),
+            typeahead_prefix: String::new(),
+            last_typeahead_time: 0.0,
+            submenu_selection: Vec::new(),
             title_color: options.title_color.unwrap_or(theme.title_color),
+            unfocused_title_color: None,
             title_font_size: options.title_font_size.unwrap_or(12.0),
             theme_mode: options.theme_mode,
             show_title_on_macos: options.show_title_on_macos,
             show_title_on_windows: options.show_title_on_windows,
             show_title_on_linux: options.show_title_on_linux,
+            title_hide_below_width: 0.0,
             menu_text_color: options.menu_text_color.unwrap_or(theme.menu_text_color),
             menu_text_size: options.menu_text_size.unwrap_or(theme.menu_text_size),
             menu_hover_color: options.menu_hover_color.unwrap_or(theme.menu_hover_color),
             keyboard_selection_color: options
                 .keyboard_selection_color
                 .unwrap_or(theme.keyboard_selection_color),
+            menu_badge_color: theme.menu_badge_color,
+            menu_badge_text_color: theme.menu_badge_text_color,
+            submenu_open_delay: Duration::ZERO,
+            submenu_close_delay: Duration::ZERO,
+            submenu_animation: None,
+            submenu_animation_slide: false,
+            hover_animation: DEFAULT_HOVER_ANIMATION,
+            animations: true,
+            rtl: false,
+            shortcuts_while_editing: true,
+            consume_shortcuts: true,
+            submenu_max_visible_items: None,
+            submenu_max_width: None,
+            #[cfg(feature = "global-shortcuts")]
+            global_shortcuts: GlobalShortcuts::default(),
             // Submenu colors
             submenu_background_color: theme.submenu_background_color,
             submenu_text_color: theme.submenu_text_color,
@@ -246,6 +565,14 @@ impl TitleBar {
             submenu_shortcut_color: theme.submenu_shortcut_color,
             submenu_border_color: theme.submenu_border_color,
             submenu_keyboard_selection_color: theme.submenu_keyboard_selection_color,
+            submenu_separator_color: None,
+            submenu_separator_thickness: 1.0,
+            submenu_chevron_color: None,
+            submenu_chevron_size: None,
+            submenu_chevron_style: ChevronStyle::default(),
+            submenu_shadow: DEFAULT_SUBMENU_SHADOW,
+            submenu_corner_radius: 4,
+            submenu_min_width: 120.0,
             // Theme provider
             theme_provider: None,
             current_theme_id: None,
@@ -253,12 +580,114 @@ impl TitleBar {
             show_close_button: options.show_close_button.unwrap_or(true),
             show_maximize_button: options.show_maximize_button.unwrap_or(true),
             show_minimize_button: options.show_minimize_button.unwrap_or(true),
+            on_minimize: None,
+            on_close_requested: None,
+            on_icon_click: None,
+            icon_menu: None,
+            icon_menu_anchor: None,
+            system_menu: None,
+            system_menu_open: None,
+            window_key_mode: None,
+            close_confirmed: false,
             icon_animation_states: Vec::new(),
             icon_spacing: options.icon_spacing.unwrap_or(4.0),
+            icon_separator_spacing: 6.0,
+            icon_separator_color: None,
+            ui_scale: options.ui_scale.unwrap_or(1.0),
+            compact: false,
+            bar_height: None,
+            bar_rect: None,
+            follow_system_theme: false,
+            light_color_scheme: ColorScheme::light(),
+            dark_color_scheme: ColorScheme::dark(),
+            applied_dark_mode: None,
+            tabs: None,
+            breadcrumb: Vec::new(),
+            breadcrumb_clicked: None,
+            search: None,
+            control_button_layout: ControlButtonLayout::default(),
+            position: TitleBarEdge::default(),
+            style: TitleBarStyle::default(),
+            toolbar: None,
+            toolbar_height: 32.0,
+            toolbar_rect: None,
+            progress: None,
+            progress_indeterminate: false,
+            progress_color: None,
+            #[cfg(feature = "snap-layouts")]
+            snap_layouts: false,
+            status_text: None,
+            status_text_color: None,
+            center_content: None,
+            center_content_width: 0.0,
+            snap_assist: false,
+            snap_drag: None,
+            drag_handle: None,
+            show_drag_handle_grip: true,
+            label_resolver: None,
+            action_sender: None,
+            no_drag_rects: Vec::new(),
+            text_widths: TextWidthCache::default(),
         };
 
         title_bar
     }
+
+    /// Scale a size in points by the bar's `ui_scale`.
+    ///
+    /// All bar and menu metrics are expressed in points; egui converts them to
+    /// physical pixels with `pixels_per_point`, so they must never be multiplied
+    /// by the display scale here.
+    pub(crate) fn scaled(&self, points: f32) -> f32 {
+        points * self.ui_scale
+    }
+
+    /// Scale a size in points by `ui_scale`, shrunk further in compact mode.
+    pub(crate) fn compacted(&self, points: f32) -> f32 {
+        if self.compact {
+            self.scaled(points) * COMPACT_SCALE
+        } else {
+            self.scaled(points)
+        }
+    }
+
+    /// Duration of hover fades, zero while animations are turned off.
+    pub(crate) fn hover_animation_time(&self) -> Duration {
+        if self.animations {
+            self.hover_animation
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Opacity of the hover highlight of the widget `id`, fading toward `hovered`
+    ///
+    /// egui keeps repainting while the fade runs; a zero `duration` snaps.
+    pub(crate) fn hover_fade(ctx: &Context, id: Id, hovered: bool, duration: Duration) -> f32 {
+        if duration.is_zero() {
+            return if hovered { 1.0 } else { 0.0 };
+        }
+        ctx.animate_bool_with_time(id.with("hover_fade"), hovered, duration.as_secs_f32())
+    }
+
+    /// Whether the bar is drawn the macOS way, as chosen by `style`.
+    pub(crate) fn uses_macos_style(&self) -> bool {
+        match self.style {
+            TitleBarStyle::Auto => cfg!(target_os = "macos"),
+            TitleBarStyle::MacOs => true,
+            TitleBarStyle::Generic => false,
+        }
+    }
+
+    /// Height the bar is drawn at: the explicit height, or the default of
+    /// its style shrunk in compact mode.
+    pub(crate) fn target_bar_height(&self) -> f32 {
+        match self.bar_height {
+            Some(height) => self.scaled(height),
+            None if self.uses_macos_style() => self.compacted(28.0),
+            None => self.compacted(32.0),
+        }
+    }
 }
 
 /// Public animation context passed to animated icon callbacks.
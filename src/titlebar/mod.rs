@@ -1,13 +1,53 @@
 /// Public API for constructing and interacting with the title bar.
 pub mod api;
+/// Colors and sizes shared between title bars.
+pub mod appearance;
+/// Clickable breadcrumb path shown in place of the title.
+pub mod breadcrumb;
+/// Custom widgets drawn in the middle of the bar.
+pub mod center_content;
+/// Serializable title bar appearance for loading from files.
+#[cfg(feature = "serde")]
+pub mod config;
 /// Window control icons and drawing helpers.
 pub mod control_buttons;
+/// Restricting window drags to a handle inside the bar.
+pub mod drag_handle;
+/// Callback and menu opened by clicking the app icon.
+pub mod icon_menu;
 /// Core title bar types and data structures.
 pub mod main;
 /// Options and configuration for the title bar.
 pub mod options;
+/// Progress bar and spinner shown in the bar during long operations.
+pub mod progress;
 /// Platform-specific rendering helpers for the title bar.
 pub mod render_bar;
+/// Search field rendered in the title bar.
+pub mod search;
+/// Snap-to-edge assist for windows dragged by the bar.
+pub mod snap;
+/// Windows 11 snap layouts flyout for the maximize button.
+#[cfg(feature = "snap-layouts")]
+pub mod snap_layouts;
+/// Short status message shown next to the window controls.
+pub mod status;
+/// Alt+Space window menu moving and resizing the window from the keyboard.
+pub mod system_menu;
+/// Browser-style tab strip rendered in the title bar.
+pub mod tabs;
+/// Optional widget row drawn next to the title bar.
+pub mod toolbar;
 
+pub use appearance::TitleBarAppearance;
+pub use center_content::CenterContentFn;
+#[cfg(feature = "serde")]
+pub use config::TitleBarConfig;
+pub use control_buttons::{CloseRequestFn, ControlButtonLayout, MinimizeFn};
 pub use main::*;
 pub use options::*;
+pub use search::{SearchEvent, TitleBarSearch};
+pub use snap::SnapZone;
+pub use system_menu::SystemMenu;
+pub use tabs::{Tab, TabEvent, TitleBarTabs};
+pub use toolbar::ToolbarFn;
@@ -51,6 +51,8 @@ pub struct TitleBarOptions {
     pub show_minimize_button: Option<bool>,
     /// Spacing between custom icons in pixels.
     pub icon_spacing: Option<f32>,
+    /// Multiplier for bar and menu metrics.
+    pub ui_scale: Option<f32>,
 }
 
 impl Default for TitleBarOptions {
@@ -79,6 +81,7 @@ impl Default for TitleBarOptions {
             show_maximize_button: None,
             show_minimize_button: None,
             icon_spacing: None,
+            ui_scale: None,
         }
     }
 }
@@ -225,4 +228,10 @@ impl TitleBarOptions {
         self.icon_spacing = Some(spacing);
         self
     }
+
+    /// Set a multiplier for bar and menu metrics (see `TitleBar::with_ui_scale`).
+    pub fn with_ui_scale(mut self, scale: f32) -> Self {
+        self.ui_scale = Some(scale);
+        self
+    }
 }
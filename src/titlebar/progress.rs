@@ -0,0 +1,128 @@
+use std::f32::consts::TAU;
+
+use egui::{Color32, Pos2, Rect, Sense, Shape, Stroke, Ui, Vec2};
+
+use crate::{TitleBar, TitleBarEdge};
+
+impl TitleBar {
+    /// Show the progress of a long operation in the bar
+    ///
+    /// `Some(fraction)` draws a slim bar along the edge of the title bar
+    /// facing the window content, filled from 0.0 to 1.0. `None` hides it,
+    /// or shows a spinner when `with_progress_indeterminate(true)` is set.
+    ///
+    /// # Arguments
+    /// * `progress` - Completed fraction between 0.0 and 1.0, or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_progress(Some(0.25))
+    /// # ;
+    /// ```
+    pub fn with_progress(mut self, progress: Option<f32>) -> Self {
+        self.set_progress(progress);
+        self
+    }
+
+    /// Update the progress shown in the bar, e.g. once per frame.
+    pub fn set_progress(&mut self, progress: Option<f32>) {
+        self.progress = progress.map(|fraction| fraction.clamp(0.0, 1.0));
+    }
+
+    /// Set whether a spinner is shown while no progress fraction is set (default `false`).
+    pub fn with_progress_indeterminate(mut self, indeterminate: bool) -> Self {
+        self.progress_indeterminate = indeterminate;
+        self
+    }
+
+    /// Show or hide the spinner used while no progress fraction is set.
+    pub fn set_progress_indeterminate(&mut self, indeterminate: bool) {
+        self.progress_indeterminate = indeterminate;
+    }
+
+    /// Set the color of the progress bar and spinner (defaults to the keyboard selection color).
+    pub fn with_progress_color(mut self, color: Color32) -> Self {
+        self.progress_color = Some(color);
+        self
+    }
+
+    fn progress_color_or_default(&self) -> Color32 {
+        self.progress_color
+            .unwrap_or(self.submenu_keyboard_selection_color)
+    }
+
+    /// Whether the spinner is showing.
+    fn shows_spinner(&self) -> bool {
+        self.progress.is_none() && self.progress_indeterminate
+    }
+
+    /// Width the spinner takes in the right-hand cluster, if it is showing.
+    pub(crate) fn progress_spinner_width(&self) -> f32 {
+        if self.shows_spinner() {
+            self.scaled(16.0) + self.scaled(8.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Render the spinner in a right-to-left layout, if it is showing.
+    pub(crate) fn render_progress_spinner(&self, ui: &mut Ui) {
+        if !self.shows_spinner() {
+            return;
+        }
+        ui.add_space(self.scaled(8.0));
+        let size = self.scaled(16.0);
+        let (rect, _) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
+
+        // An arc that turns while growing and shrinking
+        let time = ui.input(|i| i.time) as f32;
+        let start = time * TAU;
+        let sweep = TAU * 2.0 / 3.0 * time.sin().abs().max(0.1);
+        let radius = size / 2.0 - self.scaled(1.0);
+        let points = (0..=24)
+            .map(|i| {
+                let angle = start + sweep * i as f32 / 24.0;
+                rect.center() + radius * Vec2::angled(angle)
+            })
+            .collect();
+        ui.painter().add(Shape::line(
+            points,
+            Stroke::new(self.scaled(2.0), self.progress_color_or_default()),
+        ));
+        ui.ctx().request_repaint();
+    }
+
+    /// Paint the progress bar along the edge of the bar facing the content.
+    pub(crate) fn render_progress(&self, ui: &Ui, title_bar_rect: Rect) {
+        let Some(fraction) = self.progress else {
+            return;
+        };
+        let height = self.scaled(2.0);
+        let y = match self.position {
+            TitleBarEdge::Top => title_bar_rect.max.y - height,
+            TitleBarEdge::Bottom => title_bar_rect.min.y,
+        };
+        let track = Rect::from_min_size(
+            Pos2::new(title_bar_rect.min.x, y),
+            Vec2::new(title_bar_rect.width(), height),
+        );
+        let filled = if self.rtl {
+            Rect::from_min_max(
+                Pos2::new(track.max.x - track.width() * fraction, track.min.y),
+                track.max,
+            )
+        } else {
+            Rect::from_min_max(
+                track.min,
+                Pos2::new(track.min.x + track.width() * fraction, track.max.y),
+            )
+        };
+        let color = self.progress_color_or_default();
+        ui.painter()
+            .rect_filled(track, 0.0, color.gamma_multiply(0.25));
+        ui.painter().rect_filled(filled, 0.0, color);
+    }
+}
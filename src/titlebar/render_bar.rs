@@ -1,9 +1,17 @@
+use std::sync::Arc;
+
+use egui::text::{LayoutJob, TextWrapping};
 use egui::{
-    Align, Align2, Color32, Context, FontId, Frame, Image, Layout, Margin, PointerButton, Pos2,
-    Rect, Sense, TextStyle, TopBottomPanel, Vec2, ViewportCommand,
+    Align, Color32, Context, CornerRadius, FontId, Frame, Galley, Image, Layout, Margin,
+    PointerButton, Pos2, Rect, Response, Sense, TextStyle, TopBottomPanel, Ui, UiBuilder, Vec2,
+    ViewportCommand,
 };
 
-use crate::{titlebar::control_buttons::WindowControlIcon, TitleBar};
+use crate::menu::state_callbacks::StateCallbacks;
+use crate::{
+    titlebar::control_buttons::{ControlButtonLayout, WindowControlIcon},
+    TitleBar, TitleBarEdge,
+};
 
 impl TitleBar {
     /// Display the title bar in the egui context
@@ -13,6 +21,11 @@ impl TitleBar {
     /// - macOS: Uses native traffic light buttons
     /// - Windows/Linux: Uses generic window control buttons
     ///
+    /// `with_style` forces either look on every platform.
+    ///
+    /// Every id of the bar is namespaced with `ctx.viewport_id()`, so title
+    /// bars in different windows never share widget ids or menu state.
+    ///
     /// # Arguments
     /// * `ctx` - The egui context
     ///
@@ -28,14 +41,117 @@ impl TitleBar {
     /// }
     /// ```
     pub fn show(&mut self, ctx: &Context) {
-        #[cfg(target_os = "macos")]
-        {
+        self.id = self.id_salt.with(ctx.viewport_id());
+        self.update_followed_theme(ctx);
+        self.handle_system_menu_keys(ctx);
+
+        if self.uses_macos_style() {
             self.render_macos_title_bar(ctx);
+        } else {
+            self.render_generic_title_bar(ctx);
         }
+        self.render_system_menu(ctx);
 
-        #[cfg(not(target_os = "macos"))]
-        {
-            self.render_generic_title_bar(ctx);
+        self.render_toolbar(ctx);
+
+        if self.snap_assist {
+            self.render_snap_preview(ctx);
+        }
+    }
+
+    /// Display the title bar and hand `state` to the menu items activated this frame
+    ///
+    /// Like `show()`, then runs the callbacks queued on `callbacks` by
+    /// `SubMenuItem::with_state_callback()` of every item clicked, chosen
+    /// with the keyboard or triggered by its shortcut, in that order.
+    ///
+    /// # Arguments
+    /// * `ctx` - The egui context
+    /// * `callbacks` - Queue the items' state callbacks were set on
+    /// * `state` - App state given to the callbacks
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// fn update(&mut self, ctx: &Context, frame: &mut eframe::Frame) {
+    ///     // The bar, the handle and the state must be separate fields of the app
+    ///     self.title_bar.show_with(ctx, &self.callbacks, &mut self.document);
+    /// }
+    /// ```
+    pub fn show_with<S: 'static>(
+        &mut self,
+        ctx: &Context,
+        callbacks: &StateCallbacks<S>,
+        state: &mut S,
+    ) {
+        self.show(ctx);
+        callbacks.run(state);
+    }
+
+    /// Display the title bar inside a secondary viewport
+    ///
+    /// Same as `show()`, which namespaces every id of the bar with
+    /// `ctx.viewport_id()`, so title bars in different windows never share
+    /// widget ids or menu state. Call it from the viewport's own callback,
+    /// with one persistent `TitleBar` per window.
+    ///
+    /// # Arguments
+    /// * `ctx` - The egui context of the viewport
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use egui::{CentralPanel, Context, ViewportBuilder, ViewportId};
+    /// # use egui_desktop::TitleBar;
+    /// # use std::sync::{Arc, Mutex};
+    /// # struct App { settings_title_bar: Arc<Mutex<TitleBar>> }
+    /// # impl App {
+    /// # fn update(&mut self, ctx: &Context) {
+    /// let settings_title_bar = self.settings_title_bar.clone();
+    /// ctx.show_viewport_deferred(
+    ///     ViewportId::from_hash_of("settings"),
+    ///     ViewportBuilder::default().with_decorations(false),
+    ///     move |ctx, _class| {
+    ///         settings_title_bar.lock().unwrap().show_in_viewport(ctx);
+    ///
+    ///         CentralPanel::default().show(ctx, |ui| {
+    ///             ui.label("Settings");
+    ///         });
+    ///     },
+    /// );
+    /// # }
+    /// # }
+    /// ```
+    pub fn show_in_viewport(&mut self, ctx: &Context) {
+        self.show(ctx);
+    }
+
+    /// Corner radius for the bar background, matching client-side window rounding.
+    ///
+    /// Only the corners on the docked edge are rounded.
+    fn window_corner_radius(&self, ctx: &Context) -> CornerRadius {
+        let radii = crate::client_side_corner_radii(ctx)
+            .unwrap_or_default()
+            .to_egui();
+        match self.position {
+            TitleBarEdge::Top => CornerRadius {
+                sw: 0,
+                se: 0,
+                ..radii
+            },
+            TitleBarEdge::Bottom => CornerRadius {
+                nw: 0,
+                ne: 0,
+                ..radii
+            },
+        }
+    }
+
+    /// Panel along the edge the bar is docked to.
+    fn panel(&self) -> TopBottomPanel {
+        match self.position {
+            TitleBarEdge::Top => TopBottomPanel::top(self.id),
+            TitleBarEdge::Bottom => TopBottomPanel::bottom(self.id),
         }
     }
 
@@ -46,13 +162,15 @@ impl TitleBar {
             return;
         }
 
-        TopBottomPanel::top(self.id)
-            .exact_height(28.0)
+        let title_color = self.current_title_color(ctx);
+        self.panel()
+            .exact_height(self.target_bar_height())
             .frame(
                 Frame::new()
-                    .fill(self.background_color)
+                    .fill(self.current_background_color(ctx))
                     .inner_margin(Margin::same(0))
-                    .outer_margin(Margin::same(0)),
+                    .outer_margin(Margin::same(0))
+                    .corner_radius(self.window_corner_radius(ctx)),
             )
             .show(ctx, |ui| {
                 let title_bar_rect = ui.available_rect_before_wrap();
@@ -60,83 +178,157 @@ impl TitleBar {
                 if title_bar_rect.width() <= 0.0 || title_bar_rect.height() <= 0.0 {
                     return;
                 }
+                self.bar_rect = Some(title_bar_rect);
 
-                let title_bar_response =
-                    ui.interact(title_bar_rect, self.id, Sense::click_and_drag());
+                self.no_drag_rects.clear();
+                let title_bar_response = ui.interact(title_bar_rect, self.id, self.bar_sense());
+                let drag_response = self
+                    .render_drag_handle(ui, title_bar_rect)
+                    .unwrap_or_else(|| title_bar_response.clone());
 
-                if title_bar_response.drag_started_by(PointerButton::Primary) {
-                    ctx.send_viewport_cmd(ViewportCommand::StartDrag);
-                }
-
-                if title_bar_response.double_clicked() {
+                if title_bar_response.double_clicked() || drag_response.double_clicked() {
                     let is_maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
                     ctx.send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
                 }
 
+                let side = self.control_button_side(ControlButtonLayout::Left);
+                let controls_rect = self.controls_rect(
+                    side,
+                    title_bar_rect,
+                    self.traffic_lights_width(),
+                    self.scaled(8.0),
+                );
+                let right_items_width = self.custom_icons_width()
+                    + self.search_box_width()
+                    + self.progress_spinner_width()
+                    + self.scaled(8.0);
+                let right_reserved = right_items_width
+                    + self.controls_reserved_width(side, title_bar_rect, controls_rect);
+                // The status text goes first when the bar gets narrow
+                let menus_width = self.menu_bar_width(ui, title_bar_rect.width() - right_reserved);
+                let status_width = self.status_text_width(
+                    ui,
+                    title_bar_rect.width(),
+                    title_bar_rect.width() - right_reserved - menus_width,
+                );
+                let right_items_width = right_items_width + status_width;
+                let right_reserved = right_reserved + status_width;
+
+                // Right edge of the menus, where the title may start, and left
+                // edge of the right-hand cluster
+                let mut menus_end = title_bar_rect.min.x;
+                let mut cluster_start = title_bar_rect.max.x;
                 ui.horizontal(|ui| {
                     ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                        ui.add_space(8.0);
-
-                        let close_response = self
-                            .render_traffic_light(ui, Color32::from_rgb(255, 95, 87), 12.0)
-                            .on_hover_text("Close");
-
-                        if close_response.clicked() {
-                            ctx.send_viewport_cmd(ViewportCommand::Close);
+                        ui.add_space(self.scaled(8.0));
+                        if side == ControlButtonLayout::Left {
+                            ui.add_space(controls_rect.width() + self.scaled(16.0));
                         }
 
-                        ui.add_space(6.0);
-
-                        let minimize_response = self
-                            .render_traffic_light(ui, Color32::from_rgb(255, 189, 46), 12.0)
-                            .on_hover_text("Minimize");
-
-                        if minimize_response.clicked() {
-                            ctx.send_viewport_cmd(ViewportCommand::Minimized(true));
-                        }
-
-                        ui.add_space(6.0);
-
-                        let is_maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
-                        let maximize_response = self
-                            .render_traffic_light(ui, Color32::from_rgb(40, 201, 55), 12.0)
-                            .on_hover_text(if is_maximized { "Restore" } else { "Maximize" });
-
-                        if maximize_response.clicked() {
-                            ctx.send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
-                        }
-
-                        ui.add_space(16.0);
-
+                        self.menu_room = Some(ui.available_width() - right_reserved);
                         self.render_menu_items(ui, ctx);
+                        self.render_tabs(ui, right_reserved);
+                        menus_end = ui.cursor().min.x;
                     });
 
                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if side == ControlButtonLayout::Right {
+                            ui.add_space(controls_rect.width() + self.scaled(8.0));
+                        }
+                        self.render_status_text(ui, status_width);
                         self.render_custom_icons(ui);
-                        ui.add_space(8.0);
+                        ui.add_space(self.scaled(8.0));
+                        self.render_search_box(ui);
+                        self.render_progress_spinner(ui);
+                        cluster_start = ui.available_rect_before_wrap().max.x;
                     });
                 });
 
-                if let Some(ref title_text) = self.title {
-                    if self.should_show_title() {
-                        let font = TextStyle::Body.resolve(ui.style());
-                        let galley = ui.fonts_mut(|f| {
-                            f.layout_no_wrap(title_text.clone(), font, self.title_color)
-                        });
-
-                        let center_x = title_bar_rect.center().x;
-                        let center_y = title_bar_rect.min.y + 14.0;
-
-                        let title_pos = Pos2::new(
-                            center_x - galley.size().x / 2.0,
-                            center_y - galley.size().y / 2.0,
-                        );
-
-                        ui.painter().galley(title_pos, galley, self.title_color);
+                let mut controls_ui = ui.new_child(
+                    UiBuilder::new()
+                        .max_rect(controls_rect)
+                        .layout(Layout::left_to_right(Align::Center)),
+                );
+                self.render_traffic_lights(&mut controls_ui, ctx);
+                self.render_progress(ui, title_bar_rect);
+
+                let gap = self.scaled(8.0);
+                self.render_center_content(
+                    ui,
+                    title_bar_rect,
+                    self.center_content_start(side, menus_end, controls_rect) + gap,
+                    cluster_start - gap,
+                );
+
+                // Tabs and center content take the place of the centered title
+                let has_tabs = self.tabs.as_ref().is_some_and(|tabs| !tabs.tabs.is_empty())
+                    || self.has_center_content();
+                // Centered controls push the title to their right
+                let title_start = (side == ControlButtonLayout::Center)
+                    .then(|| controls_rect.max.x + self.scaled(16.0));
+                if self.has_breadcrumb()
+                    && self.title_visible_at(title_bar_rect.width())
+                    && !has_tabs
+                {
+                    let mut font = TextStyle::Body.resolve(ui.style());
+                    font.size = self.scaled(font.size);
+                    let max_width = match title_start {
+                        Some(start) => title_bar_rect.max.x - start - right_items_width,
+                        None => title_bar_rect.width() * 0.5,
+                    }
+                    .max(0.0);
+                    let width = self.breadcrumb_width(ui, &font, max_width);
+                    self.render_breadcrumb(
+                        ui,
+                        Pos2::new(
+                            title_start.unwrap_or(title_bar_rect.center().x - width / 2.0),
+                            title_bar_rect.center().y,
+                        ),
+                        &font,
+                        max_width,
+                    );
+                } else if let Some(ref title_text) = self.title
+                    && self.title_visible_at(title_bar_rect.width())
+                    && !has_tabs
+                {
+                    let mut font = TextStyle::Body.resolve(ui.style());
+                    font.size = self.scaled(font.size);
+
+                    let center_x = title_bar_rect.center().x;
+                    let center_y = title_bar_rect.center().y;
+
+                    // Keep clear of the menus and the right-hand cluster
+                    let gap = self.scaled(8.0);
+                    let right_limit = title_bar_rect.max.x - right_reserved;
+                    let max_width = match title_start {
+                        Some(start) => right_limit - start.max(menus_end) - gap,
+                        None => (center_x - menus_end).min(right_limit - center_x) * 2.0 - gap,
+                    };
+                    let galley =
+                        Self::truncated_galley(ui, title_text, font, title_color, max_width);
+
+                    let title_pos = Pos2::new(
+                        title_start.unwrap_or(center_x - galley.size().x / 2.0),
+                        center_y - galley.size().y / 2.0,
+                    );
+
+                    if galley.elided {
+                        ui.interact(
+                            Rect::from_min_size(title_pos, galley.size()),
+                            self.id.with("title"),
+                            Sense::hover(),
+                        )
+                        .on_hover_text(title_text);
                     }
+                    ui.painter().galley(title_pos, galley, title_color);
                 }
+
+                self.exclude_from_drag(controls_rect);
+                self.start_drag_outside_widgets(ctx, &drag_response);
+                self.open_system_menu_on_right_click(ctx, &title_bar_response);
             });
 
+        self.render_torn_off_menus(ctx);
         self.render_open_submenu(ctx);
     }
 
@@ -147,13 +339,15 @@ impl TitleBar {
             return;
         }
 
-        TopBottomPanel::top(self.id)
-            .exact_height(32.0)
+        let title_color = self.current_title_color(ctx);
+        self.panel()
+            .exact_height(self.target_bar_height())
             .frame(
                 Frame::new()
-                    .fill(self.background_color)
+                    .fill(self.current_background_color(ctx))
                     .inner_margin(Margin::same(0))
-                    .outer_margin(Margin::same(0)),
+                    .outer_margin(Margin::same(0))
+                    .corner_radius(self.window_corner_radius(ctx)),
             )
             .show(ctx, |ui| {
                 let title_bar_rect = ui.available_rect_before_wrap();
@@ -161,28 +355,55 @@ impl TitleBar {
                 if title_bar_rect.width() <= 0.0 || title_bar_rect.height() <= 0.0 {
                     return;
                 }
+                self.bar_rect = Some(title_bar_rect);
 
-                let title_bar_response =
-                    ui.interact(title_bar_rect, self.id, Sense::click_and_drag());
-
-                if title_bar_response.drag_started_by(PointerButton::Primary) {
-                    ctx.send_viewport_cmd(ViewportCommand::StartDrag);
-                }
+                self.no_drag_rects.clear();
+                let title_bar_response = ui.interact(title_bar_rect, self.id, self.bar_sense());
+                let drag_response = self
+                    .render_drag_handle(ui, title_bar_rect)
+                    .unwrap_or_else(|| title_bar_response.clone());
 
-                if title_bar_response.double_clicked() {
+                if title_bar_response.double_clicked() || drag_response.double_clicked() {
                     let is_maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
                     ctx.send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
                 }
 
+                let side = self.control_button_side(ControlButtonLayout::Right);
+                let controls_rect =
+                    self.controls_rect(side, title_bar_rect, self.compacted(46.0) * 3.0, 0.0);
+                let right_reserved = self.custom_icons_width()
+                    + self.search_box_width()
+                    + self.progress_spinner_width()
+                    + self.controls_reserved_width(side, title_bar_rect, controls_rect);
+                // The status text goes first when the bar gets narrow
+                // The app icon takes the first 32 points
+                let icon_width = self.scaled(32.0);
+                let menus_width =
+                    self.menu_bar_width(ui, title_bar_rect.width() - right_reserved - icon_width);
+                let status_width = self.status_text_width(
+                    ui,
+                    title_bar_rect.width(),
+                    title_bar_rect.width() - right_reserved - menus_width - icon_width,
+                );
+                let right_reserved = right_reserved + status_width;
+
+                // Room left between the menus and the right-hand cluster
+                let mut menus_end = title_bar_rect.min.x;
+                let mut cluster_start = title_bar_rect.max.x;
                 ui.horizontal(|ui| {
                     ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                        let icon_size = 20.0;
-                        let title_bar_height = 32.0;
-                        let icon_center_y = title_bar_height / 2.0;
+                        if side == ControlButtonLayout::Left {
+                            ui.add_space(controls_rect.width());
+                        }
+                        let icon_size = self.scaled(20.0);
+                        let icon_center_y = title_bar_rect.center().y;
+                        let menus_width = self
+                            .menu_bar_width(ui, ui.available_width() - right_reserved - icon_width);
+                        let title_font = FontId::proportional(self.scaled(self.title_font_size));
 
                         let icon_response = ui.allocate_rect(
                             Rect::from_center_size(
-                                Pos2::new(16.0, icon_center_y),
+                                Pos2::new(ui.cursor().min.x + self.scaled(16.0), icon_center_y),
                                 Vec2::new(icon_size, icon_size),
                             ),
                             Sense::click(),
@@ -190,100 +411,353 @@ impl TitleBar {
 
                         ui.put(
                             icon_response.rect,
-                            Image::new(self.get_app_icon())
+                            Image::new(self.app_icon_image(ctx, icon_size))
                                 .fit_to_exact_size(Vec2::new(icon_size, icon_size)),
                         );
+                        if icon_response.clicked() {
+                            self.handle_icon_click(icon_response.rect);
+                        }
 
-                        if let Some(ref title) = self.title {
-                            if self.should_show_title() {
-                                let title_width = ui.fonts_mut(|f| {
-                                    f.layout_no_wrap(
-                                        title.clone(),
-                                        FontId::proportional(self.title_font_size),
-                                        self.title_color,
-                                    )
-                                    .size()
-                                    .x
-                                }) + 8.0;
-                                let title_response = ui.allocate_response(
-                                    Vec2::new(title_width, 32.0),
-                                    Sense::hover(),
-                                );
-
-                                let painter = ui.painter();
-                                painter.text(
-                                    Pos2::new(title_response.rect.left() + 4.0, icon_center_y),
-                                    Align2::LEFT_CENTER,
-                                    title,
-                                    FontId::proportional(self.title_font_size),
-                                    self.title_color,
-                                );
+                        if self.has_breadcrumb() && self.title_visible_at(title_bar_rect.width()) {
+                            // Leave at least half of the bar to the menus and controls
+                            let max_width =
+                                ((ui.available_width() - right_reserved) * 0.5).max(0.0);
+                            let breadcrumb_width =
+                                self.breadcrumb_width(ui, &title_font, max_width);
+                            let breadcrumb_response = ui.allocate_response(
+                                Vec2::new(
+                                    breadcrumb_width + self.scaled(8.0),
+                                    title_bar_rect.height(),
+                                ),
+                                Sense::hover(),
+                            );
+                            self.render_breadcrumb(
+                                ui,
+                                Pos2::new(
+                                    breadcrumb_response.rect.left() + self.scaled(4.0),
+                                    icon_center_y,
+                                ),
+                                &title_font,
+                                max_width,
+                            );
+                        } else if let Some(ref title) = self.title
+                            && self.title_visible_at(title_bar_rect.width())
+                        {
+                            // Keep clear of the menus that follow and the right-hand cluster
+                            let padding = self.scaled(8.0);
+                            let max_width =
+                                ui.available_width() - right_reserved - menus_width - padding;
+                            let galley = Self::truncated_galley(
+                                ui,
+                                title,
+                                title_font,
+                                title_color,
+                                max_width,
+                            );
+                            let title_response = ui.allocate_response(
+                                Vec2::new(galley.size().x + padding, title_bar_rect.height()),
+                                Sense::hover(),
+                            );
+
+                            let title_pos = Pos2::new(
+                                title_response.rect.left() + self.scaled(4.0),
+                                icon_center_y - galley.size().y / 2.0,
+                            );
+                            if galley.elided {
+                                title_response.on_hover_text(title);
                             }
+                            ui.painter().galley(title_pos, galley, title_color);
                         }
 
+                        self.menu_room = Some(ui.available_width() - right_reserved);
                         self.render_menu_items(ui, ctx);
+                        self.render_tabs(ui, right_reserved);
+                        menus_end = ui.cursor().min.x;
                     });
 
                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                        ui.spacing_mut().item_spacing = Vec2::ZERO;
-
-                        let close_response = self
-                            .render_window_control_button_with_drawn_icon(
-                                ui,
-                                WindowControlIcon::Close,
-                                self.close_hover_color,
-                                self.close_icon_color,
-                                16.0,
-                            )
-                            .on_hover_text("Close");
-
-                        if close_response.clicked() {
-                            ctx.send_viewport_cmd(ViewportCommand::Close);
+                        if side == ControlButtonLayout::Right {
+                            ui.add_space(controls_rect.width());
                         }
-
-                        let is_maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
-
-                        let maximize_response = self
-                            .render_window_control_button_with_drawn_icon(
-                                ui,
-                                if is_maximized {
-                                    WindowControlIcon::Restore
-                                } else {
-                                    WindowControlIcon::Maximize
-                                },
-                                self.hover_color,
-                                if is_maximized {
-                                    self.restore_icon_color
-                                } else {
-                                    self.maximize_icon_color
-                                },
-                                14.0,
-                            )
-                            .on_hover_text(if is_maximized { "Restore" } else { "Maximize" });
-
-                        if maximize_response.clicked() {
-                            ctx.send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
-                        }
-
-                        let minimize_response = self
-                            .render_window_control_button_with_drawn_icon(
-                                ui,
-                                WindowControlIcon::Minimize,
-                                self.hover_color,
-                                self.minimize_icon_color,
-                                14.0,
-                            )
-                            .on_hover_text("Minimize");
-
-                        if minimize_response.clicked() {
-                            ctx.send_viewport_cmd(ViewportCommand::Minimized(true));
-                        }
-
+                        self.render_status_text(ui, status_width);
                         self.render_custom_icons(ui);
+                        self.render_search_box(ui);
+                        self.render_progress_spinner(ui);
+                        cluster_start = ui.available_rect_before_wrap().max.x;
                     });
                 });
+
+                let gap = self.scaled(8.0);
+                self.render_center_content(
+                    ui,
+                    title_bar_rect,
+                    self.center_content_start(side, menus_end, controls_rect) + gap,
+                    cluster_start - gap,
+                );
+
+                let mut controls_ui = ui.new_child(
+                    UiBuilder::new()
+                        .max_rect(controls_rect)
+                        .layout(Layout::right_to_left(Align::Center)),
+                );
+                self.render_generic_controls(&mut controls_ui, ctx);
+                self.render_progress(ui, title_bar_rect);
+
+                self.exclude_from_drag(controls_rect);
+                self.start_drag_outside_widgets(ctx, &drag_response);
+                self.open_system_menu_on_right_click(ctx, &title_bar_response);
             });
 
+        self.render_torn_off_menus(ctx);
         self.render_open_submenu(ctx);
+        self.render_icon_menu(ctx);
+    }
+
+    /// Keep presses in `rect` from dragging the window
+    ///
+    /// egui hands a drag to the bar even when the press lands on a
+    /// click-only widget above it, such as a menu label or window button.
+    pub(crate) fn exclude_from_drag(&mut self, rect: Rect) {
+        self.no_drag_rects.push(rect);
+    }
+
+    /// Start a window drag, unless the press landed on a widget of the bar.
+    ///
+    /// Called once every widget of the bar has registered its area.
+    fn start_drag_outside_widgets(&mut self, ctx: &Context, drag_response: &Response) {
+        if !drag_response.drag_started_by(PointerButton::Primary) {
+            return;
+        }
+        let on_widget = ctx
+            .input(|i| i.pointer.press_origin())
+            .is_some_and(|origin| self.no_drag_rects.iter().any(|rect| rect.contains(origin)));
+        if !on_widget {
+            self.start_window_drag(ctx);
+        }
+    }
+
+    /// Left edge of the room for center content: past the menus, and past
+    /// the window controls when those are centered.
+    fn center_content_start(
+        &self,
+        side: ControlButtonLayout,
+        menus_end: f32,
+        controls_rect: Rect,
+    ) -> f32 {
+        match side {
+            ControlButtonLayout::Center => menus_end.max(controls_rect.max.x),
+            _ => menus_end,
+        }
+    }
+
+    /// Side the window controls are drawn on, with `Platform` resolved to
+    /// `platform_side`.
+    fn control_button_side(&self, platform_side: ControlButtonLayout) -> ControlButtonLayout {
+        match self.control_button_layout {
+            ControlButtonLayout::Platform => platform_side,
+            side => side,
+        }
+    }
+
+    /// Rect of the window control cluster on `side`, `margin` away from the
+    /// bar edge or centered in the bar.
+    fn controls_rect(
+        &self,
+        side: ControlButtonLayout,
+        title_bar_rect: Rect,
+        width: f32,
+        margin: f32,
+    ) -> Rect {
+        let min_x = match side {
+            ControlButtonLayout::Left => title_bar_rect.min.x + margin,
+            ControlButtonLayout::Center => title_bar_rect.center().x - width / 2.0,
+            _ => title_bar_rect.max.x - margin - width,
+        };
+        Rect::from_min_size(
+            Pos2::new(min_x, title_bar_rect.min.y),
+            Vec2::new(width, title_bar_rect.height()),
+        )
+    }
+
+    /// Width the window controls take from the right of content flowing
+    /// left to right.
+    fn controls_reserved_width(
+        &self,
+        side: ControlButtonLayout,
+        title_bar_rect: Rect,
+        controls_rect: Rect,
+    ) -> f32 {
+        match side {
+            ControlButtonLayout::Left => 0.0,
+            _ => title_bar_rect.max.x - controls_rect.min.x,
+        }
+    }
+
+    /// Lay out text on one line, cut with a trailing "…" past `max_width`.
+    ///
+    /// Cut text has `elided` set so callers can show the full text on hover.
+    pub(crate) fn truncated_galley(
+        ui: &Ui,
+        text: &str,
+        font: FontId,
+        color: Color32,
+        max_width: f32,
+    ) -> Arc<Galley> {
+        let mut job = LayoutJob::simple_singleline(text.to_owned(), font, color);
+        job.wrap = TextWrapping::truncate_at_width(max_width.max(0.0));
+        ui.fonts_mut(|f| f.layout_job(job))
+    }
+
+    /// Close the window if the hook set with `with_on_close_requested()` allows it.
+    pub(crate) fn close_window(&mut self, ctx: &Context) {
+        if self.confirm_close() {
+            self.close_confirmed = true;
+            ctx.send_viewport_cmd(ViewportCommand::Close);
+        }
+    }
+
+    /// Minimize the window, or run the callback set with `with_minimize_callback()`.
+    pub(crate) fn minimize_window(&self, ctx: &Context) {
+        match &self.on_minimize {
+            Some(callback) => callback(ctx),
+            None => ctx.send_viewport_cmd(ViewportCommand::Minimized(true)),
+        }
+    }
+
+    /// Width of the three macOS traffic lights and the gaps between them.
+    fn traffic_lights_width(&self) -> f32 {
+        self.compacted(12.0) * 3.0 + self.compacted(6.0) * 2.0
+    }
+
+    /// Draw the macOS traffic lights left to right.
+    fn render_traffic_lights(&mut self, ui: &mut Ui, ctx: &Context) {
+        let light_size = self.compacted(12.0);
+        let light_spacing = self.compacted(6.0);
+
+        // Native traffic lights reveal their symbols together when the
+        // pointer is anywhere over the cluster, not per button.
+        let cluster_rect = Rect::from_min_size(
+            Pos2::new(
+                ui.cursor().min.x,
+                ui.max_rect().center().y - light_size / 2.0,
+            ),
+            Vec2::new(light_size * 3.0 + light_spacing * 2.0, light_size),
+        );
+        let cluster_hovered = ui.rect_contains_pointer(cluster_rect);
+        let symbol = |icon| cluster_hovered.then_some(icon);
+
+        let close_response = self
+            .render_traffic_light_with_symbol(
+                ui,
+                Color32::from_rgb(255, 95, 87),
+                light_size,
+                symbol(WindowControlIcon::Close),
+            )
+            .on_hover_text("Close");
+
+        if close_response.clicked() {
+            self.close_window(ctx);
+        }
+
+        ui.add_space(light_spacing);
+
+        let minimize_response = self
+            .render_traffic_light_with_symbol(
+                ui,
+                Color32::from_rgb(255, 189, 46),
+                light_size,
+                symbol(WindowControlIcon::Minimize),
+            )
+            .on_hover_text("Minimize");
+
+        if minimize_response.clicked() {
+            self.minimize_window(ctx);
+        }
+
+        ui.add_space(light_spacing);
+
+        let is_maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
+        let maximize_response = self
+            .render_traffic_light_with_symbol(
+                ui,
+                Color32::from_rgb(40, 201, 55),
+                light_size,
+                symbol(if is_maximized {
+                    WindowControlIcon::Restore
+                } else {
+                    WindowControlIcon::Maximize
+                }),
+            )
+            .on_hover_text(if is_maximized { "Restore" } else { "Maximize" });
+
+        if maximize_response.clicked() {
+            ctx.send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+        }
+    }
+
+    /// Draw the close, maximize and minimize buttons in a right-to-left layout.
+    fn render_generic_controls(&mut self, ui: &mut Ui, ctx: &Context) {
+        ui.spacing_mut().item_spacing = Vec2::ZERO;
+
+        let close_response = self
+            .render_window_control_button_with_drawn_icon(
+                ui,
+                WindowControlIcon::Close,
+                self.close_hover_color,
+                self.close_icon_color,
+                self.compacted(16.0),
+            )
+            .on_hover_text("Close");
+
+        if close_response.clicked() {
+            self.close_window(ctx);
+        }
+
+        let is_maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
+
+        // With snap layouts, Windows owns the pointer over the maximize button
+        #[cfg(feature = "snap-layouts")]
+        let natively_hovered = self.maximize_button_hovered_natively(ctx);
+        #[cfg(not(feature = "snap-layouts"))]
+        let natively_hovered = false;
+
+        let maximize_response = self
+            .render_window_control_button_hovered(
+                ui,
+                if is_maximized {
+                    WindowControlIcon::Restore
+                } else {
+                    WindowControlIcon::Maximize
+                },
+                self.hover_color,
+                if is_maximized {
+                    self.restore_icon_color
+                } else {
+                    self.maximize_icon_color
+                },
+                self.compacted(14.0),
+                natively_hovered,
+            )
+            .on_hover_text(if is_maximized { "Restore" } else { "Maximize" });
+
+        if maximize_response.clicked() {
+            ctx.send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+        }
+        #[cfg(feature = "snap-layouts")]
+        self.report_maximize_button(ctx, Some(maximize_response.rect));
+
+        let minimize_response = self
+            .render_window_control_button_with_drawn_icon(
+                ui,
+                WindowControlIcon::Minimize,
+                self.hover_color,
+                self.minimize_icon_color,
+                self.compacted(14.0),
+            )
+            .on_hover_text("Minimize");
+
+        if minimize_response.clicked() {
+            self.minimize_window(ctx);
+        }
     }
 }
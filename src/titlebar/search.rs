@@ -0,0 +1,171 @@
+use egui::{Align, FontId, Key, Margin, RichText, TextEdit, Ui, Vec2};
+
+use crate::TitleBar;
+
+/// Something the user did with the title bar search box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchEvent {
+    /// The query was edited; holds the new text.
+    Changed(String),
+    /// Enter was pressed; holds the submitted text.
+    Submitted(String),
+}
+
+/// Search field drawn on the right of the title bar, before the custom icons.
+#[derive(Debug, Clone, Default)]
+pub struct TitleBarSearch {
+    /// Current text of the field.
+    pub query: String,
+    /// Hint shown while the field is empty.
+    pub placeholder: String,
+    /// Width of the field in points, before UI scaling.
+    pub width: f32,
+    /// Focus the field on the next frame.
+    focus_requested: bool,
+    /// Events produced since the last `take_events()` call.
+    events: Vec<SearchEvent>,
+}
+
+impl TitleBarSearch {
+    /// Create an empty search field with a placeholder.
+    pub fn new(placeholder: impl Into<String>) -> Self {
+        Self {
+            placeholder: placeholder.into(),
+            width: 200.0,
+            ..Default::default()
+        }
+    }
+
+    /// Drain the events produced since the last call.
+    pub fn take_events(&mut self) -> Vec<SearchEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+impl TitleBar {
+    /// Show a search field in the title bar
+    ///
+    /// The field is a regular `egui::TextEdit` placed left of the custom
+    /// icons. Pressing on it never drags the window. Edits and Enter are
+    /// reported through `take_search_events()`.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to show the search field
+    /// * `placeholder` - Hint shown while the field is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_search_box(true, "Search commands…")
+    /// # ;
+    /// ```
+    pub fn with_search_box(mut self, enabled: bool, placeholder: impl Into<String>) -> Self {
+        self.search = enabled.then(|| TitleBarSearch::new(placeholder));
+        self
+    }
+
+    /// Current search query, or `None` when the search box is disabled.
+    pub fn search_query(&self) -> Option<&str> {
+        self.search.as_ref().map(|search| search.query.as_str())
+    }
+
+    /// Replace the search query. Does not emit an event.
+    pub fn set_search_query(&mut self, query: impl Into<String>) {
+        if let Some(search) = &mut self.search {
+            search.query = query.into();
+        }
+    }
+
+    /// Empty the search query. Does not emit an event.
+    pub fn clear_search(&mut self) {
+        self.set_search_query(String::new());
+    }
+
+    /// Give keyboard focus to the search box on the next frame.
+    pub fn focus_search(&mut self) {
+        if let Some(search) = &mut self.search {
+            search.focus_requested = true;
+        }
+    }
+
+    /// Drain the search events produced since the last call
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::SearchEvent;
+    /// # let mut title_bar = TitleBar::default();
+    /// # fn filter_commands(_query: &str) {}
+    /// # fn run_command(_query: &str) {}
+    /// for event in title_bar.take_search_events() {
+    ///     match event {
+    ///         SearchEvent::Changed(query) => filter_commands(&query),
+    ///         SearchEvent::Submitted(query) => run_command(&query),
+    ///     }
+    /// }
+    /// ```
+    pub fn take_search_events(&mut self) -> Vec<SearchEvent> {
+        self.search
+            .as_mut()
+            .map(TitleBarSearch::take_events)
+            .unwrap_or_default()
+    }
+
+    /// Width the search box takes, including its gap to the next item.
+    pub(crate) fn search_box_width(&self) -> f32 {
+        self.search
+            .as_ref()
+            .map_or(0.0, |search| self.scaled(search.width) + self.scaled(8.0))
+    }
+
+    /// Render the search box in a right-to-left layout.
+    pub(crate) fn render_search_box(&mut self, ui: &mut Ui) {
+        let bar_height = self
+            .bar_rect
+            .map_or(ui.max_rect().height(), |rect| rect.height());
+        let height = bar_height - self.scaled(8.0);
+        let font = FontId::proportional(self.scaled(self.menu_text_size));
+        let margin = Margin::symmetric(self.scaled(6.0) as i8, 0);
+        let id = self.id.with("search_box");
+        let background = self.menu_hover_color;
+        let text_color = self.title_color;
+        let hint_color = self.menu_text_color.gamma_multiply(0.6);
+        let width = self.search_box_width() - self.scaled(8.0);
+        let Some(search) = &mut self.search else {
+            return;
+        };
+
+        // Widgets registered after the bar's drag area take the press, so
+        // selecting text never starts a window drag
+        let response = ui.add_sized(
+            Vec2::new(width, height),
+            TextEdit::singleline(&mut search.query)
+                .id(id)
+                .hint_text(RichText::new(&search.placeholder).color(hint_color))
+                .font(font)
+                .text_color(text_color)
+                .background_color(background)
+                .margin(margin)
+                .vertical_align(Align::Center),
+        );
+
+        if std::mem::take(&mut search.focus_requested) {
+            response.request_focus();
+        }
+        if response.changed() {
+            search
+                .events
+                .push(SearchEvent::Changed(search.query.clone()));
+        }
+        if response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+            search
+                .events
+                .push(SearchEvent::Submitted(search.query.clone()));
+        }
+
+        ui.add_space(self.scaled(8.0));
+    }
+}
@@ -0,0 +1,183 @@
+use egui::{
+    Color32, Context, CornerRadius, Id, LayerId, Order, Pos2, Rect, Stroke, StrokeKind, Vec2,
+    ViewportCommand,
+};
+
+use crate::TitleBar;
+
+/// Distance in points from a monitor edge that triggers a snap.
+const SNAP_THRESHOLD: f32 = 8.0;
+
+/// Where a dragged window snaps to when released near a monitor edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapZone {
+    /// Fill the whole monitor (top edge).
+    Full,
+    /// Left half.
+    Left,
+    /// Right half.
+    Right,
+    /// Top-left quarter.
+    TopLeft,
+    /// Top-right quarter.
+    TopRight,
+    /// Bottom-left quarter.
+    BottomLeft,
+    /// Bottom-right quarter.
+    BottomRight,
+}
+
+impl SnapZone {
+    /// Zone for a pointer at `pointer` on a monitor of `monitor_size`, both in
+    /// monitor coordinates.
+    pub fn at(pointer: Pos2, monitor_size: Vec2) -> Option<Self> {
+        let left = pointer.x <= SNAP_THRESHOLD;
+        let right = pointer.x >= monitor_size.x - SNAP_THRESHOLD;
+        let top = pointer.y <= SNAP_THRESHOLD;
+        let bottom = pointer.y >= monitor_size.y - SNAP_THRESHOLD;
+        match (left, right, top, bottom) {
+            (true, _, true, _) => Some(Self::TopLeft),
+            (_, true, true, _) => Some(Self::TopRight),
+            (true, _, _, true) => Some(Self::BottomLeft),
+            (_, true, _, true) => Some(Self::BottomRight),
+            (true, _, _, _) => Some(Self::Left),
+            (_, true, _, _) => Some(Self::Right),
+            (_, _, true, _) => Some(Self::Full),
+            _ => None,
+        }
+    }
+
+    /// Outer rect of the window snapped into this zone, in monitor coordinates.
+    pub fn rect(self, monitor_size: Vec2) -> Rect {
+        let half = monitor_size / 2.0;
+        let (min, size) = match self {
+            Self::Full => (Pos2::ZERO, monitor_size),
+            Self::Left => (Pos2::ZERO, Vec2::new(half.x, monitor_size.y)),
+            Self::Right => (Pos2::new(half.x, 0.0), Vec2::new(half.x, monitor_size.y)),
+            Self::TopLeft => (Pos2::ZERO, half),
+            Self::TopRight => (Pos2::new(half.x, 0.0), half),
+            Self::BottomLeft => (Pos2::new(0.0, half.y), half),
+            Self::BottomRight => (half.to_pos2(), half),
+        };
+        Rect::from_min_size(min, size)
+    }
+}
+
+/// A window drag driven by the title bar instead of the compositor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SnapDrag {
+    /// Pointer position inside the window when the drag started.
+    grab_offset: Vec2,
+    /// Zone the window would snap to if released now.
+    zone: Option<SnapZone>,
+}
+
+impl TitleBar {
+    /// Show an Aero-snap-style preview while dragging the window by the bar
+    ///
+    /// `StartDrag` hands the drag to the compositor, which hides the pointer
+    /// from egui. With snap assist the bar moves the window itself instead,
+    /// previews the half, quarter or full-monitor rect when the pointer
+    /// reaches a monitor edge, and applies it on release. Windows already
+    /// snaps natively, so this is off by default.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether the bar drags the window itself and snaps it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_snap_assist(true)
+    /// # ;
+    /// ```
+    pub fn with_snap_assist(mut self, enabled: bool) -> Self {
+        self.snap_assist = enabled;
+        self
+    }
+
+    /// Start moving the window after a press on the bar.
+    pub(crate) fn start_window_drag(&mut self, ctx: &Context) {
+        if !self.snap_assist {
+            ctx.send_viewport_cmd(ViewportCommand::StartDrag);
+            return;
+        }
+        if let Some(pointer) = ctx.input(|i| i.pointer.press_origin()) {
+            self.snap_drag = Some(SnapDrag {
+                grab_offset: pointer.to_vec2(),
+                zone: None,
+            });
+        }
+    }
+
+    /// Move the window along with a snap-assisted drag and preview the snap target
+    ///
+    /// Called by `show()` when snap assist is enabled. Does nothing unless a
+    /// drag was started on the bar. Assumes the monitor origin is at `(0, 0)`,
+    /// since egui only reports the monitor size.
+    pub fn render_snap_preview(&mut self, ctx: &Context) {
+        let Some(mut drag) = self.snap_drag else {
+            return;
+        };
+
+        let (pointer, down, monitor_size, outer_rect, inner_rect) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (
+                i.pointer.latest_pos(),
+                i.pointer.primary_down(),
+                viewport.monitor_size,
+                viewport.outer_rect,
+                viewport.inner_rect,
+            )
+        });
+        let (Some(monitor_size), Some(outer_rect), Some(inner_rect)) =
+            (monitor_size, outer_rect, inner_rect)
+        else {
+            // Without window geometry fall back to the compositor
+            self.snap_drag = None;
+            ctx.send_viewport_cmd(ViewportCommand::StartDrag);
+            return;
+        };
+        // Space taken by native decorations (zero for borderless windows)
+        let decorations = outer_rect.size() - inner_rect.size();
+
+        if !down {
+            self.snap_drag = None;
+            if let Some(zone) = drag.zone {
+                let target = zone.rect(monitor_size);
+                ctx.send_viewport_cmd(ViewportCommand::OuterPosition(target.min));
+                ctx.send_viewport_cmd(ViewportCommand::InnerSize(target.size() - decorations));
+            }
+            return;
+        }
+
+        if let Some(pointer) = pointer {
+            let screen_pointer = inner_rect.min + pointer.to_vec2();
+            let window_offset = inner_rect.min - outer_rect.min;
+            let position = screen_pointer - drag.grab_offset - window_offset;
+            if position != outer_rect.min {
+                ctx.send_viewport_cmd(ViewportCommand::OuterPosition(position));
+            }
+            drag.zone = SnapZone::at(screen_pointer, monitor_size);
+        }
+        self.snap_drag = Some(drag);
+
+        if let Some(zone) = drag.zone {
+            // The preview can only be drawn inside the window, so draw the part
+            // of the target that overlaps it
+            let preview = zone.rect(monitor_size).translate(-inner_rect.min.to_vec2());
+            let painter = ctx.layer_painter(LayerId::new(Order::Tooltip, Id::new("snap_preview")));
+            let radius = CornerRadius::same(self.scaled(8.0).min(255.0) as u8);
+            let accent = Color32::from_rgb(0, 120, 215);
+            painter.rect_filled(preview.shrink(4.0), radius, accent.gamma_multiply(0.25));
+            painter.rect_stroke(
+                preview.shrink(4.0),
+                radius,
+                Stroke::new(2.0, accent.gamma_multiply(0.8)),
+                StrokeKind::Inside,
+            );
+        }
+        ctx.request_repaint();
+    }
+}
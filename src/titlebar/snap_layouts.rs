@@ -0,0 +1,223 @@
+use egui::{Context, Rect};
+
+use crate::TitleBar;
+
+impl TitleBar {
+    /// Show the Windows 11 snap layouts flyout when hovering the maximize button
+    ///
+    /// Windows only opens the flyout for a maximize button it knows about.
+    /// When enabled, the window procedure is subclassed so hit-testing over
+    /// the bar's maximize button answers `HTMAXBUTTON`. The button then
+    /// receives non-client mouse messages instead of egui events: hover is
+    /// tracked by the subclass and a click maximizes or restores natively.
+    ///
+    /// Only the Windows/Linux bar draws a maximize button; on other
+    /// platforms this does nothing.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to report the maximize button to Windows
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_snap_layouts(true)
+    /// # ;
+    /// ```
+    pub fn with_snap_layouts(mut self, enabled: bool) -> Self {
+        self.snap_layouts = enabled;
+        self
+    }
+
+    /// Whether the pointer is over the maximize button, as seen by the subclass.
+    ///
+    /// egui gets no pointer events there once Windows treats it as a button.
+    pub(crate) fn maximize_button_hovered_natively(&self, ctx: &Context) -> bool {
+        self.snap_layouts && platform::maximize_button_hovered(ctx)
+    }
+
+    /// Tell Windows where the maximize button is this frame, or that there is none.
+    pub(crate) fn report_maximize_button(&self, ctx: &Context, rect: Option<Rect>) {
+        if self.snap_layouts {
+            platform::report_maximize_button(ctx, rect);
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::{LazyLock, Mutex};
+    use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, POINT, WPARAM};
+    use windows::Win32::Graphics::Gdi::ScreenToClient;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        TME_LEAVE, TME_NONCLIENT, TRACKMOUSEEVENT, TrackMouseEvent,
+    };
+    use windows::Win32::UI::Shell::{DefSubclassProc, RemoveWindowSubclass, SetWindowSubclass};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        HTMAXBUTTON, IsZoomed, SW_MAXIMIZE, SW_RESTORE, ShowWindow, WM_NCDESTROY, WM_NCHITTEST,
+        WM_NCLBUTTONDBLCLK, WM_NCLBUTTONDOWN, WM_NCLBUTTONUP, WM_NCMOUSELEAVE, WM_NCMOUSEMOVE,
+    };
+
+    /// Identifies our subclass among others installed on the same window.
+    const SUBCLASS_ID: usize = 0x6567_7569;
+
+    /// Maximize button of one window, in physical client coordinates.
+    struct MaximizeButton {
+        rect: Option<[i32; 4]>,
+        hovered: bool,
+        ctx: Context,
+    }
+
+    // Buttons of every subclassed window, keyed by HWND
+    static BUTTONS: LazyLock<Mutex<HashMap<usize, MaximizeButton>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+
+    pub fn maximize_button_hovered(ctx: &Context) -> bool {
+        crate::utils::rounded_corners::viewport_window_ptr(ctx).is_some_and(|hwnd| {
+            BUTTONS
+                .lock()
+                .unwrap()
+                .get(&(hwnd as usize))
+                .is_some_and(|button| button.hovered)
+        })
+    }
+
+    pub fn report_maximize_button(ctx: &Context, rect: Option<Rect>) {
+        let Some(hwnd) = crate::utils::rounded_corners::viewport_window_ptr(ctx) else {
+            return;
+        };
+        let key = hwnd as usize;
+        let pixels_per_point = ctx.pixels_per_point();
+        let rect = rect.map(|rect| {
+            [
+                (rect.min.x * pixels_per_point).round() as i32,
+                (rect.min.y * pixels_per_point).round() as i32,
+                (rect.max.x * pixels_per_point).round() as i32,
+                (rect.max.y * pixels_per_point).round() as i32,
+            ]
+        });
+
+        if let Some(button) = BUTTONS.lock().unwrap().get_mut(&key) {
+            button.rect = rect;
+            button.hovered &= rect.is_some();
+            return;
+        }
+
+        // First report for this window: hook its window procedure
+        let installed =
+            unsafe { SetWindowSubclass(HWND(hwnd), Some(subclass_proc), SUBCLASS_ID, 0) };
+        if !installed.as_bool() {
+            eprintln!("⚠️ Failed to subclass the window for snap layouts");
+            return;
+        }
+        BUTTONS.lock().unwrap().insert(
+            key,
+            MaximizeButton {
+                rect,
+                hovered: false,
+                ctx: ctx.clone(),
+            },
+        );
+    }
+
+    /// Whether the screen point packed in `lparam` lies on the maximize button.
+    fn over_button(hwnd: HWND, lparam: LPARAM) -> bool {
+        let Some(rect) = BUTTONS
+            .lock()
+            .unwrap()
+            .get(&(hwnd.0 as usize))
+            .and_then(|button| button.rect)
+        else {
+            return false;
+        };
+        // Signed coordinates: monitors left of or above the primary are negative
+        let mut point = POINT {
+            x: (lparam.0 & 0xFFFF) as i16 as i32,
+            y: ((lparam.0 >> 16) & 0xFFFF) as i16 as i32,
+        };
+        if !unsafe { ScreenToClient(hwnd, &mut point) }.as_bool() {
+            return false;
+        }
+        point.x >= rect[0] && point.x < rect[2] && point.y >= rect[1] && point.y < rect[3]
+    }
+
+    /// Record the hover state, repainting the bar when it changes.
+    fn set_hovered(hwnd: HWND, hovered: bool) {
+        let ctx = {
+            let mut buttons = BUTTONS.lock().unwrap();
+            let Some(button) = buttons.get_mut(&(hwnd.0 as usize)) else {
+                return;
+            };
+            if button.hovered == hovered {
+                return;
+            }
+            button.hovered = hovered;
+            button.ctx.clone()
+        };
+        ctx.request_repaint();
+    }
+
+    unsafe extern "system" fn subclass_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+        _id: usize,
+        _data: usize,
+    ) -> LRESULT {
+        let on_button = wparam.0 == HTMAXBUTTON as usize;
+        match msg {
+            WM_NCHITTEST => {
+                let over = over_button(hwnd, lparam);
+                set_hovered(hwnd, over);
+                if over {
+                    return LRESULT(HTMAXBUTTON as isize);
+                }
+            }
+            WM_NCMOUSEMOVE if on_button => {
+                // Ask for WM_NCMOUSELEAVE so leaving the window clears the hover
+                let mut track = TRACKMOUSEEVENT {
+                    cbSize: size_of::<TRACKMOUSEEVENT>() as u32,
+                    dwFlags: TME_LEAVE | TME_NONCLIENT,
+                    hwndTrack: hwnd,
+                    dwHoverTime: 0,
+                };
+                let _ = unsafe { TrackMouseEvent(&mut track) };
+            }
+            WM_NCMOUSELEAVE => set_hovered(hwnd, false),
+            // Keep DefWindowProc from drawing and tracking a classic button
+            WM_NCLBUTTONDOWN | WM_NCLBUTTONDBLCLK if on_button => return LRESULT(0),
+            WM_NCLBUTTONUP if on_button => {
+                let command = if unsafe { IsZoomed(hwnd) }.as_bool() {
+                    SW_RESTORE
+                } else {
+                    SW_MAXIMIZE
+                };
+                let _ = unsafe { ShowWindow(hwnd, command) };
+                return LRESULT(0);
+            }
+            // Last message of the window: unhook and forget it, so a new
+            // window reusing the handle gets subclassed again
+            WM_NCDESTROY => {
+                let _ = unsafe { RemoveWindowSubclass(hwnd, Some(subclass_proc), SUBCLASS_ID) };
+                BUTTONS.lock().unwrap().remove(&(hwnd.0 as usize));
+            }
+            _ => {}
+        }
+        unsafe { DefSubclassProc(hwnd, msg, wparam, lparam) }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    use super::*;
+
+    pub fn maximize_button_hovered(_ctx: &Context) -> bool {
+        false
+    }
+
+    pub fn report_maximize_button(_ctx: &Context, _rect: Option<Rect>) {}
+}
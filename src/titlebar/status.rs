@@ -0,0 +1,81 @@
+use egui::{Align2, Color32, FontId, Sense, Ui, Vec2};
+
+use crate::TitleBar;
+
+/// Narrowest room the title keeps before the status text is hidden, in points.
+const MIN_TITLE_ROOM: f32 = 120.0;
+
+impl TitleBar {
+    /// Show a short status message next to the window controls
+    ///
+    /// Meant for messages like "Saved" or "Connected". The text sits on the
+    /// right of the bar, before the window controls and custom icons, and is
+    /// hidden first when the window gets too narrow for it and the title.
+    ///
+    /// # Arguments
+    /// * `text` - The status message, or `None` for none
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_status_text(Some("Saved".to_string()))
+    /// # ;
+    /// ```
+    pub fn with_status_text(mut self, text: Option<String>) -> Self {
+        self.status_text = text;
+        self
+    }
+
+    /// Update the status message, or clear it with `None`.
+    pub fn set_status_text(&mut self, text: Option<String>) {
+        self.status_text = text;
+    }
+
+    /// Set the color of the status text (defaults to a faded menu text color).
+    pub fn with_status_text_color(mut self, color: Color32) -> Self {
+        self.status_text_color = Some(color);
+        self
+    }
+
+    /// Width the status text takes on the right of the bar, or 0 when hidden
+    ///
+    /// `room` is what the menus, icons and controls leave for the title and
+    /// the status together; the status only shows if the title keeps enough
+    /// of it and stays visible itself.
+    pub(crate) fn status_text_width(&mut self, ui: &Ui, bar_width: f32, room: f32) -> f32 {
+        let Some(text) = &self.status_text else {
+            return 0.0;
+        };
+        let font = FontId::proportional(self.scaled(self.menu_text_size));
+        let width = self.text_widths.width(ui, text, &font) + self.scaled(16.0);
+        let fits = room - width >= self.scaled(MIN_TITLE_ROOM);
+        if fits && self.title_visible_at(bar_width - width) {
+            width
+        } else {
+            0.0
+        }
+    }
+
+    /// Render the status text in a right-to-left layout, taking `width` points.
+    pub(crate) fn render_status_text(&self, ui: &mut Ui, width: f32) {
+        let Some(text) = self.status_text.as_ref().filter(|_| width > 0.0) else {
+            return;
+        };
+        let height = self
+            .bar_rect
+            .map_or(ui.max_rect().height(), |rect| rect.height());
+        let (rect, _) = ui.allocate_exact_size(Vec2::new(width, height), Sense::hover());
+        let color = self
+            .status_text_color
+            .unwrap_or(self.menu_text_color.gamma_multiply(0.7));
+        ui.painter().text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            text,
+            FontId::proportional(self.scaled(self.menu_text_size)),
+            color,
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use egui::{Area, Context, Key, Modifiers, Order, Pos2, Rect, Response, Vec2, ViewportCommand};
+
+use crate::menu::navigation::{MenuNavModel, MenuNavigator, NavAction, NavKeys};
+use crate::{KeyboardShortcut, MenuItem, SubMenuItem, TitleBar, TitleBarEdge};
+
+/// Smallest inner size the keyboard resizes a window to, in points.
+const MIN_KEYBOARD_SIZE: f32 = 100.0;
+
+/// Shortcut shown next to Close.
+const CLOSE_SHORTCUT: KeyboardShortcut = KeyboardShortcut {
+    key: Key::F4,
+    modifiers: Modifiers::ALT,
+};
+
+/// Windows-style window menu opened with Alt+Space or a right-click on the bar
+///
+/// Offers Restore, Move, Size, Minimize, Maximize and Close. Move and Size
+/// then let the arrow keys move or resize the window until Enter keeps the
+/// change or Escape undoes it; Right/Down grow the window and Left/Up shrink
+/// it. Keyboard users can so place a borderless window without a mouse.
+///
+/// # Examples
+///
+/// ```rust
+/// # use egui_desktop::{SystemMenu, TitleBar, TitleBarOptions};
+/// let title_bar = TitleBar::new(TitleBarOptions::new())
+///     .with_system_menu(SystemMenu::new().with_step(20.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SystemMenu {
+    /// Points the window moves or grows per arrow key press.
+    pub step: f32,
+    /// Whether a right-click on the bar opens the menu too.
+    pub on_right_click: bool,
+}
+
+impl Default for SystemMenu {
+    fn default() -> Self {
+        Self {
+            step: 10.0,
+            on_right_click: true,
+        }
+    }
+}
+
+impl SystemMenu {
+    /// Create a system menu moving the window 10 points per key press.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the points the window moves or grows per arrow key press (default `10.0`).
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step.max(1.0);
+        self
+    }
+
+    /// Open the menu on a right-click on the bar, besides Alt+Space (default `true`).
+    pub fn with_right_click(mut self, enabled: bool) -> Self {
+        self.on_right_click = enabled;
+        self
+    }
+}
+
+/// An entry of the system menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SystemCommand {
+    Restore,
+    Move,
+    Size,
+    Minimize,
+    Maximize,
+    Close,
+}
+
+impl SystemCommand {
+    /// Every entry, in menu order.
+    const ALL: [Self; 6] = [
+        Self::Restore,
+        Self::Move,
+        Self::Size,
+        Self::Minimize,
+        Self::Maximize,
+        Self::Close,
+    ];
+
+    /// The entry at the start of a popup activation path.
+    fn at(path: &[usize]) -> Option<Self> {
+        path.first()
+            .and_then(|&index| Self::ALL.get(index))
+            .copied()
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Restore => "Restore",
+            Self::Move => "Move",
+            Self::Size => "Size",
+            Self::Minimize => "Minimize",
+            Self::Maximize => "Maximize",
+            Self::Close => "Close",
+        }
+    }
+}
+
+/// The system menu while it is open.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OpenSystemMenu {
+    /// Where the menu hangs from.
+    position: Pos2,
+    /// Keyboard selection within the menu.
+    navigator: MenuNavigator,
+}
+
+/// Moving or resizing the window with the arrow keys.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct WindowKeyMode {
+    /// Resize instead of move.
+    resize: bool,
+    /// Window rect before the first key press, restored by Escape.
+    original: Rect,
+    /// Window rect requested so far.
+    target: Rect,
+}
+
+impl TitleBar {
+    /// Open a window menu with Alt+Space or a right-click on the bar
+    ///
+    /// # Arguments
+    /// * `menu` - Step size and triggers of the menu
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::SystemMenu;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_system_menu(SystemMenu::new())
+    /// # ;
+    /// ```
+    pub fn with_system_menu(mut self, menu: SystemMenu) -> Self {
+        self.system_menu = Some(menu);
+        self
+    }
+
+    /// Whether the system menu is open, or the window is being moved or
+    /// resized with the arrow keys.
+    pub fn is_system_menu_open(&self) -> bool {
+        self.system_menu_open.is_some() || self.window_key_mode.is_some()
+    }
+
+    /// Whether `command` can run on a window in this state.
+    fn system_command_enabled(&self, command: SystemCommand, maximized: bool) -> bool {
+        match command {
+            SystemCommand::Restore => maximized,
+            SystemCommand::Move | SystemCommand::Size => !maximized,
+            SystemCommand::Minimize => self.show_minimize_button,
+            SystemCommand::Maximize => !maximized && self.show_maximize_button,
+            SystemCommand::Close => self.show_close_button,
+        }
+    }
+
+    /// The system menu entries, disabled where they can't run.
+    fn system_menu_items(&self, maximized: bool) -> MenuItem {
+        SystemCommand::ALL
+            .iter()
+            .map(|&command| {
+                let mut entry = SubMenuItem::new(command.label());
+                entry.enabled = self.system_command_enabled(command, maximized);
+                match command {
+                    SystemCommand::Maximize => entry.with_separator(),
+                    SystemCommand::Close => entry.with_shortcut(CLOSE_SHORTCUT),
+                    _ => entry,
+                }
+            })
+            .fold(MenuItem::new("System"), MenuItem::add_subitem)
+    }
+
+    /// Open the system menu at `position`, highlighting its first usable entry.
+    fn open_system_menu(&mut self, position: Pos2, maximized: bool) {
+        self.close_all_menus();
+        let first = SystemCommand::ALL
+            .iter()
+            .position(|&command| self.system_command_enabled(command, maximized))
+            .unwrap_or(0);
+        self.system_menu_open = Some(OpenSystemMenu {
+            position,
+            navigator: MenuNavigator {
+                active: true,
+                selected_menu: Some(0),
+                open_submenu: Some(0),
+                selection: vec![first],
+                just_opened: false,
+            },
+        });
+    }
+
+    /// Open the system menu at the pointer after a right-click on the bar
+    ///
+    /// Clicks on the widgets of the bar are left to them. Call once every
+    /// widget has registered its area.
+    pub(crate) fn open_system_menu_on_right_click(
+        &mut self,
+        ctx: &Context,
+        bar_response: &Response,
+    ) {
+        if !self.system_menu.is_some_and(|menu| menu.on_right_click)
+            || !bar_response.secondary_clicked()
+        {
+            return;
+        }
+        let Some(pointer) = bar_response.interact_pointer_pos() else {
+            return;
+        };
+        if self.no_drag_rects.iter().any(|rect| rect.contains(pointer)) {
+            return;
+        }
+        let maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
+        self.open_system_menu(pointer, maximized);
+    }
+
+    /// Open the system menu on Alt+Space and drive it, and the keyboard
+    /// move or resize it started, from the keyboard.
+    pub(crate) fn handle_system_menu_keys(&mut self, ctx: &Context) {
+        let Some(settings) = self.system_menu else {
+            return;
+        };
+        if self.window_key_mode.is_some() {
+            self.handle_window_key_mode(ctx, settings.step);
+            return;
+        }
+
+        let maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
+        if self.system_menu_open.is_none() {
+            if ctx.input_mut(|i| i.consume_key(Modifiers::ALT, Key::Space)) {
+                let bar_rect = self.bar_rect_or_default(ctx);
+                let x = if self.rtl {
+                    bar_rect.max.x
+                } else {
+                    bar_rect.min.x
+                };
+                let y = if self.position == TitleBarEdge::Bottom {
+                    bar_rect.min.y
+                } else {
+                    bar_rect.max.y
+                };
+                self.open_system_menu(Pos2::new(x, y), maximized);
+            }
+            return;
+        }
+
+        let menu = self.system_menu_items(maximized);
+        let model = MenuNavModel {
+            simple_menus: 0,
+            hidden_menus: Vec::new(),
+            submenus: vec![menu.subitems.iter().map(Self::nav_entry).collect()],
+        };
+        let mut keys = NavKeys::from_context(ctx);
+        keys.activate = false;
+        if self.rtl {
+            std::mem::swap(&mut keys.left, &mut keys.right);
+        }
+        let Some(open) = &mut self.system_menu_open else {
+            return;
+        };
+        let action = open.navigator.update(keys, &model);
+        if !open.navigator.active || open.navigator.open_submenu.is_none() {
+            self.system_menu_open = None;
+        }
+        if let Some(NavAction::Submenu { path, .. }) = action
+            && let Some(command) = SystemCommand::at(&path)
+        {
+            self.run_system_command(ctx, command);
+        }
+    }
+
+    /// Move or resize the window by one step per arrow key press; Enter
+    /// keeps the result, Escape restores the window and a click ends it.
+    fn handle_window_key_mode(&mut self, ctx: &Context, step: f32) {
+        let Some(mut mode) = self.window_key_mode else {
+            return;
+        };
+        let (delta, done, cancel) = ctx.input_mut(|i| {
+            let mut delta = Vec2::ZERO;
+            for (key, direction) in [
+                (Key::ArrowLeft, Vec2::new(-1.0, 0.0)),
+                (Key::ArrowRight, Vec2::new(1.0, 0.0)),
+                (Key::ArrowUp, Vec2::new(0.0, -1.0)),
+                (Key::ArrowDown, Vec2::new(0.0, 1.0)),
+            ] {
+                if i.consume_key(Modifiers::NONE, key) {
+                    delta += direction * step;
+                }
+            }
+            let done = i.consume_key(Modifiers::NONE, Key::Enter) || i.pointer.any_click();
+            (delta, done, i.consume_key(Modifiers::NONE, Key::Escape))
+        });
+
+        if cancel {
+            self.window_key_mode = None;
+            Self::send_window_rect(ctx, mode.resize, mode.original);
+            return;
+        }
+        if delta != Vec2::ZERO {
+            if mode.resize {
+                mode.target.max =
+                    (mode.target.max + delta).max(mode.target.min + Vec2::splat(MIN_KEYBOARD_SIZE));
+            } else {
+                mode.target = mode.target.translate(delta);
+            }
+            Self::send_window_rect(ctx, mode.resize, mode.target);
+        }
+        self.window_key_mode = (!done).then_some(mode);
+    }
+
+    /// Request the outer position of `rect`, or the inner size when resizing.
+    fn send_window_rect(ctx: &Context, resize: bool, rect: Rect) {
+        ctx.send_viewport_cmd(if resize {
+            ViewportCommand::InnerSize(rect.size())
+        } else {
+            ViewportCommand::OuterPosition(rect.min)
+        });
+    }
+
+    /// Run a system menu entry.
+    fn run_system_command(&mut self, ctx: &Context, command: SystemCommand) {
+        match command {
+            SystemCommand::Restore => ctx.send_viewport_cmd(ViewportCommand::Maximized(false)),
+            SystemCommand::Maximize => ctx.send_viewport_cmd(ViewportCommand::Maximized(true)),
+            SystemCommand::Minimize => self.minimize_window(ctx),
+            SystemCommand::Close => self.close_window(ctx),
+            SystemCommand::Move | SystemCommand::Size => {
+                let resize = command == SystemCommand::Size;
+                // Without window geometry, e.g. on Wayland, the window can't be placed
+                let rect = ctx.input(|i| {
+                    let viewport = i.viewport();
+                    if resize {
+                        viewport.inner_rect
+                    } else {
+                        viewport.outer_rect
+                    }
+                });
+                self.window_key_mode = rect.map(|rect| WindowKeyMode {
+                    resize,
+                    original: rect,
+                    target: rect,
+                });
+            }
+        }
+    }
+
+    /// Draw the system menu while it is open and run the entry clicked in it.
+    pub(crate) fn render_system_menu(&mut self, ctx: &Context) {
+        let Some(open) = &self.system_menu_open else {
+            return;
+        };
+        let maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
+        let menu = self.system_menu_items(maximized);
+        let position = open.position;
+        let id = self.id.with("system_menu");
+        let popup = self
+            .popup_menu(&menu, id)
+            .with_keyboard(open.navigator.selection.clone());
+        let text_widths = &mut self.text_widths;
+        let response = Area::new(id.with("overlay"))
+            .fixed_pos(Pos2::ZERO)
+            .default_size(Vec2::ZERO)
+            .order(Order::Foreground)
+            .show(ctx, |ui| popup.show_in(ui, position, text_widths))
+            .inner;
+
+        if let Some(path) = response.activated {
+            self.system_menu_open = None;
+            if let Some(command) = SystemCommand::at(&path) {
+                self.run_system_command(ctx, command);
+            }
+        } else if response.clicked_elsewhere {
+            self.system_menu_open = None;
+        }
+    }
+}
@@ -0,0 +1,358 @@
+use egui::{
+    Align2, CornerRadius, CursorIcon, FontId, PointerButton, Pos2, Rect, Sense, Stroke, Ui, Vec2,
+};
+
+use crate::TitleBar;
+use crate::titlebar::CustomIconButton;
+
+/// A single tab shown in the title bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    /// Application-defined identifier reported in `TabEvent`s.
+    pub id: u64,
+    /// Visible text of the tab.
+    pub label: String,
+    /// Whether the tab shows a close button and closes on middle-click.
+    pub closable: bool,
+}
+
+impl Tab {
+    /// Create a closable tab.
+    pub fn new(id: u64, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            closable: true,
+        }
+    }
+
+    /// Set whether the tab can be closed.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// Something the user did with the title bar tabs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabEvent {
+    /// The tab was clicked and is now the active one.
+    Selected {
+        /// Position of the tab in the strip.
+        index: usize,
+        /// Id of the tab.
+        id: u64,
+    },
+    /// The close button was clicked or the tab was middle-clicked.
+    ///
+    /// The tab is not removed; call `TitleBarTabs::remove()` once the
+    /// application agrees to close it.
+    CloseRequested {
+        /// Position of the tab in the strip.
+        index: usize,
+        /// Id of the tab.
+        id: u64,
+    },
+}
+
+/// Browser-style tab strip drawn in the title bar between the menus and
+/// the window controls.
+#[derive(Debug, Clone, Default)]
+pub struct TitleBarTabs {
+    /// Tabs in display order.
+    pub tabs: Vec<Tab>,
+    /// Index of the active tab.
+    pub active: usize,
+    /// Events produced since the last `take_events()` call.
+    events: Vec<TabEvent>,
+}
+
+impl TitleBarTabs {
+    /// Create an empty tab strip.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a tab.
+    pub fn with_tab(mut self, tab: Tab) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    /// Append a tab at runtime.
+    pub fn push(&mut self, tab: Tab) {
+        self.tabs.push(tab);
+    }
+
+    /// Remove the tab with the given id, keeping a valid active index.
+    ///
+    /// Returns the removed tab, if any.
+    pub fn remove(&mut self, id: u64) -> Option<Tab> {
+        let index = self.tabs.iter().position(|tab| tab.id == id)?;
+        let tab = self.tabs.remove(index);
+        if index < self.active || self.active >= self.tabs.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+        Some(tab)
+    }
+
+    /// The active tab, if any.
+    pub fn active_tab(&self) -> Option<&Tab> {
+        self.tabs.get(self.active)
+    }
+
+    /// Make the tab with the given id active. Does not emit an event.
+    pub fn set_active(&mut self, id: u64) {
+        if let Some(index) = self.tabs.iter().position(|tab| tab.id == id) {
+            self.active = index;
+        }
+    }
+
+    /// Drain the events produced since the last call.
+    pub fn take_events(&mut self) -> Vec<TabEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+impl TitleBar {
+    /// Show a tab strip in the title bar
+    ///
+    /// Tabs are drawn after the menus and shrink to leave room for the
+    /// window controls. Dragging a tab does not move the window.
+    ///
+    /// # Arguments
+    /// * `tabs` - The initial tabs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::{Tab, TitleBarTabs};
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_tabs(
+    ///     TitleBarTabs::new()
+    ///         .with_tab(Tab::new(1, "main.rs"))
+    ///         .with_tab(Tab::new(2, "README.md")),
+    /// )
+    /// # ;
+    /// ```
+    pub fn with_tabs(mut self, tabs: TitleBarTabs) -> Self {
+        self.tabs = Some(tabs);
+        self
+    }
+
+    /// Get the tab strip, if any.
+    pub fn tabs(&self) -> Option<&TitleBarTabs> {
+        self.tabs.as_ref()
+    }
+
+    /// Get the tab strip for adding, removing or activating tabs.
+    pub fn tabs_mut(&mut self) -> Option<&mut TitleBarTabs> {
+        self.tabs.as_mut()
+    }
+
+    /// Drain the tab events produced since the last call
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # use egui_desktop::TabEvent;
+    /// # let mut title_bar = TitleBar::default();
+    /// for event in title_bar.take_tab_events() {
+    ///     if let TabEvent::CloseRequested { id, .. } = event {
+    ///         title_bar.tabs_mut().unwrap().remove(id);
+    ///     }
+    /// }
+    /// ```
+    pub fn take_tab_events(&mut self) -> Vec<TabEvent> {
+        self.tabs
+            .as_mut()
+            .map(TitleBarTabs::take_events)
+            .unwrap_or_default()
+    }
+
+    /// Render the tab strip, leaving `reserved_width` free on the right.
+    pub(crate) fn render_tabs(&mut self, ui: &mut Ui, reserved_width: f32) {
+        let Some(tabs) = &self.tabs else {
+            return;
+        };
+        if tabs.tabs.is_empty() {
+            return;
+        }
+
+        let bar_height = self
+            .bar_rect
+            .map_or(ui.max_rect().height(), |rect| rect.height());
+        let tab_height = bar_height - self.scaled(6.0);
+        let font = FontId::proportional(self.scaled(self.menu_text_size));
+        let padding = self.scaled(12.0);
+        let close_size = self.scaled(16.0);
+        let min_width = self.scaled(72.0);
+        let max_width = self.scaled(200.0);
+        let gap = self.scaled(2.0);
+
+        // Natural widths, then shrink evenly when the strip doesn't fit
+        let mut widths: Vec<f32> = tabs
+            .tabs
+            .iter()
+            .map(|tab| {
+                let close = if tab.closable { close_size + gap } else { 0.0 };
+                (self.text_widths.width(ui, &tab.label, &font) + padding * 2.0 + close)
+                    .clamp(min_width, max_width)
+            })
+            .collect();
+        let available = (ui.available_width() - reserved_width).max(0.0);
+        let total: f32 = widths.iter().sum::<f32>() + gap * widths.len() as f32;
+        if total > available {
+            let scale = available / total;
+            for width in &mut widths {
+                *width = (*width * scale).max(min_width);
+            }
+        }
+        let strip_width = (widths.iter().sum::<f32>() + gap * widths.len() as f32).min(available);
+
+        let (strip_rect, _) =
+            ui.allocate_exact_size(Vec2::new(strip_width, bar_height), Sense::hover());
+        let painter = ui.painter_at(strip_rect);
+
+        let active = tabs.active;
+        let mut events = Vec::new();
+        let mut x = strip_rect.min.x;
+        for (index, (tab, width)) in tabs.tabs.iter().zip(widths).enumerate() {
+            let tab_rect = Rect::from_min_size(
+                Pos2::new(x, strip_rect.max.y - tab_height),
+                Vec2::new(width, tab_height),
+            );
+            x += width + gap;
+            // Tabs squeezed past the strip are not drawn
+            if tab_rect.min.x >= strip_rect.max.x {
+                break;
+            }
+            let tab_rect = tab_rect.intersect(strip_rect);
+
+            // Sensing drags keeps a press on a tab from moving the window
+            let response = ui.interact(
+                tab_rect,
+                self.id.with(("tab", tab.id)),
+                Sense::click_and_drag(),
+            );
+
+            let is_active = index == active;
+            let radius = self.scaled(6.0).min(255.0) as u8;
+            let corner_radius = CornerRadius {
+                nw: radius,
+                ne: radius,
+                sw: 0,
+                se: 0,
+            };
+            if is_active {
+                painter.rect_filled(tab_rect, corner_radius, self.menu_hover_color);
+            } else if response.hovered() {
+                painter.rect_filled(
+                    tab_rect,
+                    corner_radius,
+                    self.menu_hover_color.gamma_multiply(0.5),
+                );
+            }
+
+            let text_color = if is_active {
+                self.title_color
+            } else {
+                self.menu_text_color
+            };
+            let close_space = if tab.closable { close_size + gap } else { 0.0 };
+            let text_rect = Rect::from_min_max(
+                Pos2::new(tab_rect.min.x + padding, tab_rect.min.y),
+                Pos2::new(tab_rect.max.x - padding - close_space, tab_rect.max.y),
+            );
+            painter
+                .with_clip_rect(text_rect.intersect(strip_rect))
+                .text(
+                    text_rect.left_center(),
+                    Align2::LEFT_CENTER,
+                    &tab.label,
+                    font.clone(),
+                    text_color,
+                );
+
+            let mut close_clicked = false;
+            if tab.closable {
+                let close_rect = Rect::from_center_size(
+                    Pos2::new(
+                        tab_rect.max.x - padding - close_size / 2.0,
+                        tab_rect.center().y,
+                    ),
+                    Vec2::splat(close_size),
+                );
+                let close_response = ui.interact(
+                    close_rect,
+                    self.id.with(("tab_close", tab.id)),
+                    Sense::click(),
+                );
+                if close_response.hovered() {
+                    painter.circle_filled(close_rect.center(), close_size / 2.0, self.hover_color);
+                    ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+                }
+                // Only show the cross on the active or hovered tab, like browsers do
+                if is_active || response.hovered() || close_response.hovered() {
+                    let half = close_size * 0.2;
+                    let center = close_rect.center();
+                    let stroke = Stroke::new(1.2, text_color);
+                    painter.line_segment(
+                        [
+                            center + Vec2::new(-half, -half),
+                            center + Vec2::new(half, half),
+                        ],
+                        stroke,
+                    );
+                    painter.line_segment(
+                        [
+                            center + Vec2::new(-half, half),
+                            center + Vec2::new(half, -half),
+                        ],
+                        stroke,
+                    );
+                }
+                close_clicked = close_response.clicked();
+            }
+
+            if close_clicked || (tab.closable && response.clicked_by(PointerButton::Middle)) {
+                events.push(TabEvent::CloseRequested { index, id: tab.id });
+            } else if response.clicked() && !is_active {
+                events.push(TabEvent::Selected { index, id: tab.id });
+            }
+        }
+
+        if let Some(tabs) = &mut self.tabs {
+            for event in &events {
+                if let TabEvent::Selected { index, .. } = event {
+                    tabs.active = *index;
+                }
+            }
+            tabs.events.extend(events);
+        }
+    }
+
+    /// Width taken by the custom icons on the right of the bar.
+    pub(crate) fn custom_icons_width(&self) -> f32 {
+        if self.custom_icons.is_empty() {
+            return 0.0;
+        }
+        let icon_size = self.scaled(16.0);
+        let gaps: f32 = self.custom_icons[..self.custom_icons.len() - 1]
+            .iter()
+            .map(|icon| self.custom_icon_gap(icon))
+            .sum();
+        self.custom_icons.len() as f32 * icon_size + gaps + self.scaled(16.0)
+    }
+
+    /// Gap between a custom icon and the next one, widened by a separator.
+    pub(crate) fn custom_icon_gap(&self, icon: &CustomIconButton) -> f32 {
+        if icon.separator_after {
+            self.scaled(self.icon_separator_spacing) * 2.0
+        } else {
+            self.scaled(self.icon_spacing)
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use egui::{Align, Context, Frame, Layout, Margin, TopBottomPanel, Ui};
+
+use crate::{TitleBar, TitleBarEdge};
+
+/// Callback that fills the toolbar row with widgets each frame.
+pub type ToolbarFn = dyn FnMut(&mut Ui) + Send + Sync;
+
+impl TitleBar {
+    /// Add a toolbar row next to the title bar
+    ///
+    /// The row is drawn directly beneath the bar (above it when the bar is
+    /// docked at the bottom) in its own panel. It uses the bar background,
+    /// lays widgets out left to right and never drags the window.
+    ///
+    /// # Arguments
+    /// * `toolbar` - Called every frame to add the toolbar widgets
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use egui_desktop::TitleBar;
+    /// # let title_bar = TitleBar::default();
+    /// title_bar.with_toolbar(Box::new(|ui| {
+    ///     if ui.button("Run").clicked() {
+    ///         println!("Run clicked");
+    ///     }
+    ///     ui.separator();
+    ///     ui.label("Ready");
+    /// }))
+    /// # ;
+    /// ```
+    pub fn with_toolbar(mut self, toolbar: Box<ToolbarFn>) -> Self {
+        self.toolbar = Some(toolbar);
+        self
+    }
+
+    /// Set the height of the toolbar row in points (default 32), before UI scaling.
+    pub fn with_toolbar_height(mut self, height: f32) -> Self {
+        self.toolbar_height = height;
+        self
+    }
+
+    /// Whether a toolbar row is shown.
+    pub fn has_toolbar(&self) -> bool {
+        self.toolbar.is_some()
+    }
+
+    /// Render the toolbar panel, if any. Must run right after the bar panel.
+    pub(crate) fn render_toolbar(&mut self, ctx: &Context) {
+        let content_rect = ctx.content_rect();
+        if content_rect.width() < 100.0 || content_rect.height() < 100.0 {
+            return;
+        }
+
+        let id = self.id.with("toolbar");
+        // Panels stack away from the edge, so the same order puts the row
+        // beneath a top bar and above a bottom bar
+        let panel = match self.position {
+            TitleBarEdge::Top => TopBottomPanel::top(id),
+            TitleBarEdge::Bottom => TopBottomPanel::bottom(id),
+        };
+        let frame = Frame::new()
+            .fill(self.background_color)
+            .inner_margin(Margin::symmetric(self.scaled(8.0) as i8, 0))
+            .outer_margin(Margin::same(0));
+        let height = self.scaled(self.toolbar_height);
+        let Some(toolbar) = &mut self.toolbar else {
+            self.toolbar_rect = None;
+            return;
+        };
+
+        let response = panel
+            .exact_height(height)
+            .show_separator_line(false)
+            .frame(frame)
+            .show(ctx, |ui| {
+                ui.with_layout(Layout::left_to_right(Align::Center), |ui| toolbar(ui));
+            });
+        self.toolbar_rect = Some(response.response.rect);
+    }
+}
@@ -0,0 +1,64 @@
+use eframe::Frame;
+use egui::Context;
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+use crate::utils::os::apply_native_dark_titlebar;
+use crate::utils::rounded_corners::{native_window_ptr, viewport_window_ptr};
+
+/// Switches the native window frame between light and dark mode.
+///
+/// Windows draws the non-client area, and the border and shadow of borderless
+/// windows, from `DWMWA_USE_IMMERSIVE_DARK_MODE`; this sets it so they match
+/// the app. Call it again whenever the theme changes. On other platforms this
+/// does nothing.
+///
+/// `with_follow_system_theme(true)` already does this for the window the
+/// title bar is shown in.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui_desktop::apply_dark_titlebar;
+/// # struct App { dark_mode_changed: bool }
+/// # impl App {
+/// fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+///     if self.dark_mode_changed {
+///         apply_dark_titlebar(frame, ctx.style().visuals.dark_mode);
+///     }
+///     // ... rest of your UI
+/// }
+/// # }
+/// ```
+pub fn apply_dark_titlebar(frame: &Frame, dark: bool) {
+    if !cfg!(target_os = "windows") {
+        return;
+    }
+    let Ok(window_handle) = frame.window_handle() else {
+        return;
+    };
+    let handle: RawWindowHandle = window_handle.into();
+
+    if let Some(native_ptr) = native_window_ptr(handle)
+        && let Err(e) = apply_native_dark_titlebar(native_ptr, dark)
+    {
+        eprintln!("⚠️ Failed to apply dark title bar: {}", e);
+    }
+}
+
+/// Like [`apply_dark_titlebar`], for the viewport `ctx` belongs to.
+///
+/// The window is found the same way as in `apply_rounded_corners_to_viewport`:
+/// through a handle stored with `store_window_handle_for_viewport`, or else by
+/// its title.
+pub fn apply_dark_titlebar_to_viewport(ctx: &Context, dark: bool) {
+    if !cfg!(target_os = "windows") {
+        return;
+    }
+    let Some(native_ptr) = viewport_window_ptr(ctx) else {
+        return;
+    };
+
+    if let Err(e) = apply_native_dark_titlebar(native_ptr, dark) {
+        eprintln!("⚠️ Failed to apply dark title bar to viewport: {}", e);
+    }
+}
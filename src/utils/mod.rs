@@ -1,10 +1,16 @@
+/// Dark native window frames on Windows.
+pub mod dark_titlebar;
 /// OS interop helpers and platform-specific utilities.
 pub mod os;
 /// Viewport resize handle utilities.
 pub mod resize_handles;
 /// Fallback rounded corners drawing helpers.
 pub mod rounded_corners;
+/// Native drop shadow helpers for borderless windows.
+pub mod window_shadow;
 
+pub use dark_titlebar::*;
 pub use os::*;
 pub use resize_handles::*;
 pub use rounded_corners::*;
+pub use window_shadow::*;
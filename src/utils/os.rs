@@ -5,23 +5,43 @@ use std::ffi::c_void;
 mod platform {
     use super::*;
     use windows::Win32::Foundation::HWND;
-    use windows::Win32::Graphics::Dwm::{DWMWINDOWATTRIBUTE, DwmSetWindowAttribute};
+    use windows::Win32::Graphics::Dwm::{
+        DWMWINDOWATTRIBUTE, DwmExtendFrameIntoClientArea, DwmSetWindowAttribute,
+    };
+    use windows::Win32::UI::Controls::MARGINS;
 
+    const DWMWA_NCRENDERING_POLICY: DWMWINDOWATTRIBUTE = DWMWINDOWATTRIBUTE(2);
+    const DWMWA_USE_IMMERSIVE_DARK_MODE: DWMWINDOWATTRIBUTE = DWMWINDOWATTRIBUTE(20);
     const DWMWA_WINDOW_CORNER_PREFERENCE: DWMWINDOWATTRIBUTE = DWMWINDOWATTRIBUTE(33);
+    const DWMNCRP_ENABLED: u32 = 2;
+    const DWMWCP_DONOTROUND: u32 = 1;
     const DWMWCP_ROUND: u32 = 2;
+    const DWMWCP_ROUNDSMALL: u32 = 3;
 
-    pub fn apply_native_rounded_corners(ptr: *mut c_void) -> Result<(), Box<dyn Error>> {
+    pub fn apply_native_rounded_corners(
+        ptr: *mut c_void,
+        radius: f32,
+    ) -> Result<(), Box<dyn Error>> {
         if ptr.is_null() {
             return Err("Null HWND pointer".into());
         }
 
         let hwnd = HWND(ptr);
 
+        // DWM only offers presets: square, small (~4px) and regular (~8px) rounding
+        let preference = if radius <= 0.0 {
+            DWMWCP_DONOTROUND
+        } else if radius <= 4.0 {
+            DWMWCP_ROUNDSMALL
+        } else {
+            DWMWCP_ROUND
+        };
+
         unsafe {
             let hr = DwmSetWindowAttribute(
                 hwnd,
                 DWMWA_WINDOW_CORNER_PREFERENCE,
-                &DWMWCP_ROUND as *const _ as *const _,
+                &preference as *const _ as *const _,
                 size_of::<u32>() as u32,
             );
 
@@ -36,6 +56,72 @@ mod platform {
             }
         }
     }
+
+    pub fn apply_native_dark_titlebar(ptr: *mut c_void, dark: bool) -> Result<(), Box<dyn Error>> {
+        if ptr.is_null() {
+            return Err("Null HWND pointer".into());
+        }
+
+        let hwnd = HWND(ptr);
+        // The attribute is a Win32 BOOL
+        let enabled = i32::from(dark);
+
+        unsafe {
+            let hr = DwmSetWindowAttribute(
+                hwnd,
+                DWMWA_USE_IMMERSIVE_DARK_MODE,
+                &enabled as *const _ as *const _,
+                size_of::<i32>() as u32,
+            );
+
+            if hr.is_ok() {
+                Ok(())
+            } else {
+                Err(format!(
+                    "DwmSetWindowAttribute failed: {:?}. Possibly older than Windows 10 20H1.",
+                    hr
+                )
+                .into())
+            }
+        }
+    }
+
+    pub fn apply_native_window_shadow(ptr: *mut c_void) -> Result<(), Box<dyn Error>> {
+        if ptr.is_null() {
+            return Err("Null HWND pointer".into());
+        }
+
+        let hwnd = HWND(ptr);
+
+        unsafe {
+            // Let DWM render the non-client area, which is what draws the shadow
+            let policy = DWMNCRP_ENABLED;
+            let hr = DwmSetWindowAttribute(
+                hwnd,
+                DWMWA_NCRENDERING_POLICY,
+                &policy as *const _ as *const _,
+                size_of::<u32>() as u32,
+            );
+            if hr.is_err() {
+                return Err(format!("DwmSetWindowAttribute failed: {:?}", hr).into());
+            }
+
+            // A borderless window only gets a shadow once the DWM frame reaches
+            // into the client area; a single pixel is enough and stays invisible
+            let margins = MARGINS {
+                cxLeftWidth: 1,
+                cxRightWidth: 1,
+                cyTopHeight: 1,
+                cyBottomHeight: 1,
+            };
+            let hr = DwmExtendFrameIntoClientArea(hwnd, &margins);
+            if hr.is_ok() {
+                Ok(())
+            } else {
+                Err(format!("DwmExtendFrameIntoClientArea failed: {:?}", hr).into())
+            }
+        }
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -44,7 +130,10 @@ mod platform {
     use objc2::{ffi::nil, msg_send, runtime::AnyObject, runtime::Bool};
     use std::ffi::CString;
 
-    pub fn apply_native_rounded_corners(ptr: *mut c_void) -> Result<(), Box<dyn Error>> {
+    pub fn apply_native_rounded_corners(
+        ptr: *mut c_void,
+        radius: f32,
+    ) -> Result<(), Box<dyn Error>> {
         if ptr.is_null() {
             return Err("Null NSView pointer".into());
         }
@@ -84,7 +173,7 @@ mod platform {
                 let _: () = msg_send![content_view, setWantsLayer: Bool::YES];
                 let layer: *mut AnyObject = msg_send![content_view, layer];
                 if layer != nil {
-                    let _: () = msg_send![layer, setCornerRadius: 12.0f64];
+                    let _: () = msg_send![layer, setCornerRadius: radius.max(0.0) as f64];
                     let _: () = msg_send![layer, setMasksToBounds: Bool::YES];
                 }
             }
@@ -92,16 +181,371 @@ mod platform {
             Ok(())
         }
     }
+
+    pub fn apply_native_window_shadow(ptr: *mut c_void) -> Result<(), Box<dyn Error>> {
+        if ptr.is_null() {
+            return Err("Null NSView pointer".into());
+        }
+
+        unsafe {
+            let ns_view = ptr as *mut AnyObject;
+            if ns_view == nil {
+                return Err("Invalid NSView (nil)".into());
+            }
+
+            let ns_window: *mut AnyObject = msg_send![ns_view, window];
+            if ns_window == nil {
+                return Err("Failed to obtain NSWindow from NSView".into());
+            }
+
+            let _: () = msg_send![ns_window, setHasShadow: Bool::YES];
+            // Recompute the shadow from the current (possibly rounded) content
+            let _: () = msg_send![ns_window, invalidateShadow];
+
+            Ok(())
+        }
+    }
 }
 
 #[cfg(target_os = "linux")]
 mod platform {
     use super::*;
+    use std::collections::HashMap;
+    use std::ffi::{CStr, c_char, c_int, c_uint, c_ulong};
+    use std::sync::{LazyLock, Mutex, Once};
+    use x11_dl::xlib;
+    // x11-dl names the XFixes function table `Xlib` as well
+    use x11_dl::xfixes::Xlib as XFixes;
 
-    pub fn apply_native_rounded_corners(_ptr: *mut c_void) -> Result<(), Box<dyn Error>> {
-        // Rounded corners are not supported on Linux for now.
-        println!("ℹ️ Linux: Rounded corners are currently not supported.");
-        Ok(())
+    /// `ShapeBounding` from the X SHAPE extension.
+    const SHAPE_BOUNDING: c_int = 0;
+
+    // Lazily opened connection shared by every shaped window
+    static SHAPER: LazyLock<Mutex<Option<X11Shaper>>> =
+        LazyLock::new(|| Mutex::new(X11Shaper::open()));
+    static UNAVAILABLE_WARNING: Once = Once::new();
+
+    /// Clips X11 windows to a rounded rectangle using the SHAPE extension.
+    ///
+    /// The region is built through XFixes (`XFixesSetWindowShapeRegion`, the
+    /// server-side equivalent of `XShapeCombineRegion`) so only libX11 and
+    /// libXfixes need to be present at runtime; both are loaded dynamically.
+    struct X11Shaper {
+        xlib: xlib::Xlib,
+        xfixes: XFixes,
+        display: *mut xlib::Display,
+        /// Last applied (width, height, [tl, tr, bl, br]) per window, to reshape only on change.
+        applied: HashMap<xlib::Window, (c_int, c_int, [c_int; 4])>,
+    }
+
+    // The display connection is private to this module and only used behind the mutex
+    unsafe impl Send for X11Shaper {}
+
+    impl X11Shaper {
+        fn open() -> Option<Self> {
+            let xlib = xlib::Xlib::open().ok()?;
+            let xfixes = XFixes::open().ok()?;
+
+            unsafe {
+                let display = (xlib.XOpenDisplay)(std::ptr::null());
+                if display.is_null() {
+                    return None;
+                }
+
+                let (mut opcode, mut event_base, mut error_base) = (0, 0, 0);
+                let has_shape = (xlib.XQueryExtension)(
+                    display,
+                    c"SHAPE".as_ptr(),
+                    &mut opcode,
+                    &mut event_base,
+                    &mut error_base,
+                ) != 0;
+
+                // XFixes regions require protocol version 2 or newer
+                let (mut major, minor) = (2, 0);
+                let has_xfixes =
+                    (xfixes.XFixesQueryExtension)(display, &mut event_base, &mut error_base) != 0
+                        && (xfixes.XFixesQueryVersion)(display, &mut major, &minor) != 0
+                        && major >= 2;
+
+                if !has_shape || !has_xfixes {
+                    (xlib.XCloseDisplay)(display);
+                    return None;
+                }
+
+                Some(Self {
+                    xlib,
+                    xfixes,
+                    display,
+                    applied: HashMap::new(),
+                })
+            }
+        }
+
+        fn shape(
+            &mut self,
+            window: xlib::Window,
+            radii: CornerRadii,
+        ) -> Result<(), Box<dyn Error>> {
+            unsafe {
+                let mut attributes: xlib::XWindowAttributes = std::mem::zeroed();
+                if (self.xlib.XGetWindowAttributes)(self.display, window, &mut attributes) == 0 {
+                    return Err("XGetWindowAttributes failed".into());
+                }
+
+                let (width, height) = (attributes.width, attributes.height);
+                let clamp = |radius: f32| {
+                    (radius.max(0.0).round() as c_int)
+                        .min(width / 2)
+                        .min(height / 2)
+                };
+                let radii = [
+                    clamp(radii.tl),
+                    clamp(radii.tr),
+                    clamp(radii.bl),
+                    clamp(radii.br),
+                ];
+
+                // Shapes are pixel masks, so only redo the work when the size changes
+                if self.applied.get(&window) == Some(&(width, height, radii)) {
+                    return Ok(());
+                }
+
+                let mut rects = rounded_rect_spans(width, height, radii);
+                let region = (self.xfixes.XFixesCreateRegion)(
+                    self.display,
+                    rects.as_mut_ptr(),
+                    rects.len() as c_int,
+                );
+                (self.xfixes.XFixesSetWindowShapeRegion)(
+                    self.display,
+                    window,
+                    SHAPE_BOUNDING,
+                    0,
+                    0,
+                    region,
+                );
+                (self.xfixes.XFixesDestroyRegion)(self.display, region);
+                (self.xlib.XFlush)(self.display);
+
+                self.applied.insert(window, (width, height, radii));
+                Ok(())
+            }
+        }
+
+        fn find_window_by_title(
+            &self,
+            parent: xlib::Window,
+            title: &str,
+            depth: u32,
+        ) -> Option<xlib::Window> {
+            unsafe {
+                let mut name: *mut c_char = std::ptr::null_mut();
+                if (self.xlib.XFetchName)(self.display, parent, &mut name) != 0 && !name.is_null() {
+                    let matches = CStr::from_ptr(name).to_string_lossy() == title;
+                    (self.xlib.XFree)(name.cast());
+                    if matches {
+                        return Some(parent);
+                    }
+                }
+
+                // Window managers reparent client windows, so look a few levels deep
+                if depth == 0 {
+                    return None;
+                }
+
+                let (mut root, mut tree_parent) = (0, 0);
+                let mut children: *mut c_ulong = std::ptr::null_mut();
+                let mut count: c_uint = 0;
+                if (self.xlib.XQueryTree)(
+                    self.display,
+                    parent,
+                    &mut root,
+                    &mut tree_parent,
+                    &mut children,
+                    &mut count,
+                ) == 0
+                    || children.is_null()
+                {
+                    return None;
+                }
+
+                let found = std::slice::from_raw_parts(children, count as usize)
+                    .iter()
+                    .find_map(|&child| self.find_window_by_title(child, title, depth - 1));
+                (self.xlib.XFree)(children.cast());
+                found
+            }
+        }
+    }
+
+    /// Build the rounded rectangle as one tall middle rectangle plus one
+    /// single-pixel row per scanline in the top and bottom corner bands.
+    ///
+    /// `radii` is `[top_left, top_right, bottom_left, bottom_right]` in pixels.
+    fn rounded_rect_spans(width: c_int, height: c_int, radii: [c_int; 4]) -> Vec<xlib::XRectangle> {
+        let rect = |x: c_int, y: c_int, w: c_int, h: c_int| xlib::XRectangle {
+            x: x as i16,
+            y: y as i16,
+            width: w.max(0) as u16,
+            height: h.max(0) as u16,
+        };
+        // Horizontal inset of a corner arc on the given scanline (counted from the edge)
+        let inset = |radius: c_int, row: c_int| -> c_int {
+            if row >= radius {
+                return 0;
+            }
+            let r = radius as f32;
+            // Vertical distance from the corner circle center to this scanline's center
+            let dy = r - row as f32 - 0.5;
+            (r - (r * r - dy * dy).max(0.0).sqrt()).round() as c_int
+        };
+
+        let [tl, tr, bl, br] = radii;
+        let top = tl.max(tr);
+        let bottom = bl.max(br);
+
+        let mut rects = Vec::with_capacity((top + bottom) as usize + 1);
+        rects.push(rect(0, top, width, height - top - bottom));
+
+        for row in 0..top {
+            let (left, right) = (inset(tl, row), inset(tr, row));
+            rects.push(rect(left, row, width - left - right, 1));
+        }
+        for row in 0..bottom {
+            let (left, right) = (inset(bl, row), inset(br, row));
+            rects.push(rect(left, height - 1 - row, width - left - right, 1));
+        }
+
+        rects
+    }
+
+    /// Best-effort check for a Wayland session, where the pointer we receive is
+    /// a `wl_surface` rather than an X11 window id.
+    pub fn is_wayland_session() -> bool {
+        std::env::var_os("WAYLAND_DISPLAY").is_some()
+            && std::env::var("WINIT_UNIX_BACKEND").map_or(true, |backend| backend != "x11")
+    }
+
+    pub fn apply_native_rounded_corners(
+        ptr: *mut c_void,
+        radii: CornerRadii,
+    ) -> Result<(), Box<dyn Error>> {
+        if ptr.is_null() {
+            return Err("Null X11 window id".into());
+        }
+        if is_wayland_session() {
+            // Wayland surfaces cannot be shaped; there is nothing to do natively.
+            return Ok(());
+        }
+
+        let mut shaper = SHAPER.lock().unwrap();
+        match shaper.as_mut() {
+            Some(shaper) => shaper.shape(ptr as xlib::Window, radii),
+            None => {
+                UNAVAILABLE_WARNING.call_once(|| {
+                    eprintln!(
+                        "⚠️ Linux X11: SHAPE/XFixes extension unavailable, rounded corners disabled."
+                    );
+                });
+                Ok(())
+            }
+        }
+    }
+
+    pub fn find_window_by_title(title: &str) -> Option<*mut c_void> {
+        if is_wayland_session() {
+            return None;
+        }
+
+        let shaper = SHAPER.lock().unwrap();
+        let shaper = shaper.as_ref()?;
+        let root = unsafe { (shaper.xlib.XDefaultRootWindow)(shaper.display) };
+        shaper
+            .find_window_by_title(root, title, 3)
+            .map(|window| window as *mut c_void)
+    }
+
+    pub fn supports_native_rounded_corners() -> bool {
+        !is_wayland_session() && SHAPER.lock().unwrap().is_some()
+    }
+}
+
+/// Corner radius in points used when no explicit radius is given.
+pub const DEFAULT_CORNER_RADIUS: f32 = 12.0;
+
+/// Independent radius for each window corner, in points.
+///
+/// Platforms that only support a single radius (Windows, macOS) use the
+/// largest of the four. Linux X11 and the client-side Wayland path honor each
+/// corner exactly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use egui_desktop::CornerRadii;
+/// // Round only the top corners, e.g. for a window docked to the bottom edge
+/// let radii = CornerRadii::top(12.0);
+/// assert_eq!(radii.bl, 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CornerRadii {
+    /// Top-left radius.
+    pub tl: f32,
+    /// Top-right radius.
+    pub tr: f32,
+    /// Bottom-left radius.
+    pub bl: f32,
+    /// Bottom-right radius.
+    pub br: f32,
+}
+
+impl CornerRadii {
+    /// Creates radii with an explicit value for each corner.
+    pub const fn new(tl: f32, tr: f32, bl: f32, br: f32) -> Self {
+        Self { tl, tr, bl, br }
+    }
+
+    /// Uses the same radius for all four corners.
+    pub const fn same(radius: f32) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// Rounds only the top corners, leaving the bottom square.
+    pub const fn top(radius: f32) -> Self {
+        Self::new(radius, radius, 0.0, 0.0)
+    }
+
+    /// Rounds only the bottom corners, leaving the top square.
+    pub const fn bottom(radius: f32) -> Self {
+        Self::new(0.0, 0.0, radius, radius)
+    }
+
+    /// Largest of the four radii.
+    pub fn max(&self) -> f32 {
+        self.tl.max(self.tr).max(self.bl).max(self.br)
+    }
+
+    /// Returns true if all four corners share the same radius.
+    pub fn is_uniform(&self) -> bool {
+        self.tl == self.tr && self.tl == self.bl && self.tl == self.br
+    }
+
+    /// Converts to egui's per-corner rounding, clamped to what `CornerRadius` can hold.
+    pub fn to_egui(&self) -> egui::CornerRadius {
+        let to_u8 = |radius: f32| radius.round().clamp(0.0, u8::MAX as f32) as u8;
+        egui::CornerRadius {
+            nw: to_u8(self.tl),
+            ne: to_u8(self.tr),
+            sw: to_u8(self.bl),
+            se: to_u8(self.br),
+        }
+    }
+}
+
+impl From<f32> for CornerRadii {
+    fn from(radius: f32) -> Self {
+        Self::same(radius)
     }
 }
 
@@ -110,24 +554,109 @@ mod platform {
 /// The pointer type and semantics depend on the platform (e.g. HWND on Windows,
 /// NSView on macOS). Returns `Ok(())` on success or an error with details.
 pub fn apply_native_rounded_corners(ptr: *mut c_void) -> Result<(), Box<dyn Error>> {
+    apply_native_rounded_corners_with_radius(ptr, DEFAULT_CORNER_RADIUS)
+}
+
+/// Try to apply native rounded corners with a specific radius in points.
+///
+/// Platform support for the radius differs:
+/// - macOS honors any radius (`layer.cornerRadius`).
+/// - Windows 11 only offers DWM presets: `0` gives square corners, up to `4`
+///   gives the small preset and anything larger gives the regular preset.
+/// - Linux X11 honors any radius by clipping the window with the SHAPE
+///   extension; call it again after a resize to recompute the mask.
+pub fn apply_native_rounded_corners_with_radius(
+    ptr: *mut c_void,
+    radius: f32,
+) -> Result<(), Box<dyn Error>> {
+    apply_native_rounded_corners_with_radii(ptr, CornerRadii::same(radius))
+}
+
+/// Try to apply native rounded corners with an independent radius per corner.
+///
+/// Only Linux X11 can shape each corner separately. Windows and macOS apply a
+/// single radius, so the largest of the four is used there and a one-time
+/// notice is logged when the radii differ.
+pub fn apply_native_rounded_corners_with_radii(
+    ptr: *mut c_void,
+    radii: CornerRadii,
+) -> Result<(), Box<dyn Error>> {
+    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    {
+        static PER_CORNER_NOTICE: std::sync::Once = std::sync::Once::new();
+        if !radii.is_uniform() {
+            PER_CORNER_NOTICE.call_once(|| {
+                println!(
+                    "ℹ️ Per-corner radii are not supported natively on this platform, using the largest radius."
+                );
+            });
+        }
+    }
+
     #[cfg(target_os = "windows")]
     {
-        platform::apply_native_rounded_corners(ptr)
+        platform::apply_native_rounded_corners(ptr, radii.max())
     }
     #[cfg(target_os = "macos")]
     {
-        platform::apply_native_rounded_corners(ptr)
+        platform::apply_native_rounded_corners(ptr, radii.max())
     }
     #[cfg(target_os = "linux")]
     {
-        platform::apply_native_rounded_corners(ptr)
+        platform::apply_native_rounded_corners(ptr, radii)
     }
     #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
     {
+        let _ = (ptr, radii);
         Err("Native rounded corners not supported on this platform".into())
     }
 }
 
+/// Try to give the OS window represented by `ptr` a native drop shadow.
+///
+/// - Windows enables DWM non-client rendering and extends the frame one pixel
+///   into the client area, which restores the shadow of borderless windows.
+/// - macOS sets `NSWindow.hasShadow`.
+/// - Linux is a no-op: shadows are drawn by the compositor (or window manager
+///   on X11) and cannot be requested by the client, notably on Wayland.
+pub fn apply_native_window_shadow(ptr: *mut c_void) -> Result<(), Box<dyn Error>> {
+    #[cfg(target_os = "windows")]
+    {
+        platform::apply_native_window_shadow(ptr)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        platform::apply_native_window_shadow(ptr)
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let _ = ptr;
+        Ok(())
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    {
+        let _ = ptr;
+        Err("Native window shadows not supported on this platform".into())
+    }
+}
+
+/// Try to switch the native frame of the window represented by `ptr` to dark mode.
+///
+/// - Windows sets `DWMWA_USE_IMMERSIVE_DARK_MODE`, which tints the non-client
+///   area when it is visible, and the border and shadow of borderless windows.
+/// - Other platforms are a no-op.
+pub fn apply_native_dark_titlebar(ptr: *mut c_void, dark: bool) -> Result<(), Box<dyn Error>> {
+    #[cfg(target_os = "windows")]
+    {
+        platform::apply_native_dark_titlebar(ptr, dark)
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (ptr, dark);
+        Ok(())
+    }
+}
+
 /// Returns true if we have a native strategy for rounded corners on this platform.
 pub fn supports_native_rounded_corners() -> bool {
     #[cfg(target_os = "windows")]
@@ -140,10 +669,26 @@ pub fn supports_native_rounded_corners() -> bool {
     }
     #[cfg(target_os = "linux")]
     {
-        false
+        platform::supports_native_rounded_corners()
     }
     #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
     {
         false
     }
 }
+
+/// Returns true when running as a native Wayland client.
+///
+/// This is a best-effort check based on the environment winit also consults.
+#[cfg(target_os = "linux")]
+pub(crate) fn is_wayland_session() -> bool {
+    platform::is_wayland_session()
+}
+
+/// Find one of this application's X11 windows by its title.
+///
+/// Used as a fallback to locate secondary viewports when their handle was not stored.
+#[cfg(target_os = "linux")]
+pub(crate) fn find_x11_window_by_title(title: &str) -> Option<*mut c_void> {
+    platform::find_window_by_title(title)
+}
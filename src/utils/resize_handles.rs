@@ -1,21 +1,306 @@
+use std::ops::{BitAnd, BitOr, BitOrAssign, Not};
+
 use egui::{
-    Area, Context, CursorIcon, Id, PointerButton, Pos2, ResizeDirection, Sense, Vec2,
+    Area, Context, CursorIcon, Id, PointerButton, Pos2, Rect, ResizeDirection, Sense, Vec2,
     ViewportCommand,
 };
 
+/// Thickness of the edge resize handles, in points.
+pub(crate) const RESIZE_HANDLE_SIZE: f32 = 8.0;
+
+/// Set of window edges and corners that accept interactive resizing.
+///
+/// Works like a bitflag: combine the associated constants with `|` and test
+/// membership with [`ResizeEdges::contains`]. A disabled edge gets no handle at
+/// all, so the OS is never asked to start a resize in that direction.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use egui_desktop::{ResizeEdges, render_resize_handles_with_edges};
+/// # fn update(ctx: &egui::Context) {
+/// // Only allow resizing from the left and right edges
+/// render_resize_handles_with_edges(ctx, ResizeEdges::horizontal_only());
+///
+/// // Allow the bottom edge and the bottom-right corner
+/// render_resize_handles_with_edges(ctx, ResizeEdges::SOUTH | ResizeEdges::SOUTH_EAST);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResizeEdges(u8);
+
+impl ResizeEdges {
+    /// Top edge.
+    pub const NORTH: Self = Self(1 << 0);
+    /// Bottom edge.
+    pub const SOUTH: Self = Self(1 << 1);
+    /// Left edge.
+    pub const WEST: Self = Self(1 << 2);
+    /// Right edge.
+    pub const EAST: Self = Self(1 << 3);
+    /// Top-left corner.
+    pub const NORTH_WEST: Self = Self(1 << 4);
+    /// Top-right corner.
+    pub const NORTH_EAST: Self = Self(1 << 5);
+    /// Bottom-left corner.
+    pub const SOUTH_WEST: Self = Self(1 << 6);
+    /// Bottom-right corner.
+    pub const SOUTH_EAST: Self = Self(1 << 7);
+
+    /// All four edges and all four corners.
+    pub const fn all() -> Self {
+        Self(0xFF)
+    }
+
+    /// No resize handles at all.
+    pub const fn none() -> Self {
+        Self(0)
+    }
+
+    /// Only the left and right edges (width can change, height cannot).
+    pub const fn horizontal_only() -> Self {
+        Self(Self::WEST.0 | Self::EAST.0)
+    }
+
+    /// Only the top and bottom edges (height can change, width cannot).
+    pub const fn vertical_only() -> Self {
+        Self(Self::NORTH.0 | Self::SOUTH.0)
+    }
+
+    /// Returns true if every edge in `other` is enabled in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if no edge is enabled.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Enable or disable the given edges.
+    pub fn set(&mut self, other: Self, enabled: bool) {
+        if enabled {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+
+    /// Returns true if the handle for `direction` is enabled.
+    pub const fn allows(self, direction: ResizeDirection) -> bool {
+        self.contains(Self::from_direction(direction))
+    }
+
+    /// The edge flag corresponding to an egui resize direction.
+    pub const fn from_direction(direction: ResizeDirection) -> Self {
+        match direction {
+            ResizeDirection::North => Self::NORTH,
+            ResizeDirection::South => Self::SOUTH,
+            ResizeDirection::West => Self::WEST,
+            ResizeDirection::East => Self::EAST,
+            ResizeDirection::NorthWest => Self::NORTH_WEST,
+            ResizeDirection::NorthEast => Self::NORTH_EAST,
+            ResizeDirection::SouthWest => Self::SOUTH_WEST,
+            ResizeDirection::SouthEast => Self::SOUTH_EAST,
+        }
+    }
+}
+
+impl Default for ResizeEdges {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl BitOr for ResizeEdges {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ResizeEdges {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for ResizeEdges {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Not for ResizeEdges {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+/// Configuration for the viewport resize handles.
+///
+/// Use with [`render_resize_handles_with_options`] when the defaults of
+/// [`render_resize_handles`] are not enough.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use egui::Vec2;
+/// # use egui_desktop::{ResizeEdges, ResizeHandleOptions, render_resize_handles_with_options};
+/// # fn update(ctx: &egui::Context) {
+/// let options = ResizeHandleOptions::new()
+///     .with_edges(ResizeEdges::all())
+///     .with_min_size(Vec2::new(400.0, 300.0))
+///     .with_double_click_maximize(true);
+/// render_resize_handles_with_options(ctx, &options);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ResizeHandleOptions {
+    /// Edges and corners that accept resizing.
+    pub edges: ResizeEdges,
+    /// Minimum inner size enforced while resizing.
+    pub min_size: Option<Vec2>,
+    /// Maximum inner size enforced while resizing.
+    pub max_size: Option<Vec2>,
+    /// Double-clicking an edge maximizes the window along that axis.
+    pub double_click_maximize: bool,
+}
+
+impl ResizeHandleOptions {
+    /// Create default options (all edges, no limits, no double-click action).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select which edges and corners accept resizing.
+    pub fn with_edges(mut self, edges: ResizeEdges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    /// Set the minimum inner size enforced while resizing.
+    pub fn with_min_size(mut self, min: Vec2) -> Self {
+        self.min_size = Some(min);
+        self
+    }
+
+    /// Set the maximum inner size enforced while resizing.
+    pub fn with_max_size(mut self, max: Vec2) -> Self {
+        self.max_size = Some(max);
+        self
+    }
+
+    /// Maximize along one axis when an edge is double-clicked.
+    ///
+    /// Double-clicking the top or bottom edge stretches the window to the
+    /// monitor height while keeping its width and x position; the left or right
+    /// edge stretches it to the monitor width. On Windows the monitor's work area
+    /// is used, so the taskbar stays uncovered. Elsewhere egui only exposes the
+    /// monitor size: the window is stretched over the monitor-sized cell its
+    /// center lies in, which matches side-by-side monitors of equal size, and
+    /// may extend under a dock or panel.
+    pub fn with_double_click_maximize(mut self, enabled: bool) -> Self {
+        self.double_click_maximize = enabled;
+        self
+    }
+}
+
 /// Render invisible viewport resize handles around the window.
 pub fn render_resize_handles(ctx: &Context) {
+    render_resize_handles_with_options(ctx, &ResizeHandleOptions::default());
+}
+
+/// Render resize handles and constrain the window size during interactive resize.
+///
+/// This does not clamp the window manually every frame. Instead it sets the
+/// viewport constraints once, with `ViewportCommand::MinInnerSize` and
+/// `ViewportCommand::MaxInnerSize`, so the OS/compositor clamps the drag started
+/// by `ViewportCommand::BeginResize`. The commands are only re-sent when the
+/// limits passed in change, and a limit that is no longer passed is lifted.
+///
+/// # Arguments
+/// * `ctx` - The egui context
+/// * `min` - Minimum inner size in points
+/// * `max` - Optional maximum inner size in points
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use egui::Vec2;
+/// # use egui_desktop::render_resize_handles_with_limits;
+/// # fn update(ctx: &egui::Context) {
+/// render_resize_handles_with_limits(ctx, Vec2::new(400.0, 300.0), None);
+/// # }
+/// ```
+pub fn render_resize_handles_with_limits(ctx: &Context, min: Vec2, max: Option<Vec2>) {
+    let options = ResizeHandleOptions {
+        min_size: Some(min),
+        max_size: max,
+        ..Default::default()
+    };
+    render_resize_handles_with_options(ctx, &options);
+}
+
+/// Send the viewport size constraints, but only when they differ from the last ones sent.
+///
+/// A limit that is removed is reset to no constraint (zero minimum, infinite maximum).
+fn apply_resize_limits(ctx: &Context, min: Option<Vec2>, max: Option<Vec2>) {
+    let id = Id::new(("resize_limits", ctx.viewport_id()));
+    let limits = (min, max);
+
+    let (previous_min, previous_max) = ctx
+        .data(|data| data.get_temp::<(Option<Vec2>, Option<Vec2>)>(id))
+        .unwrap_or_default();
+    if (previous_min, previous_max) == limits {
+        return;
+    }
+
+    if min != previous_min {
+        ctx.send_viewport_cmd(ViewportCommand::MinInnerSize(min.unwrap_or(Vec2::ZERO)));
+    }
+    if max != previous_max {
+        ctx.send_viewport_cmd(ViewportCommand::MaxInnerSize(max.unwrap_or(Vec2::INFINITY)));
+    }
+    ctx.data_mut(|data| data.insert_temp(id, limits));
+}
+
+/// Render viewport resize handles only for the given edges and corners.
+///
+/// Handles for disabled edges are not created, so no
+/// `ViewportCommand::BeginResize` is ever sent for those directions.
+///
+/// # Arguments
+/// * `ctx` - The egui context
+/// * `edges` - The edges and corners that should accept resizing
+pub fn render_resize_handles_with_edges(ctx: &Context, edges: ResizeEdges) {
+    render_resize_handles_with_options(ctx, &ResizeHandleOptions::new().with_edges(edges));
+}
+
+/// Render viewport resize handles using the given options.
+///
+/// # Arguments
+/// * `ctx` - The egui context
+/// * `options` - Edges, size limits and double-click behavior
+pub fn render_resize_handles_with_options(ctx: &Context, options: &ResizeHandleOptions) {
     let content_rect = ctx.content_rect();
-    let resize_handle_size = 8.0;
+    let resize_handle_size = RESIZE_HANDLE_SIZE;
 
-    if content_rect.width() < 100.0 || content_rect.height() < 100.0 {
+    apply_resize_limits(ctx, options.min_size, options.max_size);
+
+    if options.edges.is_empty() || content_rect.width() < 100.0 || content_rect.height() < 100.0 {
         return;
     }
 
     create_resize_handle(
         ctx,
+        options,
         "resize_top",
-        "resize_top_handle",
         Pos2::new(content_rect.min.x, content_rect.min.y),
         Vec2::new(content_rect.width(), resize_handle_size),
         CursorIcon::ResizeVertical,
@@ -24,8 +309,8 @@ pub fn render_resize_handles(ctx: &Context) {
 
     create_resize_handle(
         ctx,
+        options,
         "resize_bottom",
-        "resize_bottom_handle",
         Pos2::new(content_rect.min.x, content_rect.max.y - resize_handle_size),
         Vec2::new(content_rect.width(), resize_handle_size),
         CursorIcon::ResizeVertical,
@@ -34,8 +319,8 @@ pub fn render_resize_handles(ctx: &Context) {
 
     create_resize_handle(
         ctx,
+        options,
         "resize_left",
-        "resize_left_handle",
         Pos2::new(content_rect.min.x, content_rect.min.y),
         Vec2::new(resize_handle_size, content_rect.height()),
         CursorIcon::ResizeHorizontal,
@@ -44,8 +329,8 @@ pub fn render_resize_handles(ctx: &Context) {
 
     create_resize_handle(
         ctx,
+        options,
         "resize_right",
-        "resize_right_handle",
         Pos2::new(content_rect.max.x - resize_handle_size, content_rect.min.y),
         Vec2::new(resize_handle_size, content_rect.height()),
         CursorIcon::ResizeHorizontal,
@@ -57,8 +342,8 @@ pub fn render_resize_handles(ctx: &Context) {
     if content_rect.width() > corner_size * 2.0 && content_rect.height() > corner_size * 2.0 {
         create_resize_handle(
             ctx,
+            options,
             "resize_top_left",
-            "resize_top_left_handle",
             Pos2::new(content_rect.min.x, content_rect.min.y),
             Vec2::new(corner_size, corner_size),
             CursorIcon::ResizeNwSe,
@@ -67,8 +352,8 @@ pub fn render_resize_handles(ctx: &Context) {
 
         create_resize_handle(
             ctx,
+            options,
             "resize_top_right",
-            "resize_top_right_handle",
             Pos2::new(content_rect.max.x - corner_size, content_rect.min.y),
             Vec2::new(corner_size, corner_size),
             CursorIcon::ResizeNeSw,
@@ -77,8 +362,8 @@ pub fn render_resize_handles(ctx: &Context) {
 
         create_resize_handle(
             ctx,
+            options,
             "resize_bottom_left",
-            "resize_bottom_left_handle",
             Pos2::new(content_rect.min.x, content_rect.max.y - corner_size),
             Vec2::new(corner_size, corner_size),
             CursorIcon::ResizeNeSw,
@@ -87,8 +372,8 @@ pub fn render_resize_handles(ctx: &Context) {
 
         create_resize_handle(
             ctx,
+            options,
             "resize_bottom_right",
-            "resize_bottom_right_handle",
             Pos2::new(
                 content_rect.max.x - corner_size,
                 content_rect.max.y - corner_size,
@@ -102,20 +387,27 @@ pub fn render_resize_handles(ctx: &Context) {
 
 fn create_resize_handle(
     ctx: &Context,
+    options: &ResizeHandleOptions,
     area_id: &str,
-    handle_id: &str,
     position: Pos2,
     size: Vec2,
     cursor_icon: CursorIcon,
     resize_direction: ResizeDirection,
 ) {
+    if !options.edges.allows(resize_direction) {
+        return;
+    }
+
     Area::new(Id::new(area_id))
         .fixed_pos(position)
         .show(ctx, |ui| {
             ui.set_min_size(size);
             let (_id, response) = ui.allocate_space(size);
-            let interaction_response =
-                ui.interact(response, Id::new(handle_id), Sense::click_and_drag());
+            let interaction_response = ui.interact(
+                response,
+                Id::new(area_id).with("handle"),
+                Sense::click_and_drag(),
+            );
 
             if interaction_response.hovered() {
                 ctx.set_cursor_icon(cursor_icon);
@@ -124,5 +416,273 @@ fn create_resize_handle(
             if interaction_response.drag_started_by(PointerButton::Primary) {
                 ctx.send_viewport_cmd(ViewportCommand::BeginResize(resize_direction));
             }
+
+            if options.double_click_maximize && interaction_response.double_clicked() {
+                maximize_axis(ctx, resize_direction);
+            }
+        });
+}
+
+/// Stretch the window to the monitor size along the axis of an edge.
+///
+/// egui has no "maximize one axis" command, so this computes the target from
+/// the monitor area and sends `OuterPosition` + `InnerSize`. Corners are ignored.
+fn maximize_axis(ctx: &Context, direction: ResizeDirection) {
+    let (monitor_size, outer_rect, inner_rect) = ctx.input(|i| {
+        let viewport = i.viewport();
+        (
+            viewport.monitor_size,
+            viewport.outer_rect,
+            viewport.inner_rect,
+        )
+    });
+    let (Some(monitor_size), Some(outer_rect), Some(inner_rect)) =
+        (monitor_size, outer_rect, inner_rect)
+    else {
+        return;
+    };
+
+    let Some(area) =
+        platform::work_area(ctx).or_else(|| monitor_rect(outer_rect.center(), monitor_size))
+    else {
+        return;
+    };
+
+    // Space taken by native decorations (zero for borderless windows)
+    let decorations = outer_rect.size() - inner_rect.size();
+
+    match direction {
+        ResizeDirection::North | ResizeDirection::South => {
+            ctx.send_viewport_cmd(ViewportCommand::OuterPosition(Pos2::new(
+                outer_rect.min.x,
+                area.min.y,
+            )));
+            ctx.send_viewport_cmd(ViewportCommand::InnerSize(Vec2::new(
+                inner_rect.width(),
+                area.height() - decorations.y,
+            )));
+        }
+        ResizeDirection::West | ResizeDirection::East => {
+            ctx.send_viewport_cmd(ViewportCommand::OuterPosition(Pos2::new(
+                area.min.x,
+                outer_rect.min.y,
+            )));
+            ctx.send_viewport_cmd(ViewportCommand::InnerSize(Vec2::new(
+                area.width() - decorations.x,
+                inner_rect.height(),
+            )));
+        }
+        _ => {}
+    }
+}
+
+/// Monitor-sized cell of the desktop that `center` lies in
+///
+/// Only the monitor size is known, so monitors are assumed to be of equal
+/// size and laid out side by side, including left of or above the primary.
+fn monitor_rect(center: Pos2, monitor_size: Vec2) -> Option<Rect> {
+    if monitor_size.x <= 0.0 || monitor_size.y <= 0.0 {
+        return None;
+    }
+    let min = Pos2::new(
+        (center.x / monitor_size.x).floor() * monitor_size.x,
+        (center.y / monitor_size.y).floor() * monitor_size.y,
+    );
+    Some(Rect::from_min_size(min, monitor_size))
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use egui::{Context, Pos2, Rect};
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Gdi::{
+        GetMonitorInfoW, MONITOR_DEFAULTTONEAREST, MONITORINFO, MonitorFromWindow,
+    };
+
+    /// Work area of the monitor the window is on, in points.
+    pub fn work_area(ctx: &Context) -> Option<Rect> {
+        let hwnd = crate::utils::rounded_corners::viewport_window_ptr(ctx)?;
+        let mut info = MONITORINFO {
+            cbSize: size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        let found = unsafe {
+            let monitor = MonitorFromWindow(HWND(hwnd), MONITOR_DEFAULTTONEAREST);
+            GetMonitorInfoW(monitor, &mut info)
+        };
+        if !found.as_bool() {
+            return None;
+        }
+        let pixels_per_point = ctx.pixels_per_point();
+        let work = info.rcWork;
+        Some(Rect::from_min_max(
+            Pos2::new(work.left as f32, work.top as f32) / pixels_per_point,
+            Pos2::new(work.right as f32, work.bottom as f32) / pixels_per_point,
+        ))
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    use egui::{Context, Rect};
+
+    /// The work area is only known on Windows.
+    pub fn work_area(_ctx: &Context) -> Option<Rect> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{Event, Modifiers, RawInput, ViewportId};
+
+    /// Window content size the handles are laid out in.
+    const SCREEN: Vec2 = Vec2::new(800.0, 600.0);
+
+    /// Every resize direction with a point inside its handle.
+    const HANDLES: [(ResizeDirection, Pos2); 8] = [
+        (ResizeDirection::North, Pos2::new(400.0, 2.0)),
+        (ResizeDirection::South, Pos2::new(400.0, 598.0)),
+        (ResizeDirection::West, Pos2::new(2.0, 300.0)),
+        (ResizeDirection::East, Pos2::new(798.0, 300.0)),
+        (ResizeDirection::NorthWest, Pos2::new(3.0, 3.0)),
+        (ResizeDirection::NorthEast, Pos2::new(797.0, 3.0)),
+        (ResizeDirection::SouthWest, Pos2::new(3.0, 597.0)),
+        (ResizeDirection::SouthEast, Pos2::new(797.0, 597.0)),
+    ];
+
+    /// Press the primary button at `start` and drag away from it with only
+    /// `edges` enabled. Returns the resize directions the handles asked for.
+    fn drag(edges: ResizeEdges, start: Pos2) -> Vec<ResizeDirection> {
+        let ctx = Context::default();
+        let button = |pressed| Event::PointerButton {
+            pos: start,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        // Areas only take input once they have been laid out in an earlier frame
+        let frames = [
+            Vec::new(),
+            vec![Event::PointerMoved(start)],
+            vec![button(true)],
+            vec![Event::PointerMoved(start + Vec2::new(20.0, 20.0))],
+            vec![button(false)],
+        ];
+        let mut directions = Vec::new();
+        for events in frames {
+            let input = RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, SCREEN)),
+                events,
+                ..Default::default()
+            };
+            let output = ctx.run(input, |ctx| render_resize_handles_with_edges(ctx, edges));
+            let commands = output
+                .viewport_output
+                .get(&ViewportId::ROOT)
+                .map(|viewport| viewport.commands.as_slice())
+                .unwrap_or_default();
+            directions.extend(commands.iter().filter_map(|command| match command {
+                ViewportCommand::BeginResize(direction) => Some(*direction),
+                _ => None,
+            }));
+        }
+        directions
+    }
+
+    #[test]
+    fn monitor_rect_finds_the_monitor_under_the_window() {
+        let size = Vec2::new(1920.0, 1080.0);
+        let primary = Rect::from_min_size(Pos2::ZERO, size);
+        assert_eq!(monitor_rect(Pos2::new(400.0, 300.0), size), Some(primary));
+        assert_eq!(
+            monitor_rect(Pos2::new(2400.0, 300.0), size),
+            Some(primary.translate(Vec2::new(1920.0, 0.0)))
+        );
+        assert_eq!(
+            monitor_rect(Pos2::new(-400.0, -300.0), size),
+            Some(primary.translate(-size))
+        );
+        assert_eq!(monitor_rect(Pos2::ZERO, Vec2::ZERO), None);
+    }
+
+    #[test]
+    fn dragging_a_handle_begins_a_resize_in_its_direction() {
+        for (direction, point) in HANDLES {
+            assert_eq!(drag(ResizeEdges::all(), point), [direction]);
+        }
+    }
+
+    #[test]
+    fn disabled_edges_never_begin_a_resize() {
+        for (direction, _) in HANDLES {
+            let edges = ResizeEdges::all() & !ResizeEdges::from_direction(direction);
+            for (_, point) in HANDLES {
+                assert!(!drag(edges, point).contains(&direction));
+            }
+        }
+    }
+
+    /// Size constraint commands sent by one frame with the given limits.
+    fn limit_commands(ctx: &Context, min: Option<Vec2>, max: Option<Vec2>) -> Vec<ViewportCommand> {
+        let options = ResizeHandleOptions {
+            min_size: min,
+            max_size: max,
+            ..Default::default()
+        };
+        let output = ctx.run(RawInput::default(), |ctx| {
+            render_resize_handles_with_options(ctx, &options)
         });
+        output
+            .viewport_output
+            .get(&ViewportId::ROOT)
+            .map(|viewport| viewport.commands.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|command| {
+                matches!(
+                    command,
+                    ViewportCommand::MinInnerSize(_) | ViewportCommand::MaxInnerSize(_)
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn resize_limits_are_sent_once_and_lifted_when_removed() {
+        let ctx = Context::default();
+        let min = Vec2::new(400.0, 300.0);
+        let max = Vec2::new(1200.0, 900.0);
+        assert_eq!(limit_commands(&ctx, None, None), []);
+        assert_eq!(
+            limit_commands(&ctx, Some(min), Some(max)),
+            [
+                ViewportCommand::MinInnerSize(min),
+                ViewportCommand::MaxInnerSize(max)
+            ]
+        );
+        assert_eq!(limit_commands(&ctx, Some(min), Some(max)), []);
+        assert_eq!(
+            limit_commands(&ctx, Some(min), None),
+            [ViewportCommand::MaxInnerSize(Vec2::INFINITY)]
+        );
+        assert_eq!(
+            limit_commands(&ctx, None, None),
+            [ViewportCommand::MinInnerSize(Vec2::ZERO)]
+        );
+        assert_eq!(limit_commands(&ctx, None, None), []);
+    }
+
+    #[test]
+    fn horizontal_only_and_none_leave_the_expected_handles() {
+        assert_eq!(drag(ResizeEdges::horizontal_only(), HANDLES[0].1), []);
+        assert_eq!(
+            drag(ResizeEdges::horizontal_only(), HANDLES[2].1),
+            [ResizeDirection::West]
+        );
+        for (_, point) in HANDLES {
+            assert!(drag(ResizeEdges::none(), point).is_empty());
+        }
+    }
 }
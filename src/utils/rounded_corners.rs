@@ -1,30 +1,70 @@
 use eframe::Frame;
-use egui::Context;
+use egui::{Color32, Context, Id, LayerId, Order, StrokeKind};
 use raw_window_handle::{HasWindowHandle, RawWindowHandle};
 use std::{
-    collections::HashSet,
+    collections::HashMap,
     ffi::c_void,
     sync::{LazyLock, Mutex, Once},
 };
 
-use crate::utils::os::apply_native_rounded_corners;
+use crate::utils::os::{
+    CornerRadii, DEFAULT_CORNER_RADIUS, apply_native_rounded_corners_with_radii,
+};
 
-// Track which viewports have had rounded corners applied
-static APPLIED_VIEWPORTS: LazyLock<Mutex<HashSet<egui::ViewportId>>> =
-    LazyLock::new(|| Mutex::new(HashSet::new()));
+// Track the radii last applied to each viewport, so the native call only
+// happens on actual transitions (e.g. maximize/restore)
+static APPLIED_VIEWPORTS: LazyLock<Mutex<HashMap<egui::ViewportId, CornerRadii>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 /// Applies native rounded corners to the window if supported on the current platform.
 /// This should be called once after the window is created.
 ///
 /// For the main window, use this function with the `Frame` from `eframe::App::update`.
 pub fn apply_rounded_corners(frame: &Frame) {
+    apply_rounded_corners_with_radius(frame, DEFAULT_CORNER_RADIUS);
+}
+
+/// Applies native rounded corners with a specific radius in points.
+/// This should be called once after the window is created.
+///
+/// macOS and Linux X11 honor any radius. Windows 11 maps the value onto the
+/// DWM presets (square, small or regular rounding).
+pub fn apply_rounded_corners_with_radius(frame: &Frame, radius: f32) {
+    apply_rounded_corners_with_radii(frame, CornerRadii::same(radius));
+}
+
+/// Applies native rounded corners with an independent radius per corner.
+/// This should be called once after the window is created.
+///
+/// Linux X11 honors each corner; Windows and macOS fall back to the largest
+/// radius (see [`apply_native_rounded_corners_with_radii`]).
+///
+/// The `Frame` carries no viewport state, so this does not square the corners
+/// when maximized; call [`apply_rounded_corners_to_viewport_with_radii`] every
+/// frame instead if you need that.
+///
+/// [`apply_native_rounded_corners_with_radii`]: crate::apply_native_rounded_corners_with_radii
+pub fn apply_rounded_corners_with_radii(frame: &Frame, radii: CornerRadii) {
     static INIT: Once = Once::new();
 
+    let first_call = !INIT.is_completed();
     INIT.call_once(|| {
         if let Ok(window_handle) = frame.window_handle() {
-            apply_rounded_corners_from_handle(window_handle);
+            apply_rounded_corners_from_handle(window_handle, radii);
         }
     });
+
+    // X11 shapes are pixel masks, so they must follow the window size.
+    // The platform layer only reshapes when the size actually changed.
+    #[cfg(target_os = "linux")]
+    if !first_call
+        && let Ok(window_handle) = frame.window_handle()
+        && let RawWindowHandle::Xlib(h) = window_handle.as_raw()
+    {
+        let _ = apply_native_rounded_corners_with_radii(h.window as *mut _, radii);
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = first_call;
 }
 
 /// Stores window handle information in the egui context for later use in viewport callbacks.
@@ -47,15 +87,7 @@ pub fn store_window_handle_for_viewport(
         let raw_handle: RawWindowHandle = window_handle.into();
 
         // Extract the platform-specific pointer (which is Send + Sync)
-        let ptr: Option<*mut c_void> = match raw_handle {
-            RawWindowHandle::Win32(h) => Some(h.hwnd.get() as *mut _),
-            RawWindowHandle::AppKit(h) => Some(h.ns_view.as_ptr() as *mut _),
-            RawWindowHandle::Xlib(h) => Some(h.window as *mut _),
-            RawWindowHandle::Wayland(h) => Some(h.surface.as_ptr() as *mut _),
-            _ => None,
-        };
-
-        if let Some(native_ptr) = ptr {
+        if let Some(native_ptr) = native_window_ptr(raw_handle) {
             let id = egui::Id::new(("rounded_corners_ptr", viewport_id));
             // Store as usize (which is Send + Sync) instead of raw pointer
             let ptr_as_usize = native_ptr as usize;
@@ -88,7 +120,35 @@ pub fn store_window_handle_for_viewport(
 /// });
 /// ```
 pub fn apply_rounded_corners_to_viewport(ctx: &Context) {
+    apply_rounded_corners_to_viewport_with_radius(ctx, DEFAULT_CORNER_RADIUS);
+}
+
+/// Applies native rounded corners with a specific radius to a viewport window.
+///
+/// Behaves like [`apply_rounded_corners_to_viewport`]; see
+/// [`apply_rounded_corners_with_radius`] for how each platform honors the radius.
+pub fn apply_rounded_corners_to_viewport_with_radius(ctx: &Context, radius: f32) {
+    apply_rounded_corners_to_viewport_with_radii(ctx, CornerRadii::same(radius));
+}
+
+/// Applies native rounded corners with an independent radius per corner to a
+/// viewport window.
+///
+/// Behaves like [`apply_rounded_corners_to_viewport`]; see
+/// [`apply_rounded_corners_with_radii`] for how each platform honors the radii.
+///
+/// While the viewport is maximized or fullscreen the corners are squared, and
+/// the rounding is restored once the window is restored.
+pub fn apply_rounded_corners_to_viewport_with_radii(ctx: &Context, radii: CornerRadii) {
     let viewport_id = ctx.viewport_id();
+    let radii = effective_radii(ctx, radii);
+
+    // Wayland has no window-shape API, so the rounding has to be drawn client-side
+    #[cfg(target_os = "linux")]
+    if crate::utils::os::is_wayland_session() {
+        apply_rounded_corners_wayland_with_radii(ctx, radii);
+        return;
+    }
 
     // Check if viewport is being closed - if so, remove it from the applied list
     // so it can be reapplied when reopened
@@ -104,20 +164,29 @@ pub fn apply_rounded_corners_to_viewport(ctx: &Context) {
     }
 
     // Try to get a window handle - if we can get one, apply rounded corners
-    // We don't check if we've already applied because the window handle might have changed
-    // (e.g., if the window was closed and reopened, it's a new native window)
+    // The applied state is cleared on close, so a reopened window (a new native
+    // window) is always rounded again
 
     // Try method 1: Get stored window handle pointer from the context
     let id = egui::Id::new(("rounded_corners_ptr", viewport_id));
     let mut handle_found = false;
+    let stored_ptr = ctx.data(|data| data.get_temp::<usize>(id));
+
+    // Nothing changed since the last successful call. X11 shapes still have to
+    // follow the window size, which the platform layer caches on its own.
+    let unchanged = APPLIED_VIEWPORTS.lock().unwrap().get(&viewport_id) == Some(&radii);
+    if stored_ptr.is_some() && unchanged && !cfg!(target_os = "linux") {
+        return;
+    }
 
-    if let Some(ptr_as_usize) = ctx.data(|data| data.get_temp::<usize>(id)) {
+    if let Some(ptr_as_usize) = stored_ptr {
         // Convert back from usize to pointer
         let native_ptr = ptr_as_usize as *mut c_void;
 
-        match apply_native_rounded_corners(native_ptr) {
+        match apply_native_rounded_corners_with_radii(native_ptr, radii) {
             Ok(_) => {
                 // println!("🎉 Native rounded corners applied successfully to viewport!");
+                APPLIED_VIEWPORTS.lock().unwrap().insert(viewport_id, radii);
                 handle_found = true;
             }
             Err(e) => {
@@ -134,7 +203,7 @@ pub fn apply_rounded_corners_to_viewport(ctx: &Context) {
     // This is especially useful when the window is reopened (new native window)
     if !handle_found {
         if let Some(native_ptr) = get_viewport_window_handle(ctx) {
-            match apply_native_rounded_corners(native_ptr) {
+            match apply_native_rounded_corners_with_radii(native_ptr, radii) {
                 Ok(_) => {
                     println!("🎉 Native rounded corners applied successfully to viewport!");
                     // Store the new handle for future use
@@ -142,6 +211,7 @@ pub fn apply_rounded_corners_to_viewport(ctx: &Context) {
                     ctx.data_mut(|data| {
                         data.insert_temp(id, ptr_as_usize);
                     });
+                    APPLIED_VIEWPORTS.lock().unwrap().insert(viewport_id, radii);
                     handle_found = true;
                 }
                 Err(e) => {
@@ -163,6 +233,108 @@ pub fn apply_rounded_corners_to_viewport(ctx: &Context) {
     }
 }
 
+/// Draws rounded corners client-side, for compositors without a window-shape API.
+///
+/// Wayland offers no way to clip a window, so instead the panel background is
+/// painted as a rounded rectangle with an anti-aliased border, leaving the
+/// corners transparent. The title bar picks up the same radius for its top
+/// corners. Call this every frame before showing any panel.
+///
+/// Requirements:
+/// - The viewport must be transparent (`ViewportBuilder::with_transparent(true)`)
+/// - `eframe::App::clear_color` must return a fully transparent color
+///
+/// The current `panel_fill` is remembered and replaced by a transparent fill so
+/// panels don't paint over the corners; set a new `panel_fill` to change the
+/// background color later. [`apply_rounded_corners_to_viewport`] calls this
+/// automatically on Wayland. Corners are drawn square while the viewport is
+/// maximized or fullscreen.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui_desktop::{TitleBar, apply_rounded_corners_wayland};
+/// # struct App { title_bar: TitleBar }
+/// # impl App {
+/// fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+///     apply_rounded_corners_wayland(ctx, 12.0);
+///     self.title_bar.show(ctx);
+/// }
+/// # }
+/// ```
+pub fn apply_rounded_corners_wayland(ctx: &Context, radius: f32) {
+    apply_rounded_corners_wayland_with_radii(ctx, CornerRadii::same(radius));
+}
+
+/// Draws client-side rounded corners with an independent radius per corner.
+///
+/// Every corner is honored exactly. See [`apply_rounded_corners_wayland`] for
+/// the requirements.
+pub fn apply_rounded_corners_wayland_with_radii(ctx: &Context, radii: CornerRadii) {
+    let viewport_id = ctx.viewport_id();
+    let radii = effective_radii(ctx, radii);
+    let fill_id = Id::new(("client_side_corners_fill", viewport_id));
+
+    // Panels must stay transparent so the rounded background shows through
+    let panel_fill = ctx.style().visuals.panel_fill;
+    if panel_fill != Color32::TRANSPARENT {
+        ctx.data_mut(|data| data.insert_temp(fill_id, panel_fill));
+        ctx.style_mut(|style| style.visuals.panel_fill = Color32::TRANSPARENT);
+    }
+    let fill = ctx
+        .data(|data| data.get_temp::<Color32>(fill_id))
+        .unwrap_or(panel_fill);
+
+    ctx.data_mut(|data| data.insert_temp(client_side_radius_id(viewport_id), radii));
+
+    let rect = ctx.content_rect();
+    let rounding = radii.to_egui();
+
+    ctx.layer_painter(LayerId::background())
+        .rect_filled(rect, rounding, fill);
+
+    let stroke = ctx.style().visuals.window_stroke;
+    ctx.layer_painter(LayerId::new(
+        Order::Foreground,
+        Id::new(("client_side_corners_border", viewport_id)),
+    ))
+    .rect_stroke(rect, rounding, stroke, StrokeKind::Inside);
+}
+
+/// Radii drawn by [`apply_rounded_corners_wayland`] for the current viewport, if any.
+///
+/// Lets widgets that paint up to the window edge (like the title bar) round
+/// their own corners to match.
+pub fn client_side_corner_radii(ctx: &Context) -> Option<CornerRadii> {
+    ctx.data(|data| data.get_temp::<CornerRadii>(client_side_radius_id(ctx.viewport_id())))
+}
+
+/// Square corners while the viewport fills the screen, the requested radii otherwise.
+fn effective_radii(ctx: &Context, radii: CornerRadii) -> CornerRadii {
+    let fills_screen = ctx.input(|i| {
+        let viewport = i.viewport();
+        viewport.maximized.unwrap_or(false) || viewport.fullscreen.unwrap_or(false)
+    });
+    if fills_screen {
+        CornerRadii::default()
+    } else {
+        radii
+    }
+}
+
+fn client_side_radius_id(viewport_id: egui::ViewportId) -> Id {
+    Id::new(("client_side_corner_radius", viewport_id))
+}
+
+/// Native pointer of the current viewport's window: the handle stored with
+/// [`store_window_handle_for_viewport`], or one looked up by window title.
+pub(crate) fn viewport_window_ptr(ctx: &Context) -> Option<*mut c_void> {
+    let id = egui::Id::new(("rounded_corners_ptr", ctx.viewport_id()));
+    ctx.data(|data| data.get_temp::<usize>(id))
+        .map(|ptr_as_usize| ptr_as_usize as *mut c_void)
+        .or_else(|| get_viewport_window_handle(ctx))
+}
+
 /// Attempts to get the window handle for a viewport using platform-specific APIs.
 /// This is a fallback when the handle isn't stored in the context.
 fn get_viewport_window_handle(ctx: &Context) -> Option<*mut c_void> {
@@ -236,49 +408,52 @@ fn get_viewport_window_handle(ctx: &Context) -> Option<*mut c_void> {
         }
     }
 
-    // Linux/X11 and Wayland - not implemented yet
     #[cfg(target_os = "linux")]
     {
-        // TODO: Implement for Linux
+        if let Some(window) = crate::utils::os::find_x11_window_by_title(&viewport_title) {
+            return Some(window);
+        }
     }
 
     None
 }
 
 /// Internal helper to apply rounded corners from a window handle
-fn apply_rounded_corners_from_handle(window_handle: raw_window_handle::WindowHandle) {
+fn apply_rounded_corners_from_handle(
+    window_handle: raw_window_handle::WindowHandle,
+    radii: CornerRadii,
+) {
     let handle: RawWindowHandle = window_handle.into();
 
-    let ptr: Option<*mut c_void> = match handle {
-        RawWindowHandle::Win32(h) => {
-            println!("🪟 Windows: Using Win32 window handle");
-            Some(h.hwnd.get() as *mut _)
-        }
-        RawWindowHandle::AppKit(h) => {
-            println!("🍎 macOS: Using AppKit window handle");
-            Some(h.ns_view.as_ptr() as *mut _)
-        }
-        RawWindowHandle::Xlib(h) => {
-            println!("🐧 Linux X11: Using Xlib window handle");
-            Some(h.window as *mut _)
-        }
-        RawWindowHandle::Wayland(h) => {
-            println!("🐧 Linux Wayland: Using Wayland surface handle");
-            Some(h.surface.as_ptr() as *mut _)
-        }
-        _ => {
+    let Some(native_ptr) = native_window_ptr(handle) else {
+        if let RawWindowHandle::Wayland(_) = handle {
+            println!(
+                "🐧 Linux Wayland: No native window shaping, use apply_rounded_corners_wayland(ctx, radius)"
+            );
+        } else {
             println!(
                 "ℹ️ Platform: Native rounded corners not supported for this window handle type: {:?}",
                 handle
             );
-            None
         }
+        return;
     };
 
-    if let Some(native_ptr) = ptr {
-        match apply_native_rounded_corners(native_ptr) {
-            Ok(_) => println!("🎉 Native rounded corners applied successfully!"),
-            Err(e) => eprintln!("⚠️ Failed to apply native rounded corners: {}", e),
-        }
+    match apply_native_rounded_corners_with_radii(native_ptr, radii) {
+        Ok(_) => println!("🎉 Native rounded corners applied successfully!"),
+        Err(e) => eprintln!("⚠️ Failed to apply native rounded corners: {}", e),
+    }
+}
+
+/// Extracts the pointer the native helpers in `os` expect from a window handle.
+///
+/// That is the HWND on Windows, the NSView on macOS and the window id on X11.
+/// Wayland surfaces can't be modified natively, so they yield `None`.
+pub(crate) fn native_window_ptr(handle: RawWindowHandle) -> Option<*mut c_void> {
+    match handle {
+        RawWindowHandle::Win32(h) => Some(h.hwnd.get() as *mut _),
+        RawWindowHandle::AppKit(h) => Some(h.ns_view.as_ptr() as *mut _),
+        RawWindowHandle::Xlib(h) => Some(h.window as *mut _),
+        _ => None,
     }
 }
@@ -0,0 +1,45 @@
+use eframe::Frame;
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use std::sync::Once;
+
+use crate::utils::os::apply_native_window_shadow;
+use crate::utils::rounded_corners::native_window_ptr;
+
+/// Gives a borderless window a native drop shadow if supported on the current platform.
+/// This should be called once after the window is created; later calls do nothing.
+///
+/// Without decorations the OS drops the window shadow, which makes the window
+/// look flat against the desktop. This asks the platform to draw it again:
+/// - Windows: DWM non-client rendering with a one pixel frame extension
+/// - macOS: `NSWindow.hasShadow`
+/// - Linux: no-op. Shadows are compositor-controlled, in particular on Wayland
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui_desktop::{apply_rounded_corners, apply_window_shadow};
+/// # struct App;
+/// # impl App {
+/// fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+///     apply_rounded_corners(frame);
+///     apply_window_shadow(frame);
+///     // ... rest of your UI
+/// }
+/// # }
+/// ```
+pub fn apply_window_shadow(frame: &Frame) {
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        let Ok(window_handle) = frame.window_handle() else {
+            return;
+        };
+        let handle: RawWindowHandle = window_handle.into();
+
+        if let Some(native_ptr) = native_window_ptr(handle)
+            && let Err(e) = apply_native_window_shadow(native_ptr)
+        {
+            eprintln!("⚠️ Failed to apply native window shadow: {}", e);
+        }
+    });
+}
@@ -0,0 +1,520 @@
+//! Keyboard navigation of the title bar menus, driven without a window.
+//!
+//! Synthetic key presses are fed through `Context::run`, one frame each, and
+//! the navigation state is checked after every step.
+
+use egui::{Context, Event, Key, Modifiers, RawInput};
+use egui_desktop::{
+    MenuAction, MenuItem, MenuMessages, StateCallbacks, SubMenuItem, SystemMenu, ThemeMode,
+    TitleBar, TitleBarOptions,
+};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+
+/// A title bar and the context it runs in, advanced one frame at a time.
+struct Harness {
+    ctx: Context,
+    title_bar: TitleBar,
+}
+
+impl Harness {
+    fn new(title_bar: TitleBar) -> Self {
+        Self {
+            ctx: Context::default(),
+            title_bar,
+        }
+    }
+
+    /// Run one frame with `modifiers` held and `keys` pressed.
+    fn frame(&mut self, modifiers: Modifiers, keys: &[Key]) {
+        let events = keys
+            .iter()
+            .map(|&key| Event::Key {
+                key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers,
+            })
+            .collect();
+        let input = RawInput {
+            events,
+            modifiers,
+            ..Default::default()
+        };
+        let title_bar = &mut self.title_bar;
+        let _ = self.ctx.run(input, |ctx| {
+            title_bar.handle_keyboard_navigation(ctx);
+        });
+    }
+
+    /// Run one frame holding Alt, which activates keyboard navigation.
+    fn activate(&mut self) {
+        self.frame(Modifiers::ALT, &[]);
+    }
+
+    /// Run one frame pressing `key` with no modifiers.
+    fn press(&mut self, key: Key) {
+        self.frame(Modifiers::NONE, &[key]);
+    }
+
+    /// Highlighted entry of the open submenu and of each side menu opened from it.
+    fn selection(&self) -> &[usize] {
+        &self.title_bar.submenu_selection
+    }
+}
+
+fn title_bar() -> TitleBar {
+    TitleBar::new(TitleBarOptions::new().with_theme_mode(ThemeMode::Light))
+}
+
+/// Message channel of a test bar, with the receiver to check what was sent.
+fn messages() -> (MenuMessages<&'static str>, mpsc::Receiver<&'static str>) {
+    let (sender, receiver) = mpsc::channel();
+    (MenuMessages::new(sender), receiver)
+}
+
+/// Top-level menus: 0 "Home", 1 "File", 2 "Edit". "Export" in "File" has a
+/// side menu whose "SVG" entry sets `exported`, and "Undo" in "Edit" sends
+/// "undo" to `messages`.
+fn file_edit_bar(exported: Arc<AtomicBool>, messages: &MenuMessages<&'static str>) -> TitleBar {
+    title_bar()
+        .add_menu_item("Home", None)
+        .add_menu_with_submenu(
+            MenuItem::new("File")
+                .add_subitem(SubMenuItem::new("New"))
+                .add_subitem(SubMenuItem::new("Open"))
+                .add_subitem(
+                    SubMenuItem::new("Export")
+                        .add_child(SubMenuItem::new("PNG"))
+                        .add_child(SubMenuItem::new("SVG").with_callback(Box::new(move || {
+                            exported.store(true, Ordering::Relaxed);
+                        }))),
+                ),
+        )
+        .add_menu_with_submenu(
+            MenuItem::new("Edit").add_subitem(
+                SubMenuItem::new("Undo")
+                    .with_id("edit.undo")
+                    .with_message(messages, "undo"),
+            ),
+        )
+}
+
+#[test]
+fn alt_activates_navigation_on_the_first_menu() {
+    let mut harness = Harness::new(file_edit_bar(Arc::default(), &messages().0));
+    harness.activate();
+    assert!(harness.title_bar.is_keyboard_navigation_active());
+    assert_eq!(harness.title_bar.selected_menu_index(), Some(0));
+}
+
+#[test]
+fn left_and_right_traverse_the_menus() {
+    let mut harness = Harness::new(file_edit_bar(Arc::default(), &messages().0));
+    harness.activate();
+    harness.press(Key::ArrowRight);
+    harness.press(Key::ArrowRight);
+    harness.press(Key::ArrowRight);
+    assert_eq!(harness.title_bar.selected_menu_index(), Some(2));
+    harness.press(Key::ArrowLeft);
+    assert_eq!(harness.title_bar.selected_menu_index(), Some(1));
+}
+
+#[test]
+fn enter_opens_a_submenu_and_up_down_move_through_it() {
+    let mut harness = Harness::new(file_edit_bar(Arc::default(), &messages().0));
+    harness.activate();
+    harness.press(Key::ArrowRight);
+    harness.press(Key::Enter);
+    assert_eq!(harness.title_bar.open_submenu_index(), Some(0));
+    assert_eq!(harness.selection(), [0]);
+    harness.press(Key::ArrowDown);
+    harness.press(Key::ArrowDown);
+    assert_eq!(harness.selection(), [2]);
+}
+
+#[test]
+fn right_and_left_open_and_close_a_side_menu() {
+    let exported = Arc::new(AtomicBool::new(false));
+    let mut harness = Harness::new(file_edit_bar(exported.clone(), &messages().0));
+    harness.activate();
+    harness.press(Key::ArrowRight);
+    harness.press(Key::Enter);
+    harness.press(Key::ArrowDown);
+    harness.press(Key::ArrowDown);
+    harness.press(Key::ArrowRight);
+    assert_eq!(harness.selection(), [2, 0]);
+    harness.press(Key::ArrowDown);
+    assert_eq!(harness.selection(), [2, 1]);
+    harness.press(Key::ArrowLeft);
+    assert_eq!(harness.selection(), [2]);
+    assert_eq!(harness.title_bar.open_submenu_index(), Some(0));
+
+    // Enter on a side menu entry runs its callback and closes the menus
+    harness.press(Key::ArrowRight);
+    harness.press(Key::ArrowDown);
+    harness.press(Key::Enter);
+    assert!(exported.load(Ordering::Relaxed));
+    assert!(!harness.title_bar.is_submenu_open());
+}
+
+#[test]
+fn escape_closes_everything() {
+    let mut harness = Harness::new(file_edit_bar(Arc::default(), &messages().0));
+    harness.activate();
+    harness.press(Key::ArrowRight);
+    harness.press(Key::Enter);
+    assert!(harness.title_bar.is_submenu_open());
+    harness.press(Key::Escape);
+    assert!(!harness.title_bar.is_keyboard_navigation_active());
+    assert!(!harness.title_bar.is_submenu_open());
+    assert_eq!(harness.title_bar.selected_menu_index(), None);
+}
+
+#[test]
+fn activated_entries_send_their_message_and_action() {
+    let (messages, receiver) = messages();
+    let (action_sender, actions) = mpsc::channel();
+    let mut harness =
+        Harness::new(file_edit_bar(Arc::default(), &messages).with_action_sender(action_sender));
+    harness.activate();
+    harness.press(Key::ArrowRight);
+    harness.press(Key::Enter);
+    harness.press(Key::ArrowDown);
+    harness.press(Key::ArrowDown);
+    harness.press(Key::ArrowRight);
+    harness.press(Key::ArrowDown);
+    harness.press(Key::Enter);
+
+    harness.activate();
+    harness.press(Key::ArrowRight);
+    harness.press(Key::ArrowRight);
+    harness.press(Key::Enter);
+    harness.press(Key::Enter);
+    assert_eq!(receiver.try_recv(), Ok("undo"));
+
+    // Every activated entry is reported with its label path and id
+    let reported: Vec<MenuAction> = actions.try_iter().collect();
+    let paths: Vec<Vec<&str>> = reported
+        .iter()
+        .map(|action| action.path.iter().map(String::as_str).collect())
+        .collect();
+    assert_eq!(paths, [vec!["File", "Export", "SVG"], vec!["Edit", "Undo"]]);
+    assert_eq!(reported[0].id, None);
+    assert_eq!(reported[1].id.as_deref(), Some("edit.undo"));
+}
+
+#[test]
+fn activated_entries_queue_their_state_callback() {
+    let callbacks = StateCallbacks::new();
+    let mut harness = Harness::new(
+        title_bar().add_menu_with_submenu(
+            MenuItem::new("Edit").add_subitem(
+                SubMenuItem::new("Append")
+                    .with_state_callback(&callbacks, Box::new(|text: &mut String| text.push('!'))),
+            ),
+        ),
+    );
+    harness.activate();
+    harness.press(Key::Enter);
+    harness.press(Key::Enter);
+
+    let mut text = String::from("done");
+    callbacks.run(&mut text);
+    assert_eq!(text, "done!");
+    callbacks.run(&mut text);
+    assert_eq!(text, "done!");
+}
+
+#[test]
+fn split_entries_act_on_enter_and_expand_on_right() {
+    let (messages, receiver) = messages();
+    let mut split = Harness::new(
+        title_bar().add_menu_with_submenu(
+            MenuItem::new("File").add_subitem(
+                SubMenuItem::new("Export")
+                    .with_split_button()
+                    .with_message(&messages, "export")
+                    .add_child(SubMenuItem::new("PNG").with_message(&messages, "png")),
+            ),
+        ),
+    );
+    split.activate();
+    split.press(Key::Enter);
+    assert_eq!(split.selection(), [0]);
+    split.press(Key::ArrowRight);
+    assert_eq!(split.selection(), [0, 0]);
+    split.press(Key::ArrowLeft);
+    split.press(Key::Enter);
+    assert_eq!(receiver.try_recv(), Ok("export"));
+}
+
+#[test]
+fn arrows_move_within_and_across_columns() {
+    let symbols = ["α", "β", "γ", "δ", "ε", "ζ", "η"]
+        .iter()
+        .fold(MenuItem::new("Symbols").with_columns(3), |menu, symbol| {
+            menu.add_subitem(SubMenuItem::new(symbol))
+        });
+    let mut columns = Harness::new(title_bar().add_menu_with_submenu(symbols));
+    columns.activate();
+    columns.press(Key::Enter);
+    let selection = |harness: &Harness| harness.selection().last().copied();
+    for _ in 0..3 {
+        columns.press(Key::ArrowDown);
+    }
+    assert_eq!(selection(&columns), Some(2));
+    columns.press(Key::ArrowRight);
+    assert_eq!(selection(&columns), Some(5));
+    columns.press(Key::ArrowRight);
+    assert_eq!(selection(&columns), Some(6));
+    columns.press(Key::ArrowLeft);
+    columns.press(Key::ArrowUp);
+    assert_eq!(selection(&columns), Some(3));
+    assert_eq!(columns.title_bar.open_submenu_index(), Some(0));
+}
+
+#[test]
+fn arrows_reach_side_menus_nested_several_levels_deep() {
+    // File > Export > Image > JPEG
+    let (action_sender, actions) = mpsc::channel();
+    let mut nested = Harness::new(
+        title_bar()
+            .with_action_sender(action_sender)
+            .add_menu_with_submenu(
+                MenuItem::new("File").add_subitem(
+                    SubMenuItem::new("Export").add_child(
+                        SubMenuItem::new("Image")
+                            .add_child(SubMenuItem::new("PNG"))
+                            .add_child(SubMenuItem::new("JPEG")),
+                    ),
+                ),
+            ),
+    );
+    nested.activate();
+    nested.press(Key::Enter);
+    nested.press(Key::ArrowRight);
+    nested.press(Key::ArrowRight);
+    assert_eq!(nested.selection(), [0, 0, 0]);
+    nested.press(Key::ArrowDown);
+    assert_eq!(nested.selection(), [0, 0, 1]);
+    nested.press(Key::ArrowLeft);
+    assert_eq!(nested.selection(), [0, 0]);
+    nested.press(Key::Enter);
+    assert_eq!(nested.selection(), [0, 0, 0]);
+    nested.press(Key::ArrowDown);
+    nested.press(Key::Enter);
+    assert!(!nested.title_bar.is_submenu_open());
+    let reported: Vec<MenuAction> = actions.try_iter().collect();
+    assert_eq!(reported[0].path, ["File", "Export", "Image", "JPEG"]);
+    assert!(
+        nested
+            .title_bar
+            .open_submenu_path(&["File", "Export", "Image", "PNG"])
+    );
+    assert_eq!(nested.selection(), [0, 0, 0]);
+    assert!(
+        !nested
+            .title_bar
+            .open_submenu_path(&["File", "Export", "Missing"])
+    );
+    assert_eq!(nested.selection(), [0, 0, 0]);
+}
+
+#[test]
+fn disabled_menus_are_skipped() {
+    // 0 "Home" (disabled), 1 "About", 2 "File" (disabled), 3 "Edit"
+    let mut disabled = Harness::new(
+        title_bar()
+            .add_menu_item("Home", None)
+            .add_menu_item("About", None)
+            .add_menu_with_submenu(
+                MenuItem::new("File")
+                    .disabled()
+                    .add_subitem(SubMenuItem::new("New")),
+            )
+            .add_menu_with_submenu(MenuItem::new("Edit").add_subitem(SubMenuItem::new("Undo"))),
+    );
+    disabled.title_bar.set_menu_item_enabled(0, false);
+    disabled.activate();
+    assert_eq!(disabled.title_bar.selected_menu_index(), Some(1));
+    disabled.press(Key::ArrowRight);
+    assert_eq!(disabled.title_bar.selected_menu_index(), Some(3));
+    disabled.press(Key::ArrowLeft);
+    assert_eq!(disabled.title_bar.selected_menu_index(), Some(1));
+    assert!(!disabled.title_bar.open_menu(0));
+    assert!(!disabled.title_bar.open_submenu_path(&["File", "New"]));
+    assert!(disabled.title_bar.open_menu(1));
+}
+
+#[test]
+fn hidden_entries_cannot_be_reached_or_activated() {
+    // "View" has no visible entry left and "Export" in "File" has no
+    // visible side menu entry
+    let (messages, receiver) = messages();
+    let mut hidden = Harness::new(
+        title_bar()
+            .add_menu_with_submenu(
+                MenuItem::new("View")
+                    .add_subitem(
+                        SubMenuItem::new("Zoom")
+                            .with_message(&messages, "zoom")
+                            .with_visible(false),
+                    )
+                    .add_subitem(
+                        SubMenuItem::new("Grid")
+                            .with_message(&messages, "grid")
+                            .with_visible(false),
+                    ),
+            )
+            .add_menu_with_submenu(
+                MenuItem::new("File").add_subitem(
+                    SubMenuItem::new("Export")
+                        .with_message(&messages, "export")
+                        .add_child(
+                            SubMenuItem::new("PNG")
+                                .with_message(&messages, "png")
+                                .with_visible(false),
+                        ),
+                ),
+            ),
+    );
+    hidden.activate();
+    hidden.press(Key::Enter);
+    hidden.press(Key::Enter);
+    assert!(!hidden.title_bar.is_submenu_open());
+    assert!(hidden.selection().is_empty());
+    hidden.press(Key::ArrowRight);
+    hidden.press(Key::Enter);
+    assert_eq!(hidden.selection(), [0]);
+    hidden.press(Key::ArrowRight);
+    assert_eq!(hidden.title_bar.selected_menu_index(), Some(1));
+    hidden.press(Key::Enter);
+    hidden.press(Key::Enter);
+    assert_eq!(hidden.selection(), [0]);
+    assert_eq!(receiver.try_recv(), Err(mpsc::TryRecvError::Empty));
+}
+
+#[test]
+fn custom_rows_take_keyboard_focus_only_when_they_opt_in() {
+    // "Volume" holds only a plain custom row, "Balance" one that takes focus
+    let (messages, receiver) = messages();
+    let mut custom = Harness::new(
+        title_bar()
+            .add_menu_with_submenu(MenuItem::new("Volume").add_subitem(
+                SubMenuItem::custom(32.0, Box::new(|_, _| {})).with_message(&messages, "volume"),
+            ))
+            .add_menu_with_submenu(
+                MenuItem::new("Balance").add_subitem(
+                    SubMenuItem::custom(32.0, Box::new(|_, _| {}))
+                        .with_keyboard_focus()
+                        .with_message(&messages, "balance"),
+                ),
+            ),
+    );
+    custom.activate();
+    custom.press(Key::Enter);
+    custom.press(Key::Enter);
+    assert!(!custom.title_bar.is_submenu_open());
+    assert_eq!(receiver.try_recv(), Err(mpsc::TryRecvError::Empty));
+    custom.press(Key::ArrowRight);
+    custom.press(Key::Enter);
+    assert_eq!(custom.selection(), [0]);
+    custom.press(Key::Enter);
+    assert_eq!(receiver.try_recv(), Ok("balance"));
+}
+
+#[test]
+fn navigation_without_menus_selects_nothing() {
+    let mut empty = Harness::new(title_bar());
+    empty.frame(Modifiers::ALT, &[Key::ArrowRight]);
+    empty.press(Key::ArrowRight);
+    empty.press(Key::Enter);
+    assert_eq!(empty.title_bar.selected_menu_index(), None);
+}
+
+#[test]
+fn replacing_an_open_menus_entries_keeps_the_selection_in_range() {
+    let recent = |labels: &[&str]| -> Vec<SubMenuItem> {
+        labels
+            .iter()
+            .map(|&label| SubMenuItem::new(label))
+            .collect()
+    };
+    let mut replaced = Harness::new(
+        title_bar().add_menu_with_submenu(
+            recent(&["a", "b", "c"])
+                .into_iter()
+                .fold(MenuItem::new("Recent"), MenuItem::add_subitem),
+        ),
+    );
+    replaced.activate();
+    replaced.press(Key::Enter);
+    replaced.press(Key::ArrowDown);
+    replaced.press(Key::ArrowDown);
+    assert_eq!(replaced.selection(), [2]);
+    assert!(
+        replaced
+            .title_bar
+            .set_submenu_items("Recent", recent(&["a"]))
+    );
+    assert_eq!(replaced.selection(), [0]);
+    replaced.press(Key::ArrowDown);
+    let title_bar = &mut replaced.title_bar;
+    let _ = replaced.ctx.run(RawInput::default(), |ctx| {
+        title_bar.render_open_submenu(ctx)
+    });
+    assert_eq!(replaced.selection(), [0]);
+    assert!(replaced.title_bar.set_submenu_items("Recent", Vec::new()));
+    assert!(!replaced.title_bar.is_submenu_open());
+    assert!(replaced.selection().is_empty());
+}
+
+#[test]
+fn a_missing_path_leaves_dynamic_entries_untouched() {
+    let mut harness = Harness::new(
+        title_bar().add_menu_with_submenu(
+            MenuItem::new("Recent")
+                .with_dynamic_subitems(Box::new(|| vec![SubMenuItem::new("notes.txt")])),
+        ),
+    );
+    assert!(!harness.title_bar.open_submenu_path(&["Recent", "todo.txt"]));
+    assert!(
+        harness.title_bar.menu_items_with_submenus[0]
+            .subitems
+            .is_empty()
+    );
+    assert!(!harness.title_bar.is_submenu_open());
+    assert!(
+        harness
+            .title_bar
+            .open_submenu_path(&["Recent", "notes.txt"])
+    );
+    assert_eq!(
+        harness.title_bar.menu_items_with_submenus[0].subitems.len(),
+        1
+    );
+    assert_eq!(harness.selection(), [0]);
+}
+
+#[test]
+fn the_open_system_menu_counts_as_an_open_menu() {
+    let ctx = Context::default();
+    let mut title_bar = title_bar().with_system_menu(SystemMenu::new());
+    let input = RawInput {
+        events: vec![Event::Key {
+            key: Key::Space,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::ALT,
+        }],
+        modifiers: Modifiers::ALT,
+        ..Default::default()
+    };
+    let _ = ctx.run(input, |ctx| title_bar.show(ctx));
+    assert!(title_bar.is_system_menu_open());
+    assert!(title_bar.is_any_menu_open());
+}
@@ -0,0 +1,55 @@
+//! Menu shortcuts fired through `Context::run`, without a window.
+
+use egui::{Context, Event, Key, Modifiers, RawInput};
+use egui_desktop::{KeyboardShortcut, MenuItem, SubMenuItem, ThemeMode, TitleBar, TitleBarOptions};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Press Ctrl+S on a bar with a Ctrl+S entry, then look for the press in
+/// the same frame. Returns whether the entry fired and the press was seen.
+fn press_shortcut(consume: bool) -> (bool, bool) {
+    let saved = Arc::new(AtomicBool::new(false));
+    let saved_flag = saved.clone();
+    let mut title_bar = TitleBar::new(TitleBarOptions::new().with_theme_mode(ThemeMode::Light))
+        .with_consume_shortcuts(consume)
+        .add_menu_with_submenu(
+            MenuItem::new("File").add_subitem(
+                SubMenuItem::new("Save")
+                    .with_shortcut(KeyboardShortcut::from_string("ctrl+s").unwrap())
+                    .with_callback(Box::new(move || saved_flag.store(true, Ordering::Relaxed))),
+            ),
+        );
+    let ctx = Context::default();
+    let mut seen = false;
+    for pressed in [false, true] {
+        let input = RawInput {
+            events: pressed
+                .then_some(Event::Key {
+                    key: Key::S,
+                    physical_key: None,
+                    pressed: true,
+                    repeat: false,
+                    modifiers: Modifiers::CTRL,
+                })
+                .into_iter()
+                .collect(),
+            modifiers: Modifiers::CTRL,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            title_bar.check_keyboard_shortcuts(ctx);
+            seen = ctx.input(|i| i.key_pressed(Key::S));
+        });
+    }
+    (saved.load(Ordering::Relaxed), seen)
+}
+
+#[test]
+fn fired_shortcuts_hide_their_key_press() {
+    assert_eq!(press_shortcut(true), (true, false));
+}
+
+#[test]
+fn shortcuts_can_leave_their_key_press_visible() {
+    assert_eq!(press_shortcut(false), (true, true));
+}
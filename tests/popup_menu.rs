@@ -0,0 +1,51 @@
+//! Popup menus driven with the mouse through `Context::run`, without a window.
+
+use egui::{Context, Event, Modifiers, PointerButton, Pos2, RawInput, pos2};
+use egui_desktop::{MenuItem, PopupMenu, PopupMenuResponse, SubMenuItem};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Show `menu` at a fixed position for one frame with `events` as input.
+fn frame(ctx: &Context, menu: &MenuItem, events: Vec<Event>) -> PopupMenuResponse {
+    let input = RawInput {
+        events,
+        ..Default::default()
+    };
+    let mut response = None;
+    let _ = ctx.run(input, |ctx| {
+        response = Some(PopupMenu::new("popup", menu).show(ctx, pos2(20.0, 20.0)));
+    });
+    response.unwrap()
+}
+
+fn button(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+#[test]
+fn clicking_a_side_menu_entry_runs_its_callback() {
+    let exported = Arc::new(AtomicBool::new(false));
+    let exported_flag = exported.clone();
+    let menu = MenuItem::new("File").add_subitem(SubMenuItem::new("Export").add_child(
+        SubMenuItem::new("PNG").with_callback(Box::new(move || {
+            exported_flag.store(true, Ordering::Relaxed);
+        })),
+    ));
+    let ctx = Context::default();
+
+    // Hover "Export" to open its side menu, then click "PNG" in it
+    let export = frame(&ctx, &menu, Vec::new()).rects[0].center();
+    frame(&ctx, &menu, vec![Event::PointerMoved(export)]);
+    let png = frame(&ctx, &menu, Vec::new()).rects[1].center();
+    frame(&ctx, &menu, vec![Event::PointerMoved(png)]);
+    frame(&ctx, &menu, vec![button(png, true)]);
+    let response = frame(&ctx, &menu, vec![button(png, false)]);
+
+    assert_eq!(response.activated, Some(vec![0, 0]));
+    assert!(exported.load(Ordering::Relaxed));
+}